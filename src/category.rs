@@ -0,0 +1,52 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+pub fn parse_category<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    template_name: &str,
+    parameters: &[::Parameter<'a>],
+) -> bool {
+    let kind = match template_name {
+        "C" | "c" | "top" | "topic" | "topics" => ::CategoryKind::Topic,
+        "catlangname" | "cln" => ::CategoryKind::LanguageName,
+        _ => return false,
+    };
+    let mut has_language = false;
+    let mut categories = vec![];
+    for parameter in parameters {
+        if parameter.name.is_some() {
+            if ::parse_parameter_name(parameter) != Some("sort") {
+                ::add_warning(context, parameter, ::WarningMessage::Unrecognized);
+                return true;
+            }
+            continue;
+        }
+        match ::parse_text_not_empty(&parameter.value) {
+            None => {
+                ::add_warning(context, parameter, ::WarningMessage::ValueUnrecognized);
+                return true;
+            }
+            Some(value) => if !has_language {
+                if value != ::language_code(context) {
+                    ::add_warning(context, parameter, ::WarningMessage::ValueConflicting);
+                    return true;
+                }
+                has_language = true;
+            } else {
+                let category = ::Category { kind, name: value };
+                if context.categories.contains(&category) || categories.contains(&category) {
+                    ::add_warning(context, parameter, ::WarningMessage::Duplicate);
+                } else {
+                    categories.push(category);
+                }
+            },
+        }
+    }
+    if categories.is_empty() {
+        ::add_warning(context, template_node, ::WarningMessage::Empty);
+    }
+    context.categories.extend(categories);
+    true
+}
@@ -0,0 +1,276 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+use ::Mood::*;
+use ::Tense::*;
+
+const FIRST_PLURAL: (::Person, ::Number) = (::Person::First, ::Number::Plural);
+const SECOND_PLURAL: (::Person, ::Number) = (::Person::Second, ::Number::Plural);
+const SECOND_SINGULAR: (::Person, ::Number) = (::Person::Second, ::Number::Singular);
+
+// The personal forms in the order first, second and third person singular followed by first, second and third person plural.
+const PERSONS: [(::Person, ::Number); 6] = [
+    (::Person::First, ::Number::Singular),
+    SECOND_SINGULAR,
+    (::Person::Third, ::Number::Singular),
+    FIRST_PLURAL,
+    SECOND_PLURAL,
+    (::Person::Third, ::Number::Plural),
+];
+
+// Interprets the conjugation templates of the entry into the forms of the conjugation. Only templates for regular conjugation classes are interpreted, since other templates form the conjugation by rules in the modules of Wiktionary.
+pub fn interpret_conjugation<'a>(context: &mut ::Context<'a, '_>, pos_entry: &mut ::PosEntry<'a>) {
+    for template in &pos_entry.inflection {
+        let mut forms = Forms(vec![]);
+        match &template.name as _ {
+            "cs-conj-dělat" => if let Some(stem) = stem(context, template, "at") {
+                conjugate_czech_dělat(&mut forms, &stem);
+            },
+            "cs-conj-prosit" => if let Some(stem) = stem(context, template, "it") {
+                conjugate_czech_prosit(&mut forms, &stem);
+            },
+            // With parameters, the template describes the conjugation of a strong or irregular verb.
+            "de-conj" if template.unnamed_parameters.is_empty() => {
+                if let Some(stem) = stem(context, template, "en") {
+                    conjugate_german_weak(&mut forms, &stem);
+                }
+            }
+            "de-conj-weak" => if let Some(stem) = stem(context, template, "en") {
+                conjugate_german_weak(&mut forms, &stem);
+            },
+            "es-conj-ar" => if let Some(stem) = stem(context, template, "ar") {
+                conjugate_spanish(&mut forms, &stem, &SPANISH_AR);
+            },
+            "es-conj-er" => if let Some(stem) = stem(context, template, "er") {
+                conjugate_spanish(&mut forms, &stem, &SPANISH_ER);
+            },
+            "es-conj-ir" => if let Some(stem) = stem(context, template, "ir") {
+                conjugate_spanish(&mut forms, &stem, &SPANISH_IR);
+            },
+            "sv-conj-wk" => if let Some(stem) = stem(context, template, "a") {
+                conjugate_swedish_weak(&mut forms, &stem);
+            },
+            _ => {}
+        }
+        pos_entry.conjugation.extend(forms.0);
+    }
+}
+
+struct Forms<'a>(Vec<::ConjugatedForm<'a>>);
+
+impl<'a> Forms<'a> {
+    fn push(
+        &mut self,
+        mood: ::Mood,
+        tense: Option<::Tense>,
+        person: Option<(::Person, ::Number)>,
+        form: String,
+    ) {
+        self.0.push(::ConjugatedForm {
+            form: ::Cow::Owned(form),
+            mood,
+            number: person.map(|(_, number)| number),
+            person: person.map(|(person, _)| person),
+            tense,
+        });
+    }
+
+    fn push_personal(
+        &mut self,
+        mood: ::Mood,
+        tense: Option<::Tense>,
+        stem: &str,
+        endings: &[&str],
+    ) {
+        for (person, ending) in PERSONS.iter().zip(endings) {
+            self.push(mood, tense, Some(*person), format!("{}{}", stem, ending));
+        }
+    }
+}
+
+struct SpanishClass {
+    gerund: &'static str,
+    imperative_plural: &'static str,
+    imperative_singular: &'static str,
+    imperfect: [&'static str; 6],
+    imperfect_subjunctive: [&'static str; 6],
+    infinitive: &'static str,
+    past_participle: &'static str,
+    present: [&'static str; 6],
+    present_subjunctive: [&'static str; 6],
+    preterite: [&'static str; 6],
+}
+
+const SPANISH_AR: SpanishClass = SpanishClass {
+    gerund: "ando",
+    imperative_plural: "ad",
+    imperative_singular: "a",
+    imperfect: ["aba", "abas", "aba", "ábamos", "abais", "aban"],
+    imperfect_subjunctive: ["ara", "aras", "ara", "áramos", "arais", "aran"],
+    infinitive: "ar",
+    past_participle: "ado",
+    present: ["o", "as", "a", "amos", "áis", "an"],
+    present_subjunctive: ["e", "es", "e", "emos", "éis", "en"],
+    preterite: ["é", "aste", "ó", "amos", "asteis", "aron"],
+};
+
+const SPANISH_ER: SpanishClass = SpanishClass {
+    gerund: "iendo",
+    imperative_plural: "ed",
+    imperative_singular: "e",
+    imperfect: ["ía", "ías", "ía", "íamos", "íais", "ían"],
+    imperfect_subjunctive: ["iera", "ieras", "iera", "iéramos", "ierais", "ieran"],
+    infinitive: "er",
+    past_participle: "ido",
+    present: ["o", "es", "e", "emos", "éis", "en"],
+    present_subjunctive: ["a", "as", "a", "amos", "áis", "an"],
+    preterite: ["í", "iste", "ió", "imos", "isteis", "ieron"],
+};
+
+const SPANISH_IR: SpanishClass = SpanishClass {
+    imperative_plural: "id",
+    infinitive: "ir",
+    present: ["o", "es", "e", "imos", "ís", "en"],
+    ..SPANISH_ER
+};
+
+// The parameter is the stem. When it's omitted, the stem is the page title without the ending of the infinitive. When both the stem and the page title are given, the infinitive formed from the stem is expected to occur in the page title, which allows for a prefix or reflexive pronoun written together with the verb, such as in `lavarse`.
+fn stem(context: &mut ::Context, template: &::Template, infinitive_ending: &str) -> Option<String> {
+    match template.unnamed_text(0) {
+        None => context
+            .title
+            .as_ref()
+            .and_then(|title| title.strip_suffix(infinitive_ending))
+            .map(String::from),
+        Some(stem) => {
+            let infinitive = format!("{}{}", stem, infinitive_ending);
+            if context
+                .title
+                .as_ref()
+                .is_some_and(|title| !title.contains(&infinitive))
+            {
+                ::add_warning(context, template, ::WarningMessage::ValueConflicting);
+            }
+            Some(stem.into_owned())
+        }
+    }
+}
+
+fn conjugate_czech_dělat(forms: &mut Forms, stem: &str) {
+    forms.push(Infinitive, None, None, format!("{}at", stem));
+    let present = ["ám", "áš", "á", "áme", "áte", "ají"];
+    forms.push_personal(Indicative, Some(Present), stem, &present);
+    forms.push(Imperative, None, Some(SECOND_SINGULAR), format!("{}ej", stem));
+    forms.push(Imperative, None, Some(FIRST_PLURAL), format!("{}ejme", stem));
+    forms.push(Imperative, None, Some(SECOND_PLURAL), format!("{}ejte", stem));
+    forms.push(Participle, Some(Past), None, format!("{}al", stem));
+}
+
+fn conjugate_czech_prosit(forms: &mut Forms, stem: &str) {
+    forms.push(Infinitive, None, None, format!("{}it", stem));
+    let present = ["ím", "íš", "í", "íme", "íte", "í"];
+    forms.push_personal(Indicative, Some(Present), stem, &present);
+    forms.push(Participle, Some(Past), None, format!("{}il", stem));
+}
+
+// Stems ending in `d` or `t` take an `e` before endings starting with `s` or `t`, and stems ending in a sibilant take only `t` in the second person singular. Stems ending in `el` or `er`, such as in `wandern`, drop the `e` of the endings in ways not handled here, so they're not interpreted.
+fn conjugate_german_weak(forms: &mut Forms, stem: &str) {
+    if stem.ends_with("el") || stem.ends_with("er") {
+        return;
+    }
+    let e = if stem.ends_with('d') || stem.ends_with('t') {
+        "e"
+    } else {
+        ""
+    };
+    let second_singular =
+        if e.is_empty() && stem.ends_with(|character| "sßxz".contains(character)) {
+            "t"
+        } else {
+            "st"
+        };
+    // Verbs with an inseparable prefix or the suffix `ier` form the past participle without `ge`.
+    let ge = if stem.ends_with("ier")
+        || ["be", "emp", "ent", "er", "ge", "miss", "ver", "zer"]
+            .iter()
+            .any(|prefix| stem.starts_with(prefix) && stem.chars().count() >= prefix.len() + 3)
+    {
+        ""
+    } else {
+        "ge"
+    };
+    let preterite = [
+        format!("{}te", e),
+        format!("{}test", e),
+        format!("{}te", e),
+        format!("{}ten", e),
+        format!("{}tet", e),
+        format!("{}ten", e),
+    ];
+    let preterite: Vec<_> = preterite.iter().map(String::as_str).collect();
+    let second_singular = format!("{}{}", e, second_singular);
+    let third_singular = format!("{}t", e);
+    forms.push(Infinitive, None, None, format!("{}en", stem));
+    forms.push_personal(
+        Indicative,
+        Some(Present),
+        stem,
+        &["e", &second_singular, &third_singular, "en", &third_singular, "en"],
+    );
+    forms.push_personal(Indicative, Some(Preterite), stem, &preterite);
+    forms.push_personal(Subjunctive, Some(Present), stem, &["e", "est", "e", "en", "et", "en"]);
+    forms.push_personal(Subjunctive, Some(Preterite), stem, &preterite);
+    forms.push(Imperative, None, Some(SECOND_SINGULAR), format!("{}{}", stem, e));
+    forms.push(Imperative, None, Some(SECOND_PLURAL), format!("{}{}t", stem, e));
+    forms.push(Participle, Some(Present), None, format!("{}end", stem));
+    forms.push(Participle, Some(Past), None, format!("{}{}{}t", ge, stem, e));
+}
+
+// The future and conditional are formed from the infinitive.
+fn conjugate_spanish(forms: &mut Forms, stem: &str, class: &SpanishClass) {
+    let infinitive = format!("{}{}", stem, class.infinitive);
+    forms.push(Infinitive, None, None, infinitive.clone());
+    forms.push_personal(Indicative, Some(Present), stem, &class.present);
+    forms.push_personal(Indicative, Some(Preterite), stem, &class.preterite);
+    forms.push_personal(Indicative, Some(Imperfect), stem, &class.imperfect);
+    forms.push_personal(
+        Indicative,
+        Some(Future),
+        &infinitive,
+        &["é", "ás", "á", "emos", "éis", "án"],
+    );
+    forms.push_personal(
+        Conditional,
+        None,
+        &infinitive,
+        &["ía", "ías", "ía", "íamos", "íais", "ían"],
+    );
+    forms.push_personal(Subjunctive, Some(Present), stem, &class.present_subjunctive);
+    forms.push_personal(Subjunctive, Some(Imperfect), stem, &class.imperfect_subjunctive);
+    forms.push(
+        Imperative,
+        None,
+        Some(SECOND_SINGULAR),
+        format!("{}{}", stem, class.imperative_singular),
+    );
+    forms.push(
+        Imperative,
+        None,
+        Some(SECOND_PLURAL),
+        format!("{}{}", stem, class.imperative_plural),
+    );
+    forms.push(Gerund, None, None, format!("{}{}", stem, class.gerund));
+    forms.push(Participle, Some(Past), None, format!("{}{}", stem, class.past_participle));
+}
+
+// The weak verbs of the first conjugation, with the present tense ending in `ar`, such as `kastar`.
+fn conjugate_swedish_weak(forms: &mut Forms, stem: &str) {
+    forms.push(Infinitive, None, None, format!("{}a", stem));
+    forms.push(Indicative, Some(Present), None, format!("{}ar", stem));
+    forms.push(Indicative, Some(Preterite), None, format!("{}ade", stem));
+    forms.push(Supine, None, None, format!("{}at", stem));
+    forms.push(Imperative, None, None, format!("{}a", stem));
+    forms.push(Participle, Some(Present), None, format!("{}ande", stem));
+    forms.push(Participle, Some(Past), None, format!("{}ad", stem));
+}
@@ -0,0 +1,77 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+#[must_use]
+pub fn measure_coverage(
+    language: ::Language,
+    section: ::Range<usize>,
+    warnings: &[::Warning],
+) -> ::Coverage {
+    let mut supplementary = vec![];
+    let mut unrecognized = vec![];
+    for warning in warnings {
+        let ranges = match warning.message {
+            ::WarningMessage::Supplementary => &mut supplementary,
+            ::WarningMessage::Unrecognized
+            | ::WarningMessage::ValueConflicting
+            | ::WarningMessage::ValueUnrecognized => &mut unrecognized,
+            _ => continue,
+        };
+        let start = warning.start.max(section.start);
+        let end = warning.end.min(section.end);
+        if start < end {
+            ranges.push(start..end);
+        }
+    }
+    let supplementary = merge_ranges(supplementary);
+    let unrecognized = merge_ranges(unrecognized);
+    let unrecognized_length = total_length(&unrecognized);
+    // Bytes that are both supplementary and unrecognized are counted as unrecognized.
+    let supplementary_length =
+        total_length(&supplementary) - overlap_length(&supplementary, &unrecognized);
+    let length = section.end - section.start;
+    let total = length.max(1) as f64;
+    ::Coverage {
+        language,
+        parsed: (length - supplementary_length - unrecognized_length) as f64 / total,
+        supplementary: supplementary_length as f64 / total,
+        unrecognized: unrecognized_length as f64 / total,
+    }
+}
+
+// Sorts the ranges and merges the ones that overlap or touch, so the resulting ranges are disjoint and in order.
+fn merge_ranges(mut ranges: Vec<::Range<usize>>) -> Vec<::Range<usize>> {
+    ranges.sort_by_key(|range| range.start);
+    let mut merged: Vec<::Range<usize>> = vec![];
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+// The number of bytes in both sequences of ranges, which are disjoint and in order.
+fn overlap_length(ranges1: &[::Range<usize>], ranges2: &[::Range<usize>]) -> usize {
+    let mut index1 = 0;
+    let mut index2 = 0;
+    let mut length = 0;
+    while let (Some(range1), Some(range2)) = (ranges1.get(index1), ranges2.get(index2)) {
+        length += range1
+            .end
+            .min(range2.end)
+            .saturating_sub(range1.start.max(range2.start));
+        if range1.end < range2.end {
+            index1 += 1;
+        } else {
+            index2 += 1;
+        }
+    }
+    length
+}
+
+fn total_length(ranges: &[::Range<usize>]) -> usize {
+    ranges.iter().map(|range| range.end - range.start).sum()
+}
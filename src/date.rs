@@ -0,0 +1,71 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+#[must_use]
+pub fn parse_years(text: &str) -> Option<::YearRange> {
+    let mut text = text.trim();
+    for prefix in &["from ", "since ", "circa ", "ca. ", "c. ", "c."] {
+        if text.starts_with(prefix) {
+            text = text[prefix.len()..].trim_start();
+        }
+    }
+    let text = text.trim_end_matches('.');
+    if let Some((separator, character)) = text.char_indices().find(|&(index, character)| {
+        (character == '-' || character == '–') && !text[..index].ends_with("mid")
+    }) {
+        let start = parse_years(&text[..separator])?;
+        let end = parse_years(&text[separator + character.len_utf8()..])?;
+        return if start.start <= end.end {
+            Some(::YearRange {
+                start: start.start,
+                end: end.end,
+            })
+        } else {
+            None
+        };
+    }
+    let (part, text) = [("early ", 0), ("mid ", 1), ("mid-", 1), ("late ", 2)]
+        .iter()
+        .find(|(prefix, _)| text.starts_with(prefix))
+        .map_or((None, text), |(prefix, part)| (Some(*part), &text[prefix.len()..]));
+    let (start, length) = if let Some(century) = parse_century(text) {
+        ((century - 1) * 100, 100)
+    } else if text.ends_with('s') && text.len() > 1 && text[..text.len() - 1].ends_with('0') {
+        let decade = parse_year(&text[..text.len() - 1])?;
+        (decade, if decade % 100 == 0 { 100 } else { 10 })
+    } else if part.is_none() {
+        let year = parse_year(text)?;
+        (year, 1)
+    } else {
+        return None;
+    };
+    Some(match part {
+        None => ::YearRange {
+            start,
+            end: start + length - 1,
+        },
+        Some(part) => ::YearRange {
+            start: start + length * part / 3,
+            end: start + length * (part + 1) / 3 - 1,
+        },
+    })
+}
+
+fn parse_century(text: &str) -> Option<i32> {
+    let text = ["th century", "st century", "nd century", "rd century", "th c", "st c", "nd c", "rd c"]
+        .iter()
+        .find(|suffix| text.ends_with(*suffix))
+        .map(|suffix| &text[..text.len() - suffix.len()])?;
+    match text.parse() {
+        Ok(century @ 1..=21) => Some(century),
+        _ => None,
+    }
+}
+
+fn parse_year(text: &str) -> Option<i32> {
+    if text.is_empty() || text.len() > 4 || !text.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    text.parse().ok()
+}
@@ -0,0 +1,139 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+use ::Case::*;
+use ::Number::*;
+
+// The order of the cases in the parameters of `cs-decl-noun`.
+const CZECH_CASES: [::Case; 7] = [
+    Nominative,
+    Genitive,
+    Dative,
+    Accusative,
+    Vocative,
+    Locative,
+    Instrumental,
+];
+
+// Interprets the declension templates of the entry into the forms of the declension.
+pub fn interpret_declension<'a>(context: &mut ::Context<'a, '_>, pos_entry: &mut ::PosEntry<'a>) {
+    for template in &pos_entry.inflection {
+        let mut forms = Forms(vec![]);
+        match &template.name as _ {
+            "cs-decl-noun" => {
+                check_base_form(context, template);
+                decline_czech(&mut forms, template);
+            }
+            "de-decl-noun-f" => if let Some(title) = &context.title {
+                decline_german(&mut forms, title, None, template.unnamed_text(0));
+            },
+            "de-decl-noun-m" | "de-decl-noun-n" => if let Some(title) = &context.title {
+                decline_german(
+                    &mut forms,
+                    title,
+                    template.unnamed_text(0),
+                    template.unnamed_text(1),
+                );
+            },
+            "sv-infl-noun" => {
+                check_base_form(context, template);
+                decline_swedish(&mut forms, template);
+            }
+            _ => {}
+        }
+        pos_entry.declension.extend(forms.0);
+    }
+}
+
+struct Forms<'a>(Vec<::DeclinedForm<'a>>);
+
+impl<'a> Forms<'a> {
+    // The value `-` means the form doesn't exist.
+    fn push(
+        &mut self,
+        case: ::Case,
+        definite: Option<bool>,
+        number: ::Number,
+        form: ::Cow<'a, str>,
+    ) {
+        if !form.is_empty() && form != "-" {
+            self.0.push(::DeclinedForm {
+                case,
+                definite,
+                form,
+                number,
+            });
+        }
+    }
+}
+
+// The first parameter is the base form of the word, which is expected to be the page title if given.
+fn check_base_form(context: &mut ::Context, template: &::Template) {
+    if let (Some(title), Some(form)) = (&context.title, template.unnamed_text(0)) {
+        if *title != form {
+            ::add_warning(context, template, ::WarningMessage::ValueConflicting);
+        }
+    }
+}
+
+// The parameters are the singular and plural of each case in turn.
+fn decline_czech<'a>(forms: &mut Forms<'a>, template: &::Template<'a>) {
+    for (index, case) in CZECH_CASES.iter().enumerate() {
+        for (offset, number) in [Singular, Plural].iter().enumerate() {
+            if let Some(form) = template.unnamed_text(index * 2 + offset) {
+                forms.push(*case, None, *number, form);
+            }
+        }
+    }
+}
+
+// The singular is the page name except for the genitive, and the plural is the same in all cases except that the dative adds `n` unless the plural already ends in `n` or `s`.
+fn decline_german<'a>(
+    forms: &mut Forms<'a>,
+    title: &str,
+    genitive: Option<::Cow<'a, str>>,
+    plural: Option<::Cow<'a, str>>,
+) {
+    for case in &[Nominative, Genitive, Dative, Accusative] {
+        let form = match (case, &genitive) {
+            (Genitive, Some(genitive)) => genitive.clone(),
+            _ => ::Cow::Owned(title.to_string()),
+        };
+        forms.push(*case, None, Singular, form);
+    }
+    if let Some(plural) = plural {
+        for case in &[Nominative, Genitive, Dative, Accusative] {
+            let form = match case {
+                Dative if !plural.ends_with('n') && !plural.ends_with('s') && plural != "-" => {
+                    ::Cow::Owned(format!("{}n", plural))
+                }
+                _ => plural.clone(),
+            };
+            forms.push(*case, None, Plural, form);
+        }
+    }
+}
+
+// The parameters are the indefinite singular, definite singular, indefinite plural and definite plural. The genitive adds `s` unless the form already ends in `s`, `x` or `z`.
+fn decline_swedish<'a>(forms: &mut Forms<'a>, template: &::Template<'a>) {
+    for (index, (definite, number)) in [
+        (false, Singular),
+        (true, Singular),
+        (false, Plural),
+        (true, Plural),
+    ]
+        .iter()
+        .enumerate()
+    {
+        if let Some(form) = template.unnamed_text(index) {
+            let genitive = if form.ends_with(|character| "-sxz".contains(character)) {
+                form.clone()
+            } else {
+                ::Cow::Owned(format!("{}s", form))
+            };
+            forms.push(Nominative, Some(*definite), *number, form);
+            forms.push(Genitive, Some(*definite), *number, genitive);
+        }
+    }
+}
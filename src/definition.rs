@@ -3,38 +3,59 @@
 // the file LICENSE at the top-level directory of this distribution.
 
 pub fn parse_definition<'a>(
-    context: &mut ::Context<'a>,
+    context: &mut ::Context<'a, '_>,
     list_item: &::ListItem<'a>,
 ) -> ::Definition<'a> {
     let mut definition = vec![];
-    let mut definitions = None;
+    let mut definitions = vec![];
     let mut examples = 0;
     let mut quotations = 0;
+    let mut requests = vec![];
     macro_rules! push {
         ($expression:expr) => {{
             definition.push($expression);
             continue;
         }};
     }
-    for node in &list_item.nodes {
+    let mut node_index = 0;
+    while let Some(node) = list_item.nodes.get(node_index) {
+        node_index += 1;
         match node {
             ::Node::DefinitionList { items, .. } => {
                 examples += items.len() as u32;
                 ::add_warning(context, node, ::WarningMessage::Supplementary);
                 continue;
             }
+            ::Node::CharacterEntity { character, .. } => push!(::Flowing::CharacterEntity {
+                character: *character,
+            }),
+            ::Node::Comment { .. } => continue,
+            ::Node::EndTag { name, .. } | ::Node::StartTag { name, .. } if name == "small" => {
+                continue
+            }
+            ::Node::ExternalLink { nodes, .. } => {
+                push!(::flowing::parse_external_link(context, node, nodes))
+            }
+            ::Node::Image { target, text, .. } => {
+                push!(::flowing::parse_image(context, target, text))
+            }
             ::Node::Italic { .. } => push!(::Flowing::Italic),
             ::Node::Link { target, text, .. } => push!(::parse_link(context, node, target, text)),
             ::Node::OrderedList { items, .. } => {
-                definitions = Some(if definitions.is_some() {
-                    ::add_warning(context, node, ::WarningMessage::Duplicate);
-                    vec![]
-                } else {
-                    items
-                        .iter()
-                        .map(|item| parse_definition(context, item))
-                        .collect()
-                });
+                // A nested list is split in several lists when examples or quotations occur between its items.
+                for item in items {
+                    definitions.push(parse_definition(context, item));
+                }
+                continue;
+            }
+            ::Node::StartTag { .. } => if let Some((element, length)) =
+                ::flowing::parse_start_tag(context, &list_item.nodes[node_index - 1..])
+            {
+                node_index += length - 1;
+                push!(element);
+            },
+            ::Node::Tag { name, nodes, .. } if name == "nowiki" => {
+                definition.extend(::flowing::parse_nowiki(context, node, nodes));
                 continue;
             }
             ::Node::Text { value, .. } => push!(::Flowing::Text {
@@ -45,11 +66,30 @@ pub fn parse_definition<'a>(
             } => if let Some(name) = ::parse_text(name) {
                 match &name as _ {
                     "defdate" => push!(parse_definition_date(context, node, parameters)),
+                    "Latn-def" => push!(parse_latin_definition(context, node, parameters)),
+                    "ja-romanization of" => {
+                        let element = parse_romanization_of(context, node, parameters);
+                        if let ::Flowing::Term { .. } = element {
+                            definition.push(::Flowing::Text {
+                                value: ::Cow::Borrowed("Rōmaji transcription of "),
+                            });
+                        }
+                        push!(element)
+                    }
                     "label" | "lb" => push!(parse_labels(context, node, parameters)),
-                    "n-g" | "non-gloss definition" => {
+                    "n-g" | "ngd" | "non-gloss definition" => {
                         push!(parse_non_gloss_definition(context, node, parameters))
                     }
-                    _ => {}
+                    _ => {
+                        if ::request::parse_request(&mut requests, &name) {
+                            continue;
+                        }
+                        if let Some(element) =
+                            ::flowing::parse_template(context, node, &name, parameters)
+                        {
+                            push!(element);
+                        }
+                    }
                 }
             },
             ::Node::UnorderedList { items, .. } => {
@@ -66,45 +106,84 @@ pub fn parse_definition<'a>(
             ::WarningMessage::Unrecognized,
         ))
     }
-    if definition.is_empty() {
+    if definition.is_empty() && requests.is_empty() {
         ::add_warning(context, list_item, ::WarningMessage::Empty);
     }
     ::Definition {
         definition,
-        definitions: definitions.unwrap_or_default(),
+        definitions,
         examples,
         quotations,
+        requests,
+    }
+}
+
+// Leaves out the definitions nested deeper than the given depth, as set by `ParseOptions::max_definition_depth`.
+pub fn limit_depth(definitions: &mut [::Definition], depth: usize) {
+    for definition in definitions {
+        match depth {
+            0 => definition.definitions.clear(),
+            _ => limit_depth(&mut definition.definitions, depth - 1),
+        }
     }
 }
 
 fn parse_definition_date<'a>(
-    context: &mut ::Context<'a>,
+    context: &mut ::Context<'a, '_>,
     template_node: &::Node,
     parameters: &[::Parameter<'a>],
 ) -> ::Flowing<'a> {
-    match parameters {
-        [parameter @ ::Parameter { name: None, .. }] => {
-            match ::parse_text_not_empty(&parameter.value) {
-                None => ::create_unknown(
+    let (parameter, obsolescence_parameter) = match parameters {
+        [parameter @ ::Parameter { name: None, .. }] => (parameter, None),
+        [parameter @ ::Parameter { name: None, .. }, obsolescence_parameter @ ::Parameter { name: None, .. }] => {
+            (parameter, Some(obsolescence_parameter))
+        }
+        _ => {
+            return ::create_unknown(
+                context,
+                template_node,
+                template_node,
+                ::WarningMessage::ValueUnrecognized,
+            )
+        }
+    };
+    let value = match ::parse_text_not_empty(&parameter.value) {
+        None => {
+            return ::create_unknown(
+                context,
+                template_node,
+                parameter,
+                ::WarningMessage::ValueUnrecognized,
+            )
+        }
+        Some(value) => value,
+    };
+    let obsolescence_value = match obsolescence_parameter {
+        None => None,
+        Some(obsolescence_parameter) => match ::parse_text_not_empty(&obsolescence_parameter.value) {
+            None => {
+                return ::create_unknown(
                     context,
                     template_node,
-                    parameter,
+                    obsolescence_parameter,
                     ::WarningMessage::ValueUnrecognized,
-                ),
-                Some(value) => ::Flowing::DefinitionDate { value },
+                )
             }
-        }
-        _ => ::create_unknown(
-            context,
-            template_node,
-            template_node,
-            ::WarningMessage::ValueUnrecognized,
-        ),
+            value => value,
+        },
+    };
+    ::Flowing::DefinitionDate {
+        first_attestation: ::date::parse_years(&value),
+        obsolescence: obsolescence_value
+            .as_ref()
+            .and_then(|value| ::date::parse_years(value)),
+        obsolescence_value,
+        value,
     }
 }
 
 fn parse_labels<'a>(
-    context: &mut ::Context<'a>,
+    context: &mut ::Context<'a, '_>,
     template_node: &::Node,
     parameters: &[::Parameter<'a>],
 ) -> ::Flowing<'a> {
@@ -127,7 +206,7 @@ fn parse_labels<'a>(
                 )
             }
             Some(language) => {
-                if language != context.language.unwrap().language_code() {
+                if language != ::language_code(context) {
                     return ::create_unknown(
                         context,
                         template_node,
@@ -136,17 +215,25 @@ fn parse_labels<'a>(
                     );
                 }
                 if parameters.len() > 1 {
-                    let mut labels = vec![];
+                    let mut conjunction = None;
+                    let mut expect_label = true;
+                    let mut join = false;
+                    let mut labels: Vec<::Label<'a>> = vec![];
+                    // The text of the previous label, and whether it was kept rather than left out as a duplicate.
+                    let mut previous = ::Cow::Borrowed("");
+                    let mut previous_kept = false;
                     for parameter in &parameters[1..] {
                         if parameter.name.is_some() {
                             return ::create_unknown(
                                 context,
                                 template_node,
-                                language_parameter,
+                                parameter,
                                 ::WarningMessage::Unrecognized,
                             );
                         }
-                        match ::parse_text_not_empty(&parameter.value) {
+                        let value = match ::parse_text_not_empty(&parameter.value).map(::trim_text)
+                        {
+                            Some(value) => value,
                             None => {
                                 return ::create_unknown(
                                     context,
@@ -155,14 +242,72 @@ fn parse_labels<'a>(
                                     ::WarningMessage::ValueUnrecognized,
                                 )
                             }
-                            Some(value) => if labels.contains(&value) {
-                                ::add_warning(context, parameter, ::WarningMessage::Duplicate);
-                            } else {
-                                labels.push(value);
-                            },
+                        };
+                        match &value as &str {
+                            "_" | "&" | "and" | "or" => {
+                                let separator_conjunction = match &value as &str {
+                                    "_" => None,
+                                    "or" => Some(true),
+                                    _ => Some(false),
+                                };
+                                if expect_label
+                                    || separator_conjunction.is_some()
+                                        && conjunction.is_some()
+                                        && conjunction != separator_conjunction
+                                {
+                                    return ::create_unknown(
+                                        context,
+                                        template_node,
+                                        parameter,
+                                        ::WarningMessage::ValueUnrecognized,
+                                    );
+                                }
+                                if separator_conjunction.is_some() {
+                                    conjunction = separator_conjunction;
+                                } else {
+                                    join = true;
+                                }
+                                expect_label = true;
+                            }
+                            _ => {
+                                expect_label = false;
+                                let text = if join {
+                                    join = false;
+                                    if previous_kept {
+                                        labels.pop();
+                                    }
+                                    ::Cow::Owned(format!("{} {}", previous, value))
+                                } else {
+                                    value
+                                };
+                                previous = text.clone();
+                                previous_kept = !labels.iter().any(|label| label.text == text);
+                                if !previous_kept {
+                                    ::add_warning(context, parameter, ::WarningMessage::Duplicate);
+                                    continue;
+                                }
+                                labels.push(::Label {
+                                    #[cfg(feature = "label-kinds")]
+                                    kind: ::label::classify_label(&text),
+                                    #[cfg(not(feature = "label-kinds"))]
+                                    kind: None,
+                                    text,
+                                });
+                            }
                         }
                     }
-                    return ::Flowing::Labels { labels };
+                    if expect_label {
+                        return ::create_unknown(
+                            context,
+                            template_node,
+                            template_node,
+                            ::WarningMessage::ValueUnrecognized,
+                        );
+                    }
+                    return ::Flowing::Labels {
+                        disjunctive: conjunction == Some(true),
+                        labels,
+                    };
                 }
             }
         }
@@ -175,8 +320,88 @@ fn parse_labels<'a>(
     )
 }
 
+// Parses the template `Latn-def`, which displays a definition of a letter of the Latin script.
+fn parse_latin_definition<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+) -> ::Flowing<'a> {
+    if let Some(parameter) = parameters.iter().find(|parameter| parameter.name.is_some()) {
+        return ::create_unknown(context, template_node, parameter, ::WarningMessage::Unrecognized);
+    }
+    let values: Option<Vec<_>> = parameters
+        .iter()
+        .map(|parameter| ::parse_text_not_empty(&parameter.value))
+        .collect();
+    let values = match values {
+        None => {
+            return ::create_unknown(
+                context,
+                template_node,
+                template_node,
+                ::WarningMessage::ValueUnrecognized,
+            )
+        }
+        Some(values) => values,
+    };
+    let values: Vec<&str> = values.iter().map(|value| value as _).collect();
+    let value = match &values as &[_] {
+        [language, ..] if *language != ::language_code(context) => {
+            return ::create_unknown(
+                context,
+                template_node,
+                &parameters[0],
+                ::WarningMessage::ValueConflicting,
+            )
+        }
+        [_, "letter", number] | [_, "letter", number, _] => {
+            match number.parse().ok().and_then(ordinal) {
+                None => {
+                    return ::create_unknown(
+                        context,
+                        template_node,
+                        &parameters[2],
+                        ::WarningMessage::ValueUnrecognized,
+                    )
+                }
+                Some(ordinal) => {
+                    let alphabet = match context.registered_language {
+                        None => match context.language.unwrap() {
+                            ::Language::Mul => "basic modern Latin".to_string(),
+                            language => language.name().to_string(),
+                        },
+                        Some(ref language) => language.name.clone(),
+                    };
+                    match values.get(3) {
+                        None => format!(
+                            "The {} letter of the {} alphabet, written in the Latin script.",
+                            ordinal, alphabet
+                        ),
+                        Some(name) => format!(
+                            "The {} letter of the {} alphabet, called {} and written in the Latin script.",
+                            ordinal, alphabet, name
+                        ),
+                    }
+                }
+            }
+        }
+        [_, "name", letter] => format!("The name of the Latin-script letter {}.", letter),
+        _ => {
+            return ::create_unknown(
+                context,
+                template_node,
+                template_node,
+                ::WarningMessage::ValueUnrecognized,
+            )
+        }
+    };
+    ::Flowing::Text {
+        value: ::Cow::Owned(value),
+    }
+}
+
 fn parse_non_gloss_definition<'a>(
-    context: &mut ::Context<'a>,
+    context: &mut ::Context<'a, '_>,
     template_node: &::Node,
     parameters: &[::Parameter<'a>],
 ) -> ::Flowing<'a> {
@@ -208,3 +433,67 @@ fn parse_non_gloss_definition<'a>(
         ),
     }
 }
+
+fn parse_romanization_of<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+) -> ::Flowing<'a> {
+    match parameters {
+        [parameter @ ::Parameter { name: None, .. }] => match ::parse_text_not_empty(&parameter.value) {
+            None => ::create_unknown(
+                context,
+                template_node,
+                parameter,
+                ::WarningMessage::ValueUnrecognized,
+            ),
+            Some(term) => ::Flowing::Term {
+                alternative: None,
+                gloss: None,
+                id: None,
+                language: ::Cow::Borrowed("ja"),
+                literal: None,
+                part_of_speech: None,
+                term,
+                transcription: None,
+                transliteration: None,
+            },
+        },
+        _ => ::create_unknown(
+            context,
+            template_node,
+            template_node,
+            ::WarningMessage::ValueUnrecognized,
+        ),
+    }
+}
+
+// The English ordinal number for the position of a letter in an alphabet.
+fn ordinal(number: usize) -> Option<String> {
+    const ORDINALS: [&str; 19] = [
+        "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth", "ninth",
+        "tenth", "eleventh", "twelfth", "thirteenth", "fourteenth", "fifteenth", "sixteenth",
+        "seventeenth", "eighteenth", "nineteenth",
+    ];
+    const TENS: [(&str, &str); 8] = [
+        ("twenty", "twentieth"),
+        ("thirty", "thirtieth"),
+        ("forty", "fortieth"),
+        ("fifty", "fiftieth"),
+        ("sixty", "sixtieth"),
+        ("seventy", "seventieth"),
+        ("eighty", "eightieth"),
+        ("ninety", "ninetieth"),
+    ];
+    match number {
+        1..=19 => Some(ORDINALS[number - 1].to_string()),
+        20..=99 => {
+            let (cardinal, ordinal) = TENS[number / 10 - 2];
+            Some(match number % 10 {
+                0 => ordinal.to_string(),
+                ones => format!("{}-{}", cardinal, ORDINALS[ones - 1]),
+            })
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,744 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+#[derive(Default)]
+pub struct Etymology<'a> {
+    pub cognates: Vec<::Cognate<'a>>,
+    pub derivations: Vec<::Derivation<'a>>,
+    pub doublets: Vec<::Cow<'a, str>>,
+    pub onomatopoeic: bool,
+    pub pie_roots: Vec<::Cow<'a, str>>,
+    pub requests: Vec<::Request>,
+    pub text: Vec<::Flowing<'a>>,
+    pub uncertain_origin: bool,
+    pub unknown_origin: bool,
+    pub word_formations: Vec<::WordFormation<'a>>,
+}
+
+#[derive(Default)]
+struct Part<'a> {
+    alternative: Option<::Cow<'a, str>>,
+    gloss: Option<::Cow<'a, str>>,
+    id: Option<::Cow<'a, str>>,
+    language: Option<::Cow<'a, str>>,
+    literal: Option<::Cow<'a, str>>,
+    part_of_speech: Option<::Cow<'a, str>>,
+    term: Option<::Cow<'a, str>>,
+    transcription: Option<::Cow<'a, str>>,
+    transliteration: Option<::Cow<'a, str>>,
+}
+
+struct SourceTerm<'a> {
+    gloss: Option<::Cow<'a, str>>,
+    language: ::Cow<'a, str>,
+    term: Option<::Cow<'a, str>>,
+}
+
+pub fn parse_etymology<'a>(
+    context: &mut ::Context<'a, '_>,
+    heading_node: &::Node,
+    nodes: &[::Node<'a>],
+    output: &mut Option<Etymology<'a>>,
+) -> usize {
+    if output.is_some() {
+        ::add_warning(context, heading_node, ::WarningMessage::Duplicate);
+    }
+    let mut etymology = output.take().unwrap_or_default();
+    let node_index = parse_etymology_nodes(context, nodes, &mut etymology);
+    *output = Some(etymology);
+    node_index
+}
+
+pub fn parse_etymology_nodes<'a>(
+    context: &mut ::Context<'a, '_>,
+    nodes: &[::Node<'a>],
+    etymology: &mut Etymology<'a>,
+) -> usize {
+    let mut node_index = 0;
+    macro_rules! push {
+        ($expression:expr) => {{
+            node_index += 1;
+            etymology.text.push($expression);
+            continue;
+        }};
+    }
+    while let Some(node) = nodes.get(node_index) {
+        match node {
+            ::Node::Bold { .. } => push!(::Flowing::Bold),
+            ::Node::CharacterEntity { character, .. } => push!(::Flowing::CharacterEntity {
+                character: *character,
+            }),
+            ::Node::Comment { .. } => {
+                node_index += 1;
+                continue;
+            }
+            ::Node::EndTag { name, .. } | ::Node::StartTag { name, .. } if name == "small" => {
+                node_index += 1;
+                continue;
+            }
+            ::Node::ExternalLink { nodes, .. } => {
+                push!(::flowing::parse_external_link(context, node, nodes))
+            }
+            ::Node::Heading { .. } => break,
+            ::Node::Image { target, text, .. } => {
+                push!(::flowing::parse_image(context, target, text))
+            }
+            ::Node::Italic { .. } => push!(::Flowing::Italic),
+            ::Node::Link { target, text, .. } => push!(::parse_link(context, node, target, text)),
+            ::Node::ParagraphBreak { .. } => push!(::Flowing::ParagraphBreak),
+            ::Node::StartTag { .. } => if let Some((element, length)) =
+                ::flowing::parse_start_tag(context, &nodes[node_index..])
+            {
+                node_index += length - 1;
+                push!(element);
+            },
+            ::Node::Tag { name, .. } if name == "ref" => {
+                ::add_warning(context, node, ::WarningMessage::Supplementary);
+                push!(::Flowing::Reference);
+            }
+            ::Node::Tag { name, nodes, .. } if name == "nowiki" => {
+                node_index += 1;
+                etymology.text.extend(::flowing::parse_nowiki(context, node, nodes));
+                continue;
+            }
+            ::Node::Template {
+                name, parameters, ..
+            } => if let Some(name) = ::parse_text(name) {
+                macro_rules! parse {
+                    ($expression:expr) => {{
+                        node_index += 1;
+                        $expression;
+                        continue;
+                    }};
+                }
+                match &name as _ {
+                    "PIE root" => parse!(parse_pie_root(
+                        context,
+                        node,
+                        parameters,
+                        &mut etymology.pie_roots
+                    )),
+                    "af" | "affix" => parse!(parse_word_formation(
+                        context,
+                        node,
+                        parameters,
+                        ::WordFormationKind::Affix,
+                        etymology
+                    )),
+                    "back-form" | "back-formation" | "bf" => parse!(parse_single_word_formation(
+                        context,
+                        node,
+                        parameters,
+                        ::WordFormationKind::BackFormation,
+                        etymology
+                    )),
+                    "blend" => parse!(parse_word_formation(
+                        context,
+                        node,
+                        parameters,
+                        ::WordFormationKind::Blend,
+                        etymology
+                    )),
+                    "bor" | "borrowed" => parse!(parse_derivation(
+                        context,
+                        node,
+                        parameters,
+                        ::DerivationKind::Borrowed,
+                        etymology
+                    )),
+                    "cal" | "calq" | "calque" => parse!(parse_derivation(
+                        context,
+                        node,
+                        parameters,
+                        ::DerivationKind::Calque,
+                        etymology
+                    )),
+                    "clip" | "clipping" => parse!(parse_single_word_formation(
+                        context,
+                        node,
+                        parameters,
+                        ::WordFormationKind::Clipping,
+                        etymology
+                    )),
+                    "cog" | "cognate" => {
+                        parse!(parse_cognate(context, node, parameters, false, etymology))
+                    }
+                    "com" | "compound" => parse!(parse_word_formation(
+                        context,
+                        node,
+                        parameters,
+                        ::WordFormationKind::Compound,
+                        etymology
+                    )),
+                    "confix" => parse!(parse_word_formation(
+                        context,
+                        node,
+                        parameters,
+                        ::WordFormationKind::Confix,
+                        etymology
+                    )),
+                    "dbt" | "doublet" => {
+                        parse!(parse_doublet(context, node, parameters, etymology))
+                    }
+                    "der" | "derived" => parse!(parse_derivation(
+                        context,
+                        node,
+                        parameters,
+                        ::DerivationKind::Derived,
+                        etymology
+                    )),
+                    "inh" | "inherited" => parse!(parse_derivation(
+                        context,
+                        node,
+                        parameters,
+                        ::DerivationKind::Inherited,
+                        etymology
+                    )),
+                    "lbor" | "learned borrowing" => parse!(parse_derivation(
+                        context,
+                        node,
+                        parameters,
+                        ::DerivationKind::LearnedBorrowing,
+                        etymology
+                    )),
+                    "nc" | "ncog" | "noncog" | "noncognate" => {
+                        parse!(parse_cognate(context, node, parameters, true, etymology))
+                    }
+                    "onom" | "onomatopoeic" => {
+                        node_index += 1;
+                        etymology.onomatopoeic |= parse_origin(
+                            context,
+                            node,
+                            parameters,
+                            "Onomatopoeic",
+                            &mut etymology.text,
+                        );
+                        continue;
+                    }
+                    "pre" | "prefix" => parse!(parse_word_formation(
+                        context,
+                        node,
+                        parameters,
+                        ::WordFormationKind::Prefix,
+                        etymology
+                    )),
+                    "suf" | "suffix" => parse!(parse_word_formation(
+                        context,
+                        node,
+                        parameters,
+                        ::WordFormationKind::Suffix,
+                        etymology
+                    )),
+                    "unc" | "uncertain" => {
+                        node_index += 1;
+                        etymology.uncertain_origin |= parse_origin(
+                            context,
+                            node,
+                            parameters,
+                            "Uncertain",
+                            &mut etymology.text,
+                        );
+                        continue;
+                    }
+                    "unk" | "unknown" => {
+                        node_index += 1;
+                        etymology.unknown_origin |= parse_origin(
+                            context,
+                            node,
+                            parameters,
+                            "Unknown",
+                            &mut etymology.text,
+                        );
+                        continue;
+                    }
+                    _ => {}
+                }
+                if ::request::parse_request(&mut etymology.requests, &name)
+                    || ::category::parse_category(context, node, &name, parameters)
+                {
+                    node_index += 1;
+                    continue;
+                }
+                if let Some(element) = ::flowing::parse_template(context, node, &name, parameters) {
+                    push!(element);
+                }
+            },
+            ::Node::Text { value, .. } => push!(::Flowing::Text {
+                value: ::Cow::Borrowed(value)
+            }),
+            _ => {}
+        }
+        push!(::create_unknown(
+            context,
+            node,
+            node,
+            ::WarningMessage::Unrecognized,
+        ));
+    }
+    node_index
+}
+
+fn parse_cognate<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+    noncognate: bool,
+    etymology: &mut Etymology<'a>,
+) {
+    if let Some(source_term) =
+        parse_source_term(context, template_node, parameters, false, 0, &mut etymology.text)
+    {
+        etymology.cognates.push(::Cognate {
+            gloss: source_term.gloss,
+            language: source_term.language,
+            noncognate,
+            term: source_term.term,
+        });
+    }
+}
+
+fn parse_derivation<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+    kind: ::DerivationKind,
+    etymology: &mut Etymology<'a>,
+) {
+    if let Some(source_term) =
+        parse_source_term(context, template_node, parameters, true, 1, &mut etymology.text)
+    {
+        etymology.derivations.push(::Derivation {
+            gloss: source_term.gloss,
+            kind,
+            language: source_term.language,
+            term: source_term.term,
+        });
+    }
+}
+
+fn parse_pie_root<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+    output: &mut Vec<::Cow<'a, str>>,
+) {
+    let mut has_language = false;
+    let mut roots = vec![];
+    for parameter in parameters {
+        if parameter.name.is_some() {
+            if ::parse_parameter_name(parameter) != Some("id") {
+                ::add_warning(context, parameter, ::WarningMessage::Unrecognized);
+                return;
+            }
+            continue;
+        }
+        match ::parse_text_not_empty(&parameter.value) {
+            None => {
+                ::add_warning(context, parameter, ::WarningMessage::ValueUnrecognized);
+                return;
+            }
+            Some(value) => if !has_language {
+                if value != ::language_code(context) {
+                    ::add_warning(context, parameter, ::WarningMessage::ValueConflicting);
+                    return;
+                }
+                has_language = true;
+            } else if output.contains(&value) || roots.contains(&value) {
+                ::add_warning(context, parameter, ::WarningMessage::Duplicate);
+            } else {
+                roots.push(value);
+            },
+        }
+    }
+    if roots.is_empty() {
+        ::add_warning(context, template_node, ::WarningMessage::Empty);
+    }
+    output.extend(roots);
+}
+
+// Parses the parameters that templates for etymology have in common with `mention`, after any skipped parameters, and adds the term to the text. If the template has the language of the entry as its first parameter, it's checked.
+fn parse_source_term<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+    has_language: bool,
+    skipped_parameters: usize,
+    text: &mut Vec<::Flowing<'a>>,
+) -> Option<SourceTerm<'a>> {
+    let unnamed_parameters: Vec<_> = parameters
+        .iter()
+        .filter(|parameter| parameter.name.is_none())
+        .collect();
+    if has_language {
+        if let Some(parameter) = unnamed_parameters.first() {
+            if !check_language(context, template_node, parameter, text) {
+                return None;
+            }
+        }
+    }
+    // A template without a specific term, such as `{{der|en|la}}`, displays only the name of the language.
+    if unnamed_parameters.len() < skipped_parameters + 2 {
+        return match unnamed_parameters
+            .get(skipped_parameters)
+            .and_then(|parameter| ::parse_text_not_empty(&parameter.value))
+        {
+            None => {
+                text.push(::create_unknown(
+                    context,
+                    template_node,
+                    template_node,
+                    ::WarningMessage::ValueUnrecognized,
+                ));
+                None
+            }
+            Some(language) => Some(SourceTerm {
+                gloss: None,
+                language,
+                term: None,
+            }),
+        };
+    }
+    let element = ::flowing::parse_term(context, template_node, parameters, skipped_parameters);
+    let source_term = match &element {
+        ::Flowing::Term {
+            gloss,
+            language,
+            term,
+            ..
+        } => Some(SourceTerm {
+            gloss: gloss.clone(),
+            language: language.clone(),
+            term: Some(term.clone()).filter(|term| term != "-" && !term.is_empty()),
+        }),
+        _ => None,
+    };
+    // The term `-` means there is no specific term, and only the name of the language is displayed.
+    if !matches!(&element, ::Flowing::Term { term, .. } if term == "-") {
+        text.push(element);
+    }
+    source_term
+}
+
+fn check_language<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameter: &::Parameter<'a>,
+    text: &mut Vec<::Flowing<'a>>,
+) -> bool {
+    if ::parse_text(&parameter.value).as_ref().map(|value| value as _)
+        == Some(::language_code(context))
+    {
+        return true;
+    }
+    text.push(::create_unknown(
+        context,
+        template_node,
+        parameter,
+        ::WarningMessage::ValueConflicting,
+    ));
+    false
+}
+
+fn create_part_term<'a>(context: &::Context<'a, '_>, part: Part<'a>) -> ::Flowing<'a> {
+    ::Flowing::Term {
+        alternative: part.alternative,
+        gloss: part.gloss,
+        id: part.id,
+        language: part
+            .language
+            .unwrap_or_else(|| ::Cow::Owned(::language_code(context).to_string())),
+        literal: part.literal,
+        part_of_speech: part.part_of_speech,
+        term: part.term.unwrap(),
+        transcription: part.transcription,
+        transliteration: part.transliteration,
+    }
+}
+
+fn parse_doublet<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+    etymology: &mut Etymology<'a>,
+) {
+    if let Some(parts) = parse_parts(context, template_node, parameters, 1, &mut etymology.text) {
+        for (index, part) in parts.into_iter().enumerate() {
+            if index > 0 {
+                etymology.text.push(::Flowing::Text {
+                    value: ::Cow::Borrowed(", "),
+                });
+            }
+            let term = part.term.clone().unwrap();
+            if !etymology.doublets.contains(&term) {
+                etymology.doublets.push(term);
+            }
+            etymology.text.push(create_part_term(context, part));
+        }
+    }
+}
+
+// Templates that state the kind of origin display a fixed text, which the parameters can change or hide.
+fn parse_origin<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+    default_text: &'static str,
+    text: &mut Vec<::Flowing<'a>>,
+) -> bool {
+    let mut has_language = false;
+    let mut lowercase = false;
+    let mut no_text = false;
+    let mut title = None;
+    for parameter in parameters {
+        match parameter.name {
+            None => if has_language {
+                text.push(::create_unknown(
+                    context,
+                    template_node,
+                    parameter,
+                    ::WarningMessage::Unrecognized,
+                ));
+                return false;
+            } else {
+                if !check_language(context, template_node, parameter, text) {
+                    return false;
+                }
+                has_language = true;
+            },
+            Some(_) => match ::parse_parameter_name(parameter) {
+                Some("nocap") => lowercase = true,
+                Some("nocat") | Some("sort") => {}
+                Some("notext") => no_text = true,
+                Some("title") => match ::parse_text(&parameter.value) {
+                    None => {
+                        text.push(::create_unknown(
+                            context,
+                            template_node,
+                            parameter,
+                            ::WarningMessage::ValueUnrecognized,
+                        ));
+                        return false;
+                    }
+                    Some(value) => title = Some(value),
+                },
+                _ => {
+                    text.push(::create_unknown(
+                        context,
+                        template_node,
+                        parameter,
+                        ::WarningMessage::Unrecognized,
+                    ));
+                    return false;
+                }
+            },
+        }
+    }
+    if !has_language {
+        text.push(::create_unknown(
+            context,
+            template_node,
+            template_node,
+            ::WarningMessage::ValueUnrecognized,
+        ));
+        return false;
+    }
+    if !no_text {
+        text.push(::Flowing::Text {
+            value: match title {
+                None if lowercase => ::Cow::Owned(default_text.to_lowercase()),
+                None => ::Cow::Borrowed(default_text),
+                Some(title) => title,
+            },
+        });
+    }
+    true
+}
+
+// The parts of templates for etymology with multiple terms have numbered parameters, as in `t1` for the gloss of the first part.
+fn parse_parts<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+    minimum_parts: usize,
+    text: &mut Vec<::Flowing<'a>>,
+) -> Option<Vec<Part<'a>>> {
+    let mut has_language = false;
+    // A numbered parameter refers to at most one part beyond the unnamed parameters after the language. Higher numbers are not recognized, so a parameter such as `t400000000` doesn't allocate that many parts.
+    let maximum_index = parameters
+        .iter()
+        .filter(|parameter| parameter.name.is_none())
+        .count();
+    let mut parts: Vec<Part> = vec![];
+    let mut unnamed_index = 0;
+    for parameter in parameters {
+        let (index, output) = match parameter.name {
+            None => {
+                if !has_language {
+                    if !check_language(context, template_node, parameter, text) {
+                        return None;
+                    }
+                    has_language = true;
+                    continue;
+                }
+                unnamed_index += 1;
+                (unnamed_index, "")
+            }
+            Some(_) => match ::parse_parameter_name(parameter).and_then(|name| {
+                let digits = name.find(|character: char| character.is_ascii_digit())?;
+                Some((name[digits..].parse().ok()?, &name[..digits]))
+            }) {
+                Some((index, name)) if index > 0 && index <= maximum_index => (index, name),
+                _ => match ::parse_parameter_name(parameter) {
+                    Some("lit") | Some("pos") => {
+                        ::add_warning(context, parameter, ::WarningMessage::Supplementary);
+                        continue;
+                    }
+                    Some("nocap") | Some("nocat") | Some("sc") | Some("sort") => continue,
+                    _ => {
+                        text.push(::create_unknown(
+                            context,
+                            template_node,
+                            parameter,
+                            ::WarningMessage::Unrecognized,
+                        ));
+                        return None;
+                    }
+                },
+            },
+        };
+        if parts.len() < index {
+            parts.resize_with(index, Default::default);
+        }
+        let part = &mut parts[index - 1];
+        let output = match output {
+            "" => &mut part.term,
+            "alt" => &mut part.alternative,
+            "g" => {
+                ::add_warning(context, parameter, ::WarningMessage::Supplementary);
+                continue;
+            }
+            "gloss" | "t" => &mut part.gloss,
+            "id" => &mut part.id,
+            "lang" => &mut part.language,
+            "lit" => &mut part.literal,
+            "pos" => &mut part.part_of_speech,
+            "sc" => continue,
+            "tr" => &mut part.transliteration,
+            "ts" => &mut part.transcription,
+            _ => {
+                text.push(::create_unknown(
+                    context,
+                    template_node,
+                    parameter,
+                    ::WarningMessage::Unrecognized,
+                ));
+                return None;
+            }
+        };
+        match ::parse_text(&parameter.value) {
+            None => {
+                text.push(::create_unknown(
+                    context,
+                    template_node,
+                    parameter,
+                    ::WarningMessage::ValueUnrecognized,
+                ));
+                return None;
+            }
+            Some(value) => {
+                if output.is_some() {
+                    ::add_warning(context, parameter, ::WarningMessage::Duplicate);
+                }
+                *output = Some(value).filter(|value| !value.is_empty());
+            }
+        }
+    }
+    if parts.len() < minimum_parts || parts.iter().any(|part| part.term.is_none()) {
+        text.push(::create_unknown(
+            context,
+            template_node,
+            template_node,
+            ::WarningMessage::ValueUnrecognized,
+        ));
+        return None;
+    }
+    Some(parts)
+}
+
+// Back-formations and clippings have a single term in the language of the entry, with the same parameters as `mention`.
+fn parse_single_word_formation<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+    kind: ::WordFormationKind,
+    etymology: &mut Etymology<'a>,
+) {
+    if let Some(source_term) =
+        parse_source_term(context, template_node, parameters, true, 0, &mut etymology.text)
+    {
+        match source_term.term {
+            None => ::add_warning(context, template_node, ::WarningMessage::Empty),
+            Some(term) => etymology.word_formations.push(::WordFormation {
+                kind,
+                morphemes: vec![::Morpheme {
+                    gloss: source_term.gloss,
+                    language: source_term.language,
+                    term,
+                }],
+            }),
+        }
+    }
+}
+
+fn parse_word_formation<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+    kind: ::WordFormationKind,
+    etymology: &mut Etymology<'a>,
+) {
+    let parts = match parse_parts(context, template_node, parameters, 2, &mut etymology.text) {
+        None => return,
+        Some(parts) => parts,
+    };
+    let last_index = parts.len() - 1;
+    let mut morphemes = vec![];
+    for (index, mut part) in parts.into_iter().enumerate() {
+        // The templates for prefixes and suffixes take the affixes without hyphens.
+        let (is_prefix, is_suffix) = match kind {
+            ::WordFormationKind::Confix => (index == 0, index == last_index),
+            ::WordFormationKind::Prefix => (index < last_index, false),
+            ::WordFormationKind::Suffix => (false, index > 0),
+            _ => (false, false),
+        };
+        let term = part.term.as_mut().unwrap();
+        if is_prefix && !term.ends_with('-') {
+            term.to_mut().push('-');
+        }
+        if is_suffix && !term.starts_with('-') {
+            term.to_mut().insert(0, '-');
+        }
+        if index > 0 {
+            etymology.text.push(::Flowing::Text {
+                value: ::Cow::Borrowed(" + "),
+            });
+        }
+        let element = create_part_term(context, part);
+        if let ::Flowing::Term {
+            gloss,
+            language,
+            term,
+            ..
+        } = &element
+        {
+            morphemes.push(::Morpheme {
+                gloss: gloss.clone(),
+                language: language.clone(),
+                term: term.clone(),
+            });
+        }
+        etymology.text.push(element);
+    }
+    etymology.word_formations.push(::WordFormation { kind, morphemes });
+}
@@ -0,0 +1,505 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+pub fn parse_flowing<'a>(context: &mut ::Context<'a, '_>, nodes: &[::Node<'a>]) -> Vec<::Flowing<'a>> {
+    let mut node_index = 0;
+    let mut output = vec![];
+    while let Some(node) = nodes.get(node_index) {
+        match node {
+            ::Node::Bold { .. } => {
+                node_index += 1;
+                output.push(::Flowing::Bold);
+                continue;
+            }
+            ::Node::CharacterEntity { character, .. } => {
+                node_index += 1;
+                output.push(::Flowing::CharacterEntity {
+                    character: *character,
+                });
+                continue;
+            }
+            ::Node::Comment { .. } => {
+                node_index += 1;
+                continue;
+            }
+            ::Node::EndTag { name, .. } | ::Node::StartTag { name, .. } if name == "small" => {
+                node_index += 1;
+                continue;
+            }
+            ::Node::ExternalLink { nodes: link_nodes, .. } => {
+                node_index += 1;
+                output.push(parse_external_link(context, node, link_nodes));
+                continue;
+            }
+            ::Node::Image { target, text, .. } => {
+                node_index += 1;
+                output.push(parse_image(context, target, text));
+                continue;
+            }
+            ::Node::Italic { .. } => {
+                node_index += 1;
+                output.push(::Flowing::Italic);
+                continue;
+            }
+            ::Node::Link { target, text, .. } => {
+                node_index += 1;
+                output.push(::parse_link(context, node, target, text));
+                continue;
+            }
+            ::Node::StartTag { .. } => if let Some((element, length)) =
+                parse_start_tag(context, &nodes[node_index..])
+            {
+                node_index += length;
+                output.push(element);
+                continue;
+            },
+            ::Node::Table { captions, rows, .. } => {
+                node_index += 1;
+                output.push(parse_table_element(context, captions, rows));
+                continue;
+            }
+            ::Node::Tag { name, nodes, .. } if name == "nowiki" => {
+                node_index += 1;
+                output.extend(parse_nowiki(context, node, nodes));
+                continue;
+            }
+            ::Node::Template {
+                name, parameters, ..
+            } => if let Some(name) = ::parse_text(name) {
+                if let Some(element) = parse_template(context, node, &name, parameters) {
+                    node_index += 1;
+                    output.push(element);
+                    continue;
+                }
+            },
+            ::Node::Text { value, .. } => {
+                node_index += 1;
+                output.push(::Flowing::Text {
+                    value: ::Cow::Borrowed(value),
+                });
+                continue;
+            }
+            _ => {}
+        }
+        node_index += 1;
+        output.push(::create_unknown(
+            context,
+            node,
+            node,
+            ::WarningMessage::Unrecognized,
+        ));
+    }
+    output
+}
+
+#[must_use]
+pub fn parse_external_link<'a>(
+    context: &mut ::Context<'a, '_>,
+    node: &::Node,
+    nodes: &[::Node<'a>],
+) -> ::Flowing<'a> {
+    match nodes.split_first() {
+        Some((::Node::Text { value, .. }, rest)) => {
+            let (url, text) = match value.find(' ') {
+                None => (*value, ""),
+                Some(index) => (&value[..index], value[index + 1..].trim_start()),
+            };
+            let mut text = if text.is_empty() {
+                vec![]
+            } else {
+                vec![::Flowing::Text {
+                    value: ::Cow::Borrowed(text),
+                }]
+            };
+            text.extend(parse_flowing(context, rest));
+            ::Flowing::ExternalLink {
+                text,
+                url: ::Cow::Borrowed(url),
+            }
+        }
+        _ => ::create_unknown(context, node, node, ::WarningMessage::Unrecognized),
+    }
+}
+
+#[must_use]
+pub fn parse_table<'a>(
+    context: &mut ::Context<'a, '_>,
+    captions: &[::parse_wiki_text::TableCaption<'a>],
+    rows: &[::parse_wiki_text::TableRow<'a>],
+) -> ::Table<'a> {
+    ::Table {
+        captions: captions
+            .iter()
+            .map(|caption| parse_table_content(context, &caption.content))
+            .collect(),
+        rows: rows
+            .iter()
+            .filter(|row| !row.cells.is_empty())
+            .map(|row| {
+                row.cells
+                    .iter()
+                    .map(|cell| ::TableCell {
+                        heading: cell.type_ == ::parse_wiki_text::TableCellType::Heading,
+                        value: parse_table_content(context, &cell.content),
+                    })
+                    .collect()
+            })
+            .collect(),
+    }
+}
+
+// Parses a table in flowing text, which becomes the element `Table`.
+#[must_use]
+pub fn parse_table_element<'a>(
+    context: &mut ::Context<'a, '_>,
+    captions: &[::parse_wiki_text::TableCaption<'a>],
+    rows: &[::parse_wiki_text::TableRow<'a>],
+) -> ::Flowing<'a> {
+    let ::Table { captions, rows } = parse_table(context, captions, rows);
+    ::Flowing::Table { captions, rows }
+}
+
+#[must_use]
+pub fn parse_template<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    template_name: &str,
+    parameters: &[::Parameter<'a>],
+) -> Option<::Flowing<'a>> {
+    let text = match template_name {
+        "l" | "link" | "m" | "mention" => return Some(parse_term(context, template_node, parameters, 0)),
+        "w" => return Some(parse_wikipedia_link(context, template_node, parameters)),
+        "!" => "|",
+        "," => ",",
+        "..." => "[…]",
+        "=" => "=",
+        "mdash" => "—",
+        "nbsp" => "\u{a0}",
+        "ndash" => "–",
+        "smallcaps" | "sub" | "sup" => {
+            return Some(match parameters {
+                [parameter @ ::Parameter { name: None, .. }] => {
+                    let value = parse_flowing(context, &parameter.value);
+                    if value.is_empty() {
+                        ::create_unknown(context, template_node, parameter, ::WarningMessage::Empty)
+                    } else {
+                        match template_name {
+                            "sub" => ::Flowing::Subscript { value },
+                            "sup" => ::Flowing::Superscript { value },
+                            _ => ::Flowing::SmallCapitals { value },
+                        }
+                    }
+                }
+                _ => ::create_unknown(
+                    context,
+                    template_node,
+                    template_node,
+                    ::WarningMessage::ValueUnrecognized,
+                ),
+            })
+        }
+        _ => return None,
+    };
+    Some(if parameters.is_empty() {
+        ::Flowing::Text {
+            value: ::Cow::Borrowed(text),
+        }
+    } else {
+        ::create_unknown(
+            context,
+            template_node,
+            template_node,
+            ::WarningMessage::ValueUnrecognized,
+        )
+    })
+}
+
+#[must_use]
+pub fn parse_image<'a>(
+    context: &mut ::Context<'a, '_>,
+    target: &'a str,
+    nodes: &[::Node<'a>],
+) -> ::Flowing<'a> {
+    // The caption is the last of the parameters separated by `|`, unless that parameter is a formatting option.
+    let mut caption_start = (0, 0);
+    for (node_index, node) in nodes.iter().enumerate() {
+        if let ::Node::Text { value, .. } = node {
+            if let Some(index) = value.rfind('|') {
+                caption_start = (node_index, index + 1);
+            }
+        }
+    }
+    let mut caption = vec![];
+    let (node_index, offset) = caption_start;
+    match nodes.get(node_index) {
+        Some(::Node::Text { value, .. }) => {
+            let value = &value[offset..];
+            if !value.is_empty() && (node_index + 1 < nodes.len() || !is_image_option(value)) {
+                caption.push(::Flowing::Text {
+                    value: ::Cow::Borrowed(value),
+                });
+            }
+            caption.extend(parse_flowing(context, &nodes[node_index + 1..]));
+        }
+        _ => caption.extend(parse_flowing(context, &nodes[node_index..])),
+    }
+    ::Flowing::Media {
+        caption,
+        file: ::Cow::Borrowed(target.split_once(':').map_or(target, |(_, file)| file).trim()),
+    }
+}
+
+// The content of the tag `nowiki` is text, possibly split up by character entities, which is concatenated. Nothing is returned if the tag is empty.
+#[must_use]
+pub fn parse_nowiki<'a>(
+    context: &mut ::Context<'a, '_>,
+    node: &::Node,
+    nodes: &[::Node<'a>],
+) -> Option<::Flowing<'a>> {
+    match ::parse_text(nodes) {
+        None => Some(::create_unknown(context, node, node, ::WarningMessage::Unrecognized)),
+        Some(value) => if value.is_empty() {
+            None
+        } else {
+            Some(::Flowing::Text { value })
+        },
+    }
+}
+
+#[must_use]
+pub fn parse_start_tag<'a>(
+    context: &mut ::Context<'a, '_>,
+    nodes: &[::Node<'a>],
+) -> Option<(::Flowing<'a>, usize)> {
+    let tag_name = match nodes.first() {
+        Some(::Node::StartTag { name, .. }) => name,
+        _ => return None,
+    };
+    match tag_name as &str {
+        "br" => return Some((::Flowing::LineBreak, 1)),
+        "sub" | "sup" => {}
+        _ => return None,
+    }
+    let mut depth = 0;
+    for (node_index, node) in nodes.iter().enumerate().skip(1) {
+        match node {
+            ::Node::EndTag { name, .. } if name == tag_name => {
+                if depth > 0 {
+                    depth -= 1;
+                    continue;
+                }
+                let value = parse_flowing(context, &nodes[1..node_index]);
+                if value.is_empty() {
+                    ::add_warning(context, &nodes[0], ::WarningMessage::Empty);
+                }
+                return Some((
+                    if tag_name == "sub" {
+                        ::Flowing::Subscript { value }
+                    } else {
+                        ::Flowing::Superscript { value }
+                    },
+                    node_index + 1,
+                ));
+            }
+            ::Node::StartTag { name, .. } if name == tag_name => depth += 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn parse_table_content<'a>(
+    context: &mut ::Context<'a, '_>,
+    nodes: &[::Node<'a>],
+) -> Vec<::Flowing<'a>> {
+    // Cells commonly have whitespace around their content, as in `| value`.
+    let mut output = parse_flowing(context, nodes);
+    if let Some(::Flowing::Text { value: ::Cow::Borrowed(value) }) = output.first_mut() {
+        *value = value.trim_start();
+    }
+    if let Some(::Flowing::Text { value: ::Cow::Borrowed(value) }) = output.last_mut() {
+        *value = value.trim_end();
+    }
+    output.retain(|element| match element {
+        ::Flowing::Text { value } => !value.is_empty(),
+        _ => true,
+    });
+    output
+}
+
+// Templates for etymology have the same parameters as `mention` after one or more additional unnamed parameters, which are skipped.
+pub fn parse_term<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+    skipped_parameters: usize,
+) -> ::Flowing<'a> {
+    let mut alternative = None;
+    let mut gloss = None;
+    let mut id = None;
+    let mut language = None;
+    let mut literal = None;
+    let mut part_of_speech = None;
+    let mut term = None;
+    let mut transcription = None;
+    let mut transliteration = None;
+    let mut unnamed_index = 0;
+    for parameter in parameters {
+        let output = match parameter.name {
+            None => {
+                unnamed_index += 1;
+                if unnamed_index <= skipped_parameters {
+                    continue;
+                }
+                match unnamed_index - skipped_parameters {
+                    1 => &mut language,
+                    2 => &mut term,
+                    3 => &mut alternative,
+                    4 => &mut gloss,
+                    _ => {
+                        return ::create_unknown(
+                            context,
+                            template_node,
+                            parameter,
+                            ::WarningMessage::Unrecognized,
+                        )
+                    }
+                }
+            }
+            Some(_) => match ::parse_parameter_name(parameter) {
+                Some("alt") => &mut alternative,
+                Some("g") | Some("g2") | Some("g3") => {
+                    ::add_warning(context, parameter, ::WarningMessage::Supplementary);
+                    continue;
+                }
+                Some("gloss") | Some("t") => &mut gloss,
+                Some("id") => &mut id,
+                Some("lit") => &mut literal,
+                Some("nocap") | Some("nocat") | Some("notext") | Some("sort") if skipped_parameters > 0 => {
+                    continue
+                }
+                Some("pos") => &mut part_of_speech,
+                Some("sc") => continue,
+                Some("tr") => &mut transliteration,
+                Some("ts") => &mut transcription,
+                _ => {
+                    return ::create_unknown(
+                        context,
+                        template_node,
+                        parameter,
+                        ::WarningMessage::Unrecognized,
+                    )
+                }
+            },
+        };
+        match ::parse_text(&parameter.value) {
+            None => {
+                return ::create_unknown(
+                    context,
+                    template_node,
+                    parameter,
+                    ::WarningMessage::ValueUnrecognized,
+                )
+            }
+            Some(value) => {
+                if output.is_some() {
+                    ::add_warning(context, parameter, ::WarningMessage::Duplicate);
+                }
+                *output = Some(value).filter(|value| !value.is_empty());
+            }
+        }
+    }
+    match (language, term.or_else(|| alternative.as_ref().map(|_| ::Cow::Borrowed("")))) {
+        (Some(language), Some(term)) => ::Flowing::Term {
+            alternative,
+            gloss,
+            id,
+            language,
+            literal,
+            part_of_speech,
+            term,
+            transcription,
+            transliteration,
+        },
+        _ => ::create_unknown(
+            context,
+            template_node,
+            template_node,
+            ::WarningMessage::ValueUnrecognized,
+        ),
+    }
+}
+
+fn parse_wikipedia_link<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+) -> ::Flowing<'a> {
+    let mut language = None;
+    let mut unnamed_parameters = vec![];
+    for parameter in parameters {
+        match parameter.name {
+            None => unnamed_parameters.push(parameter),
+            Some(_) => match ::parse_parameter_name(parameter) {
+                Some("lang") => match ::parse_text_not_empty(&parameter.value) {
+                    None => {
+                        return ::create_unknown(
+                            context,
+                            template_node,
+                            parameter,
+                            ::WarningMessage::ValueUnrecognized,
+                        )
+                    }
+                    value => language = value,
+                },
+                _ => {
+                    return ::create_unknown(
+                        context,
+                        template_node,
+                        parameter,
+                        ::WarningMessage::Unrecognized,
+                    )
+                }
+            },
+        }
+    }
+    let (target, text) = match unnamed_parameters.as_slice() {
+        [target] => (::parse_text_not_empty(&target.value), None),
+        [target, text] => (
+            ::parse_text_not_empty(&target.value),
+            ::parse_text_not_empty(&text.value),
+        ),
+        _ => (None, None),
+    };
+    match target {
+        None => ::create_unknown(
+            context,
+            template_node,
+            template_node,
+            ::WarningMessage::ValueUnrecognized,
+        ),
+        Some(target) => ::Flowing::WikipediaLink {
+            language,
+            text: text.unwrap_or_else(|| target.clone()),
+            target,
+        },
+    }
+}
+
+fn is_image_option(value: &str) -> bool {
+    match value.trim() {
+        "border" | "center" | "centre" | "frame" | "framed" | "frameless" | "left" | "none"
+        | "right" | "thumb" | "thumbnail" | "upright" => true,
+        value => {
+            value.split_once('=').is_some_and(|(name, _)| {
+                matches!(
+                    name,
+                    "alt" | "class" | "lang" | "link" | "page" | "thumb" | "thumbnail" | "upright"
+                )
+            }) || value.strip_suffix("px").is_some_and(|size| {
+                !size.is_empty() && size.chars().all(|character| character.is_ascii_digit() || character == 'x')
+            })
+        }
+    }
+}
@@ -0,0 +1,611 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+// Interprets the parameters of the head template of the entry into the inflected forms it displays. Russian declension templates are interpreted as well, since the head template `ru-noun+` takes the same parameters as them.
+pub fn interpret_head<'a>(context: &::Context<'a, '_>, pos_entry: &mut ::PosEntry<'a>) {
+    if let Some(head) = pos_entry.head.take() {
+        interpret_template(context, &head, pos_entry);
+        // Most head templates, including `head`, take the genders in the parameters `g`, `g2` and so on.
+        let genders = ::std::iter::once(head.named_text("g")).chain(
+            (2..)
+                .map(|number| head.named_text(&format!("g{}", number)))
+                .take_while(Option::is_some),
+        );
+        for gender in genders.flatten() {
+            for gender in parse_genders(&gender) {
+                if !pos_entry.genders.contains(gender) {
+                    pos_entry.genders.push(*gender);
+                }
+            }
+        }
+        pos_entry.head = Some(head);
+    }
+    // Head templates display the page name unless given the parameter `head`.
+    pos_entry.headword = match pos_entry
+        .head
+        .as_ref()
+        .and_then(|head| head.named_parameters.get("head"))
+        .filter(|value| !value.is_empty())
+    {
+        None => context.title.clone().map(::Cow::Owned),
+        Some(value) => Some(
+            ::text::plain_text(value).unwrap_or_else(|| ::Cow::Owned(::text::render_plain(value))),
+        ),
+    };
+    // Without a head template telling the comparability, an adjective or adverb is not comparable if all its definitions have the label `not comparable`.
+    if pos_entry.comparable.is_none()
+        && matches!(pos_entry.pos, ::Pos::Adjective | ::Pos::Adverb)
+        && !pos_entry.definitions.is_empty()
+        && pos_entry.definitions.iter().all(|definition| {
+            definition.definition.iter().any(|element| match element {
+                ::Flowing::Labels { disjunctive: false, labels } => labels
+                    .iter()
+                    .any(|label| label.text == "not comparable"),
+                _ => false,
+            })
+        })
+    {
+        pos_entry.comparable = Some(false);
+    }
+    for template in &pos_entry.inflection {
+        if matches!(&template.name as _, "ru-decl-adj" | "ru-noun-table") {
+            pos_entry
+                .russian_declensions
+                .extend(interpret_russian_declension(template));
+        }
+    }
+}
+
+fn interpret_template<'a>(
+    context: &::Context<'a, '_>,
+    head: &::Template<'a>,
+    pos_entry: &mut ::PosEntry<'a>,
+) {
+    match &head.name as _ {
+        "de-noun" => interpret_german_noun(context, head, pos_entry),
+        "en-adj" | "en-adv" => {
+            let (comparatives, superlatives) = interpret_english_comparison(context, head);
+            pos_entry.comparable = english_comparability(head);
+            pos_entry.comparatives = comparatives;
+            pos_entry.superlatives = superlatives;
+        }
+        "en-noun" => {
+            let (countability, plurals) = interpret_english_noun(context, head);
+            pos_entry.countability = countability;
+            pos_entry.plurals = plurals;
+        }
+        "en-plural noun" => pos_entry.plural_only = true,
+        // Unlike common nouns, proper nouns have no plural unless given in the parameters.
+        "en-proper noun" if !head.unnamed_parameters.is_empty() => {
+            pos_entry.plurals = interpret_english_noun(context, head).1;
+        }
+        "en-verb" => {
+            let verb_forms = interpret_english_verb(context, head);
+            if verb_forms != ::VerbForms::default() {
+                pos_entry.verb_forms = Some(verb_forms);
+            }
+        }
+        "es-noun" | "fr-noun" | "it-noun" | "pt-noun" => {
+            interpret_romance_noun(context, head, pos_entry)
+        }
+        "nl-noun" | "nl-proper noun" => pos_entry
+            .genders
+            .extend(head.unnamed_text(0).iter().flat_map(|gender| parse_genders(gender))),
+        "ru-noun" | "ru-proper noun" => pos_entry
+            .genders
+            .extend(head.unnamed_text(1).iter().flat_map(|gender| parse_genders(gender))),
+        "ru-noun+" | "ru-proper noun+" => {
+            pos_entry.russian_declensions = interpret_russian_declension(head);
+        }
+        _ => {}
+    }
+}
+
+// Each parameter is a comparative, or `er` or `more` for the regular comparatives formed by suffix or by the word `more`. The parameter `-` first means not comparable. Without parameters, the comparative is formed with `more`. The superlative of an explicit comparative is given in the parameter `sup`, `sup2` and so on, numbered from the first comparative after any leading `-`, or else formed from the comparative.
+fn interpret_english_comparison<'a>(
+    context: &::Context<'a, '_>,
+    head: &::Template<'a>,
+) -> (Vec<::Cow<'a, str>>, Vec<::Cow<'a, str>>) {
+    let mut comparatives = vec![];
+    let mut superlatives = vec![];
+    let mut parameters: Vec<_> = (0..head.unnamed_parameters.len())
+        .map(|index| head.unnamed_text(index))
+        .collect();
+    match parameters.first() {
+        None => parameters.push(Some(::Cow::Borrowed("more"))),
+        Some(Some(parameter)) if parameter == "-" => {
+            parameters.remove(0);
+        }
+        _ => {}
+    }
+    // The index is taken after the leading `-` is removed, since it's not counted in the numbering of the superlatives.
+    for (index, parameter) in parameters.into_iter().enumerate() {
+        let parameter = match parameter {
+            None => continue,
+            Some(parameter) => parameter,
+        };
+        match &parameter as &str {
+            "!" | "-" | "?" | "~" => {}
+            "er" | "more" => if let Some(title) = &context.title {
+                let (comparative, superlative) = if parameter == "er" {
+                    english_comparison_by_suffix(title)
+                } else {
+                    (format!("more {}", title), format!("most {}", title))
+                };
+                comparatives.push(::Cow::Owned(comparative));
+                superlatives.push(::Cow::Owned(superlative));
+            },
+            _ => {
+                let superlative_name = if index == 0 {
+                    "sup".to_string()
+                } else {
+                    format!("sup{}", index + 1)
+                };
+                match head.named_text(&superlative_name) {
+                    None => if let Some(stem) = parameter.strip_suffix("er") {
+                        superlatives.push(::Cow::Owned(format!("{}est", stem)));
+                    } else if let Some(positive) = parameter.strip_prefix("more ") {
+                        superlatives.push(::Cow::Owned(format!("most {}", positive)));
+                    },
+                    Some(superlative) => superlatives.push(superlative),
+                }
+                comparatives.push(parameter);
+            }
+        }
+    }
+    (comparatives, superlatives)
+}
+
+// The first parameter `-` means not comparable, unless followed by comparatives, which means generally not comparable but still having comparatives. The parameter `?` means the comparability is unknown.
+fn english_comparability(head: &::Template) -> Option<bool> {
+    let parameters: Vec<_> = (0..head.unnamed_parameters.len())
+        .filter_map(|index| head.unnamed_text(index))
+        .collect();
+    match parameters.first().map(|parameter| parameter as &str) {
+        Some("-") => Some(
+            parameters[1..]
+                .iter()
+                .any(|parameter| !matches!(parameter as &str, "!" | "-" | "?" | "~")),
+        ),
+        Some("?") => None,
+        _ => Some(true),
+    }
+}
+
+// The parameters are either the gender, genitive, plural and diminutive, or a single parameter with the gender, genitive and plural separated by commas, such as `m,es,^er`. In the latter form, the genitive and plural may be endings added to the page name, where `^` means umlaut, and alternative forms are separated by colons. The forms may also be given in named parameters. The value `-` means the form doesn't exist.
+fn interpret_german_noun<'a>(
+    context: &::Context<'a, '_>,
+    head: &::Template<'a>,
+    pos_entry: &mut ::PosEntry<'a>,
+) {
+    let first = head.unnamed_text(0);
+    match first {
+        Some(ref first) if first.contains(',') => {
+            let mut fields = first.split(',');
+            pos_entry.genders.extend(fields.next().into_iter().flat_map(parse_genders));
+            let outputs = [&mut pos_entry.genitives, &mut pos_entry.plurals];
+            for (field, output) in fields.zip(outputs) {
+                output.extend(
+                    field
+                        .split(':')
+                        .filter_map(|form| german_form(context, form))
+                        .map(::Cow::Owned),
+                );
+            }
+        }
+        _ => {
+            pos_entry.genders.extend(first.iter().flat_map(|first| parse_genders(first)));
+            let outputs = [
+                &mut pos_entry.genitives,
+                &mut pos_entry.plurals,
+                &mut pos_entry.diminutives,
+            ];
+            for (index, output) in (1..).zip(outputs) {
+                output.extend(
+                    head.unnamed_text(index)
+                        .filter(|form| !matches!(form as &str, "" | "-")),
+                );
+            }
+        }
+    }
+    for (names, output) in [
+        (["gen", "gen2", "genitive"], &mut pos_entry.genitives),
+        (["pl", "pl2", "plural"], &mut pos_entry.plurals),
+        (["dim", "dim2", "diminutive"], &mut pos_entry.diminutives),
+    ] {
+        output.extend(
+            names
+                .iter()
+                .filter_map(|name| head.named_text(name))
+                .filter(|value| value != "-"),
+        );
+    }
+}
+
+// A form in the compact parameter of `de-noun`, which is either a whole word, starting with a capital letter like every German noun, or an ending added to the page name.
+fn german_form(context: &::Context, form: &str) -> Option<String> {
+    if form.is_empty() || form == "-" {
+        return None;
+    }
+    if form.starts_with(char::is_uppercase) {
+        return Some(form.to_string());
+    }
+    let title = context.title.as_ref()?;
+    Some(match form.strip_prefix('^') {
+        None => format!("{}{}", title, form),
+        Some(ending) => format!("{}{}", german_umlaut(title), ending),
+    })
+}
+
+// The word with umlaut on the last vowel that takes umlaut, such as `Häus` for `Haus` and `Väter` for `Vater`.
+fn german_umlaut(word: &str) -> String {
+    let characters: Vec<char> = word.chars().collect();
+    for index in (0..characters.len()).rev() {
+        let umlaut = match characters[index] {
+            'a' => 'ä',
+            'o' => 'ö',
+            'u' => match index.checked_sub(1).map(|index| characters[index]) {
+                // The diphthong `au` takes umlaut on the `a`, while `eu` doesn't take umlaut.
+                Some('a') | Some('A') | Some('e') | Some('E') => continue,
+                _ => 'ü',
+            },
+            'A' => 'Ä',
+            'O' => 'Ö',
+            'U' => 'Ü',
+            _ => continue,
+        };
+        let mut characters = characters.clone();
+        characters[index] = umlaut;
+        return characters.into_iter().collect();
+    }
+    word.to_string()
+}
+
+// The first parameter is the gender and the second the plural. The plural may be `+` or omitted for the regular plural, `-` for no plural, `#` for the same form as the singular, or an ending such as `s` or `es` added to the page name. Further plurals are given in the parameters `pl2`, `pl3` and so on.
+fn interpret_romance_noun<'a>(
+    context: &::Context<'a, '_>,
+    head: &::Template<'a>,
+    pos_entry: &mut ::PosEntry<'a>,
+) {
+    let language = context.language.unwrap();
+    pos_entry
+        .genders
+        .extend(head.unnamed_text(0).iter().flat_map(|gender| parse_genders(gender)));
+    let feminine = pos_entry.genders == [::Gender::Feminine];
+    let plurals = ::std::iter::once(head.unnamed_text(1)).chain(
+        (2..)
+            .map(|number| head.named_text(&format!("pl{}", number)))
+            .take_while(Option::is_some),
+    );
+    for (index, plural) in plurals.enumerate() {
+        let plural = match plural {
+            None if index == 0 => ::Cow::Borrowed("+"),
+            None => continue,
+            Some(plural) => plural,
+        };
+        let title = context.title.as_ref();
+        match &plural as &str {
+            "-" | "?" | "!" | "~" => {}
+            "+" => pos_entry.plurals.extend(
+                title
+                    .and_then(|title| romance_plural(language, title, feminine))
+                    .map(::Cow::Owned),
+            ),
+            "#" => pos_entry
+                .plurals
+                .extend(title.map(|title| ::Cow::Owned(title.clone()))),
+            "es" | "s" | "x" => pos_entry
+                .plurals
+                .extend(title.map(|title| ::Cow::Owned(format!("{}{}", title, plural)))),
+            _ => pos_entry.plurals.push(plural),
+        }
+    }
+}
+
+// The regular plural of a Spanish, French, Italian or Portuguese noun, or nothing if the spelling of the singular doesn't determine it. For example, the plural of the Spanish `joven` gains a written accent, `jóvenes`, and the plural of the French `bal` is `bals` rather than `baux`, so such plurals are left out rather than guessed.
+fn romance_plural(language: ::Language, singular: &str, feminine: bool) -> Option<String> {
+    let last = singular.chars().last()?;
+    let stem = &singular[..singular.len() - last.len_utf8()];
+    match language {
+        ::Language::Es => match last {
+            'a' | 'e' | 'i' | 'o' | 'u' | 'á' | 'é' | 'ó' => Some(format!("{}s", singular)),
+            // Words of more than one syllable ending in `s` without a written accent are stressed before the last syllable and are invariable, such as `lunes`.
+            's' if remove_last_accent(singular) == singular && count_vowel_groups(singular) > 1 => {
+                Some(singular.to_string())
+            }
+            // The accent on the last syllable is not written when the plural ending adds a syllable, such as in `canción` and `canciones`, except on `í` and `ú`, which may keep it, such as in `país` and `países`.
+            'n' | 's' if remove_last_accent(singular) != singular
+                && !stem.ends_with(['í', 'ú']) =>
+            {
+                Some(format!("{}es", remove_last_accent(singular)))
+            }
+            // A word of one syllable keeps its stress, such as `mes` and `meses`, while a longer word ending in `n` without a written accent gains one, such as `joven` and `jóvenes`.
+            'n' | 's' if count_vowel_groups(singular) == 1 => Some(format!("{}es", singular)),
+            'z' => Some(format!("{}ces", stem)),
+            // Words ending in these consonants are stressed on the last syllable unless the accent is written on another one, and the accent stays in the plural, such as in `papel` and `papeles` or `árbol` and `árboles`.
+            'd' | 'j' | 'l' | 'r' => Some(format!("{}es", singular)),
+            _ => None,
+        },
+        ::Language::Fr => {
+            if matches!(last, 's' | 'x' | 'z') {
+                Some(singular.to_string())
+            } else if singular.ends_with("eau") {
+                Some(format!("{}x", singular))
+            } else if ["ail", "al", "au", "eu", "ou"]
+                .iter()
+                .any(|ending| singular.ends_with(ending))
+            {
+                None
+            } else {
+                Some(format!("{}s", singular))
+            }
+        }
+        ::Language::It => match last {
+            // The plurals of nouns ending in `ca`, `ga`, `co`, `go`, `ia`, `ie` or `io` may or may not keep the sound of the consonant or the `i`, such as `amico` and `amici` or `fuoco` and `fuochi`.
+            'a' | 'o' if stem.ends_with(['c', 'g', 'i']) => None,
+            'e' if stem.ends_with('i') => None,
+            'a' if feminine => Some(format!("{}e", stem)),
+            'a' | 'e' => Some(format!("{}i", stem)),
+            // Feminine nouns ending in `o` are usually shortened words and invariable, such as `foto`.
+            'o' if !feminine => Some(format!("{}i", stem)),
+            'o' => None,
+            // Nouns ending in a consonant or a stressed vowel are invariable.
+            _ => Some(singular.to_string()),
+        },
+        _ => {
+            if let Some(stem) = singular.strip_suffix("ção") {
+                Some(format!("{}ções", stem))
+            } else if singular.ends_with("ão") {
+                None
+            } else if singular.ends_with("al") || singular.ends_with("ul") {
+                // Words of one syllable may take `es` instead, such as `mal` and `males`.
+                if count_vowel_groups(singular) > 1 {
+                    Some(format!("{}is", stem))
+                } else {
+                    None
+                }
+            } else {
+                match last {
+                    'a' | 'e' | 'i' | 'o' | 'u' | 'á' | 'ã' | 'é' | 'ê' | 'í' | 'ó' | 'ô' | 'ú' => {
+                        Some(format!("{}s", singular))
+                    }
+                    'm' => Some(format!("{}ns", stem)),
+                    'r' | 'z' => Some(format!("{}es", singular)),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+// The number of runs of consecutive vowels in the word, which is the number of syllables except where vowels next to each other are pronounced separately.
+fn count_vowel_groups(word: &str) -> usize {
+    let mut count = 0;
+    let mut previous_vowel = false;
+    for character in word.chars() {
+        let vowel = "aeiouáéíóúü".contains(character);
+        if vowel && !previous_vowel {
+            count += 1;
+        }
+        previous_vowel = vowel;
+    }
+    count
+}
+
+// The word with the accent removed from the last vowel if it's in the last syllable, such as `cancion` for `canción`.
+fn remove_last_accent(word: &str) -> String {
+    let mut characters: Vec<char> = word.chars().collect();
+    for character in characters.iter_mut().rev() {
+        *character = match *character {
+            'á' => 'a',
+            'é' => 'e',
+            'í' => 'i',
+            'ó' => 'o',
+            'ú' => 'u',
+            'a' | 'e' | 'i' | 'o' | 'u' => break,
+            _ => continue,
+        };
+        break;
+    }
+    characters.into_iter().collect()
+}
+
+// Each set of parameters is the optional accent pattern, the lemma and the declension type. The accent pattern is a letter from `a` to `f`, optionally followed by one or two primes, or a number from 1 to 6 in the older style.
+fn interpret_russian_declension<'a>(template: &::Template<'a>) -> Vec<::RussianDeclension<'a>> {
+    let parameters: Vec<_> = (0..template.unnamed_parameters.len())
+        .map(|index| template.unnamed_text(index).filter(|value| !value.is_empty()))
+        .collect();
+    parameters
+        .split(|parameter| parameter.as_ref().is_some_and(|parameter| parameter == "or"))
+        .map(|parameters| match parameters {
+            [Some(stress_pattern), parameters @ ..] if is_russian_stress_pattern(stress_pattern) => {
+                ::RussianDeclension {
+                    declension_type: parameters.get(1).cloned().flatten(),
+                    lemma: parameters.first().cloned().flatten(),
+                    stress_pattern: Some(stress_pattern.clone()),
+                }
+            }
+            _ => ::RussianDeclension {
+                declension_type: parameters.get(1).cloned().flatten(),
+                lemma: parameters.first().cloned().flatten(),
+                stress_pattern: None,
+            },
+        })
+        .collect()
+}
+
+fn is_russian_stress_pattern(value: &str) -> bool {
+    let mut characters = value.chars();
+    matches!(characters.next(), Some('a'..='f') | Some('1'..='6'))
+        && characters.all(|character| character == '\'')
+        && value.len() <= 3
+}
+
+// Parses a gender specification such as `m` or `f-p`, where anything after the gender itself specifies the number or animacy. The specification `mf` means both masculine and feminine.
+fn parse_genders(specification: &str) -> &'static [::Gender] {
+    match specification.split('-').next() {
+        Some("c") => &[::Gender::Common],
+        Some("f") => &[::Gender::Feminine],
+        Some("m") => &[::Gender::Masculine],
+        Some("mf") | Some("mfbysense") => &[::Gender::Masculine, ::Gender::Feminine],
+        Some("n") => &[::Gender::Neuter],
+        _ => &[],
+    }
+}
+
+// The regular comparative and superlative of an English adjective formed by suffix.
+fn english_comparison_by_suffix(adjective: &str) -> (String, String) {
+    if adjective.ends_with('e') {
+        return (format!("{}r", adjective), format!("{}st", adjective));
+    }
+    match consonant_y_stem(adjective) {
+        None => (format!("{}er", adjective), format!("{}est", adjective)),
+        Some(stem) => (format!("{}ier", stem), format!("{}iest", stem)),
+    }
+}
+
+// The first parameter `-` means uncountable, or usually uncountable if followed by plurals, and `~` means both countable and uncountable. The parameter `-` or `~` in another position also means both countable and uncountable, and `?` means the countability is unknown. Further parameters are plurals for the countable senses.
+fn interpret_english_noun<'a>(
+    context: &::Context<'a, '_>,
+    head: &::Template<'a>,
+) -> (Option<::Countability>, Vec<::Cow<'a, str>>) {
+    // Parameters that aren't plain text are kept in their positions, so that they still count as the first parameter or as plurals.
+    let mut parameters: Vec<_> = (0..head.unnamed_parameters.len())
+        .map(|index| head.unnamed_text(index))
+        .collect();
+    let countability = match parameters.first() {
+        Some(Some(parameter)) if parameter == "-" => {
+            parameters.remove(0);
+            Some(if parameters.is_empty() {
+                ::Countability::Uncountable
+            } else {
+                ::Countability::UsuallyUncountable
+            })
+        }
+        Some(Some(parameter)) if parameter == "?" => None,
+        Some(Some(parameter)) if parameter == "~" => {
+            parameters.remove(0);
+            if parameters.is_empty() {
+                parameters.push(Some(::Cow::Borrowed("+")));
+            }
+            Some(::Countability::CountableAndUncountable)
+        }
+        None => {
+            parameters.push(Some(::Cow::Borrowed("+")));
+            Some(::Countability::Countable)
+        }
+        _ => Some(
+            if parameters.iter().flatten().any(|parameter| parameter == "-" || parameter == "~") {
+                ::Countability::CountableAndUncountable
+            } else {
+                ::Countability::Countable
+            },
+        ),
+    };
+    let plurals = parameters
+        .into_iter()
+        .flatten()
+        .filter_map(|parameter| match &parameter as &str {
+            // The plural is unattested or unknown, or the parameter indicates countability in a position other than the first.
+            "!" | "-" | "?" | "~" => None,
+            "+" => context.title.as_ref().map(|title| ::Cow::Owned(english_plural(title))),
+            "es" | "s" => context
+                .title
+                .as_ref()
+                .map(|title| ::Cow::Owned(format!("{}{}", title, parameter))),
+            _ => Some(parameter),
+        })
+        .collect();
+    (countability, plurals)
+}
+
+// The parameters are the third-person singular, present participle, past tense and past participle, each defaulting to the regular form. The parameter `+` gives the regular form and `++` the regular form with the final consonant doubled. When the first parameter is `++` and the other parameters are omitted, all forms are formed with the final consonant doubled. When the past participle is omitted it's the same as the past tense.
+fn interpret_english_verb<'a>(
+    context: &::Context<'a, '_>,
+    head: &::Template<'a>,
+) -> ::VerbForms<'a> {
+    let parameters: Vec<_> = (0..4).map(|index| head.unnamed_text(index)).collect();
+    let double = parameters[0].as_ref().is_some_and(|parameter| parameter == "++")
+        && parameters[1..].iter().all(Option::is_none);
+    let regular = |inflect: fn(&str, bool) -> String, double: bool| {
+        context
+            .title
+            .as_ref()
+            .map(|title| ::Cow::Owned(inflect(title, double)))
+    };
+    let form = |index: usize, inflect: fn(&str, bool) -> String| match parameters[index] {
+        None => regular(inflect, double),
+        Some(ref parameter) if parameter == "+" => regular(inflect, false),
+        Some(ref parameter) if parameter == "++" => regular(inflect, true),
+        Some(ref parameter) => Some(parameter.clone()),
+    };
+    let past_tense = form(2, english_past);
+    ::VerbForms {
+        past_participle: match parameters[3] {
+            None => past_tense.clone(),
+            Some(_) => form(3, english_past),
+        },
+        past_tense,
+        present_participle: form(1, english_present_participle),
+        third_person_singular: form(0, |verb, _| english_plural(verb)),
+    }
+}
+
+// The regular past tense of an English verb, optionally with the final consonant doubled.
+fn english_past(verb: &str, double: bool) -> String {
+    if double {
+        return format!("{}ed", double_final_consonant(verb));
+    }
+    if verb.ends_with('e') {
+        return format!("{}d", verb);
+    }
+    match consonant_y_stem(verb) {
+        None => format!("{}ed", verb),
+        Some(stem) => format!("{}ied", stem),
+    }
+}
+
+// The regular present participle of an English verb, optionally with the final consonant doubled.
+fn english_present_participle(verb: &str, double: bool) -> String {
+    if double {
+        return format!("{}ing", double_final_consonant(verb));
+    }
+    if let Some(stem) = verb.strip_suffix("ie") {
+        return format!("{}ying", stem);
+    }
+    if let Some(stem) = verb.strip_suffix('e') {
+        if !["ee", "oe", "ye"].iter().any(|ending| verb.ends_with(ending)) {
+            return format!("{}ing", stem);
+        }
+    }
+    format!("{}ing", verb)
+}
+
+// The word with its final letter repeated, such as `stopp` for `stop`.
+fn double_final_consonant(word: &str) -> String {
+    match word.chars().last() {
+        None => String::new(),
+        Some(last) => format!("{}{}", word, last),
+    }
+}
+
+// The stem of a word ending in a consonant followed by `y`, such as `cr` for `cry`.
+fn consonant_y_stem(word: &str) -> Option<&str> {
+    word.strip_suffix('y')
+        .filter(|stem| stem.ends_with(|character| !"aeiou".contains(character)))
+}
+
+// The regular plural of an English noun.
+fn english_plural(singular: &str) -> String {
+    if ["ch", "s", "sh", "x", "z"]
+        .iter()
+        .any(|ending| singular.ends_with(ending))
+    {
+        return format!("{}es", singular);
+    }
+    match consonant_y_stem(singular) {
+        None => format!("{}s", singular),
+        Some(stem) => format!("{}ies", stem),
+    }
+}
@@ -3,31 +3,39 @@
 // the file LICENSE at the top-level directory of this distribution.
 
 pub fn parse_inflection<'a>(
-    context: &mut ::Context,
+    context: &mut ::Context<'a, '_>,
     heading_node: &::Node,
     nodes: &[::Node<'a>],
     output: &mut Vec<::Template<'a>>,
-    template_name: &str,
+    tables: &mut Vec<::Table<'a>>,
+    template_names: &[&str],
 ) -> usize {
-    let mut inflection = None;
+    let mut inflection_found = false;
+    let table_count = tables.len();
     let mut node_index = 0;
     while let Some(node) = nodes.get(node_index) {
         match node {
             ::Node::Heading { .. } => break,
+            ::Node::Table { captions, rows, .. } => {
+                node_index += 1;
+                tables.push(::flowing::parse_table(context, captions, rows));
+                continue;
+            }
+            // Templates on consecutive lines are separated by a line break.
+            ::Node::Text { value, .. } if value.trim().is_empty() => {
+                node_index += 1;
+                continue;
+            }
             ::Node::Template {
                 name, parameters, ..
             } => if let Some(name) = ::parse_text(name) {
-                let language_code = context.language.unwrap().language_code();
-                if name.starts_with(language_code)
-                    && name[language_code.len()..].starts_with(template_name)
+                if template_names
+                    .iter()
+                    .any(|template_name| check_template_name(context, template_name, &name))
                 {
                     node_index += 1;
-                    if inflection.is_some() {
-                        inflection = Some(None);
-                        ::add_warning(context, node, ::WarningMessage::Duplicate);
-                    } else {
-                        inflection = Some(::template::parse_template(context, name, parameters));
-                    }
+                    inflection_found = true;
+                    output.extend(::template::parse_template(context, node, name, parameters));
                     continue;
                 }
             },
@@ -36,10 +44,28 @@ pub fn parse_inflection<'a>(
         node_index += 1;
         ::add_warning(context, node, ::WarningMessage::Unrecognized);
     }
-    match inflection {
-        None => ::add_warning(context, heading_node, ::WarningMessage::SectionEmpty),
-        Some(None) => {}
-        Some(Some(inflection)) => output.push(inflection),
+    if !inflection_found && tables.len() == table_count {
+        ::add_warning(context, heading_node, ::WarningMessage::SectionEmpty);
     }
     node_index
 }
+
+// The template name is the language code followed by the given part, such as `de-conj-weak` for `-conj-`, or by the part without the final `-`, such as `la-conj`.
+fn check_template_name(context: &::Context, template_name: &str, name: &str) -> bool {
+    let language_code = ::language_code(context);
+    name.starts_with(language_code)
+        && (name[language_code.len()..].starts_with(template_name)
+            || name[language_code.len()..] == template_name[..template_name.len() - 1])
+        || matches!(
+            (context.language.unwrap(), template_name, name),
+            (::Language::Ang, "-decl-", "ang-adecl")
+                | (::Language::Grc, "-decl-", "grc-adecl")
+                | (::Language::Hu, "-decl-", "hu-infl-nom")
+                | (::Language::La, "-decl-", "la-adecl")
+                | (::Language::La, "-decl-", "la-ndecl")
+                | (::Language::Ru, "-decl-", "ru-noun-table")
+                | (::Language::Sv, "-decl-", "sv-infl-noun")
+                | (::Language::Uk, "-decl-", "uk-adecl")
+                | (::Language::Uk, "-decl-", "uk-ndecl")
+        )
+}
@@ -0,0 +1,33 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+// Based on the label data in https://en.wiktionary.org/wiki/Module:labels/data and its submodules. Only the most common labels are included.
+#[must_use]
+pub fn classify_label(label: &str) -> Option<::LabelKind> {
+    Some(match label {
+        "ambitransitive" | "attributive" | "auxiliary" | "by ellipsis" | "countable"
+        | "ditransitive" | "ergative" | "impersonal" | "in the plural" | "in the singular"
+        | "intransitive" | "modal" | "not comparable" | "plural only" | "pluralonly"
+        | "predicative" | "reflexive" | "singular only" | "transitive" | "uncountable"
+        | "usually in the plural" | "with a definite article" => ::LabelKind::Grammar,
+        "AU" | "Australia" | "Australian" | "Austria" | "Austrian" | "Brazil" | "Brazilian"
+        | "British" | "Canada" | "Canadian" | "Cockney" | "Commonwealth" | "England" | "Ireland"
+        | "Irish" | "Latin America" | "Mexico" | "New Zealand" | "North America"
+        | "Northern England" | "Portugal" | "Scotland" | "Scottish" | "South Africa" | "Spain"
+        | "Switzerland" | "UK" | "US" | "Wales" | "dialectal" | "regional" => ::LabelKind::Region,
+        "colloquial" | "derogatory" | "euphemistic" | "figurative" | "figuratively" | "formal"
+        | "humorous" | "idiomatic" | "informal" | "ironic" | "literary" | "mildly vulgar"
+        | "nonstandard" | "offensive" | "pejorative" | "poetic" | "rare" | "slang" | "vulgar" => {
+            ::LabelKind::Register
+        }
+        "archaic" | "dated" | "historical" | "neologism" | "obsolete" => ::LabelKind::Temporal,
+        "anatomy" | "astronomy" | "biology" | "botany" | "chemistry" | "computing" | "cooking"
+        | "economics" | "finance" | "geography" | "geology" | "grammar" | "law" | "linguistics"
+        | "mathematics" | "medicine" | "military" | "music" | "nautical" | "philosophy"
+        | "physics" | "politics" | "programming" | "religion" | "sports" | "zoology" => {
+            ::LabelKind::Topic
+        }
+        _ => return None,
+    })
+}
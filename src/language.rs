@@ -2,172 +2,533 @@
 // This is free software distributed under the terms specified in
 // the file LICENSE at the top-level directory of this distribution.
 
-macro_rules! module { { $( $name:tt $variant:tt ),+ } => {
-    pub fn parse_language<'a>(
-        context: &mut ::Context<'a>,
-        heading_node: &::Node,
-        nodes: &[::Node<'a>],
-        language_entries: &mut Vec<::LanguageEntry<'a>>,
-        language: ::Language
-    ) -> usize {
-        for entry in language_entries.iter() {
-            if entry.language == language {
-                ::add_warning(context, heading_node, ::WarningMessage::Duplicate);
-                break;
-            }
+pub fn parse_language<'a>(
+    context: &mut ::Context<'a, '_>,
+    heading_node: &::Node,
+    nodes: &[::Node<'a>],
+    language_entries: &mut Vec<::LanguageEntry<'a>>,
+    language: ::Language,
+    language_name: Option<::Cow<'a, str>>,
+) -> usize {
+    for entry in language_entries.iter() {
+        if entry.language == language && entry.language_name == language_name {
+            ::add_warning(context, heading_node, ::WarningMessage::Duplicate);
+            break;
+        }
+    }
+    let mut alternative_forms = false;
+    let mut anagrams = false;
+    let mut etymology = None;
+    let mut etymology_entries = vec![];
+    let mut further_reading = false;
+    let mut forms = None;
+    let mut glyph_origin = None;
+    let mut node_index = 0;
+    let mut pos_entries = vec![];
+    let mut pronunciation = None;
+    let mut resources = vec![];
+    let mut root = vec![];
+    while let Some(node) = nodes.get(node_index) {
+        // The rest of the section is not parsed once the output is too large, since the section is left out of the output, or once the visitor has stopped the parsing.
+        if context.visit_stopped || ::stats::is_output_too_large(context) {
+            break;
         }
-        let mut alternative_forms = false;
-        let mut anagrams = false;
-        let mut etymology = false;
-        let mut etymology_entries = vec![];
-        let mut further_reading = false;
-        let mut node_index = 0;
-        let mut pos_entries = vec![];
-        let mut pronunciation = None;
-        while let Some(node) = nodes.get(node_index) {
-            macro_rules! parse_section { ( $output:tt $function:path ) => { {
+        macro_rules! parse_section {
+            ( $output:tt, $function:path ) => {{
                 node_index += 1;
                 node_index += $function(context, node, &nodes[node_index..], &mut $output);
                 continue;
-            } } }
-            match node {
-                ::Node::Heading { level, nodes: heading_child_nodes, .. } if *level < 4 => {
-                    if *level < 3 {
-                        break;
+            }};
+        }
+        match node {
+            ::Node::Heading {
+                level,
+                nodes: heading_child_nodes,
+                ..
+            } if *level < 4 => {
+                if *level < 3 {
+                    break;
+                }
+                if let Some(heading_text) = ::parse_text(heading_child_nodes) {
+                    if ::is_skipped_section(context, &heading_text) {
+                        node_index += 1;
+                        node_index +=
+                            ::supplementary::skip_section(context, *level, &nodes[node_index..]);
+                        continue;
                     }
-                    if let Some(heading_text) = ::parse_text(heading_child_nodes) {
-                        match &heading_text as _ {
-                            "Alternative forms" => parse_section!(alternative_forms ::supplementary::parse_supplementary),
-                            "Anagrams" => parse_section!(anagrams ::supplementary::parse_supplementary),
-                            "Etymology" => parse_section!(etymology ::supplementary::parse_supplementary),
-                            "Etymology 1" | "Etymology 2" | "Etymology 3" | "Etymology 4" => parse_section!(etymology_entries parse_etymology),
-                            "Further reading" => parse_section!(further_reading ::supplementary::parse_supplementary),
-                            "Pronunciation" => parse_section!(pronunciation ::pronunciation::parse_pronunciation),
-                            $( $name => {
+                    match &heading_text as _ {
+                        "Alternative forms" => {
+                            parse_section!(alternative_forms, ::supplementary::parse_supplementary)
+                        }
+                        "Anagrams" => {
+                            parse_section!(anagrams, ::supplementary::parse_supplementary)
+                        }
+                        "Etymology" => parse_section!(etymology, ::etymology::parse_etymology),
+                        "Further reading" => {
+                            if further_reading {
+                                ::add_warning(context, node, ::WarningMessage::Duplicate);
+                            }
+                            further_reading = true;
+                            parse_section!(resources, ::resource::parse_resources)
+                        }
+                        // The section has the same format as usage notes.
+                        "Glyph origin" => {
+                            parse_section!(glyph_origin, ::usage_notes::parse_usage_notes)
+                        }
+                        "Pronunciation" => {
+                            parse_section!(pronunciation, ::pronunciation::parse_pronunciation)
+                        }
+                        "References" => parse_section!(resources, ::resource::parse_resources),
+                        // On a page in the namespace `Reconstruction`, the heading is the part of speech of a reconstructed root.
+                        "Root" if context.reconstruction => {
+                            node_index += 1;
+                            node_index += ::pos::parse_pos(
+                                context,
+                                node,
+                                &nodes[node_index..],
+                                &mut pos_entries,
+                                4,
+                                ::pos::PosHeading {
+                                    name: None,
+                                    number: None,
+                                    pos: ::Pos::Root,
+                                },
+                            );
+                            continue;
+                        }
+                        "Root" => parse_section!(root, ::root::parse_root),
+                        _ if is_numbered_heading(&heading_text, "Etymology") => {
+                            parse_section!(etymology_entries, parse_numbered_etymology)
+                        }
+                        _ if is_numbered_heading(&heading_text, "Pronunciation") => {
+                            parse_section!(etymology_entries, parse_numbered_pronunciation)
+                        }
+                        _ => {
+                            if let Some(heading) =
+                                parse_pos_heading(context, &heading_text, &nodes[node_index + 1..])
+                            {
                                 node_index += 1;
-                                node_index += ::pos::parse_pos(context, node, &nodes[node_index..], &mut pos_entries, 4, ::Pos::$variant);
+                                node_index += ::pos::parse_pos(
+                                    context,
+                                    node,
+                                    &nodes[node_index..],
+                                    &mut pos_entries,
+                                    4,
+                                    heading,
+                                );
                                 continue;
-                            } )+
-                            _ => {}
+                            }
                         }
                     }
                 }
-                ::Node::Template { name, .. } => if let Some(name) = ::parse_text(name) {
+            }
+            ::Node::Template {
+                name, parameters, ..
+            } => {
+                if let Some(name) = ::parse_text(name) {
+                    if ::category::parse_category(context, node, &name, parameters) {
+                        node_index += 1;
+                        continue;
+                    }
                     match &name as _ {
                         "number box" | "was fwotd" | "was wotd" | "wikipedia" => {
                             node_index += 1;
                             ::add_warning(context, node, ::WarningMessage::Supplementary);
                             continue;
                         }
+                        // Some languages have a template for displaying the written forms, such as `zh-forms`.
+                        _ if name.strip_suffix("-forms") == Some(::language_code(context)) => {
+                            node_index += 1;
+                            if forms.is_some() {
+                                forms = Some(None);
+                                ::add_warning(context, node, ::WarningMessage::Duplicate);
+                            } else {
+                                forms = Some(::template::parse_template(
+                                    context, node, name, parameters,
+                                ));
+                            }
+                            continue;
+                        }
                         _ => {}
                     }
                 }
-                _ => {}
             }
-            node_index += 1;
-            ::add_warning(context, node, ::WarningMessage::Unrecognized);
-        }
-        if pos_entries.is_empty() && etymology_entries.is_empty() {
-            ::add_warning(context, heading_node, ::WarningMessage::SectionEmpty);
-        }
-        let pronunciation = pronunciation.unwrap_or_default();
-        language_entries.push(::LanguageEntry {
-            anagrams,
-            etymology_entries,
-            etymology_entry: ::EtymologyEntry {
-                alternative_forms,
-                audio: pronunciation.audio,
-                etymology,
-                homophones: pronunciation.homophones,
-                hyphenation: pronunciation.hyphenation,
-                ipa: pronunciation.ipa,
-                pos_entries,
-                rhymes: pronunciation.rhymes
+            _ => {}
+        }
+        node_index += 1;
+        ::add_warning(context, node, ::WarningMessage::Unrecognized);
+    }
+    if pos_entries.is_empty() && etymology_entries.is_empty() {
+        ::add_warning(context, heading_node, ::WarningMessage::SectionEmpty);
+    }
+    let has_etymology = etymology.is_some();
+    let etymology = etymology.unwrap_or_default();
+    language_entries.push(::LanguageEntry {
+        anagrams,
+        categories: ::std::mem::take(&mut context.categories),
+        etymology_entries,
+        etymology_entry: ::EtymologyEntry {
+            alternative_forms,
+            cognates: etymology.cognates,
+            derivations: etymology.derivations,
+            doublets: etymology.doublets,
+            etymology: if has_etymology {
+                Some(etymology.text)
+            } else {
+                None
             },
-            further_reading,
-            language
+            onomatopoeic: etymology.onomatopoeic,
+            pie_roots: etymology.pie_roots,
+            pos_entries,
+            pronunciation,
+            requests: etymology.requests,
+            root,
+            uncertain_origin: etymology.uncertain_origin,
+            unknown_origin: etymology.unknown_origin,
+            word_formations: etymology.word_formations,
+        },
+        forms: forms.unwrap_or_default(),
+        further_reading,
+        glyph_origin: glyph_origin.unwrap_or_default(),
+        language,
+        language_code: context
+            .registered_language
+            .as_ref()
+            .map(|language| ::Cow::Owned(language.code.clone())),
+        language_name,
+        resources,
+    });
+    node_index
+}
+
+// Recognizes a heading consisting of the given text followed by a number, such as `Etymology 1`.
+fn is_numbered_heading(heading_text: &str, text: &str) -> bool {
+    heading_text
+        .strip_prefix(text)
+        .and_then(|number| number.strip_prefix(' '))
+        .and_then(|number| number.parse::<u32>().ok())
+        .is_some_and(|number| number > 0)
+}
+
+// Recognizes the heading of a part of speech section, which may be numbered, such as `Noun 2`.
+pub fn parse_pos_heading<'a>(
+    context: &::Context,
+    heading_text: &::Cow<'a, str>,
+    nodes: &[::Node],
+) -> Option<::pos::PosHeading<'a>> {
+    if let Some(pos) = ::Pos::from_heading(heading_text) {
+        return Some(::pos::PosHeading {
+            name: None,
+            number: None,
+            pos,
         });
-        node_index
     }
-
-    fn parse_etymology<'a>(
-        context: &mut ::Context<'a>,
-        heading_node: &::Node,
-        nodes: &[::Node<'a>],
-        output: &mut Vec<::EtymologyEntry<'a>>
-    ) -> usize {
-        let mut alternative_forms = false;
-        let mut etymology = false;
-        let mut node_index = 0;
-        let mut pos_entries = vec![];
-        let mut pronunciation = None;
-        while let Some(node) = nodes.get(node_index) {
-            if let ::Node::Heading { .. } = node {
-                break;
+    if let Some((name, number)) = heading_text.rsplit_once(' ') {
+        if let Some(number) = number.parse().ok().filter(|number| *number > 0) {
+            if let Some(pos) = ::Pos::from_heading(name) {
+                return Some(::pos::PosHeading {
+                    name: None,
+                    number: Some(number),
+                    pos,
+                });
             }
-            etymology = true;
-            node_index += 1;
-            ::add_warning(context, node, ::WarningMessage::Supplementary);
         }
-        while let Some(node) = nodes.get(node_index) {
-            macro_rules! parse_section { ( $output:tt $function:path ) => { {
+    }
+    // An unrecognized heading is taken to be a part of speech if the section has a list of definitions.
+    if context.parse_unknown_parts_of_speech
+        && nodes
+            .iter()
+            .take_while(|node| !matches!(node, ::Node::Heading { .. }))
+            .any(|node| matches!(node, ::Node::OrderedList { .. }))
+    {
+        return Some(::pos::PosHeading {
+            name: Some(heading_text.clone()),
+            number: None,
+            pos: ::Pos::Other,
+        });
+    }
+    None
+}
+
+fn parse_numbered_etymology<'a>(
+    context: &mut ::Context<'a, '_>,
+    heading_node: &::Node,
+    nodes: &[::Node<'a>],
+    output: &mut Vec<::EtymologyEntry<'a>>,
+) -> usize {
+    let mut etymology = ::etymology::Etymology::default();
+    let node_index = ::etymology::parse_etymology_nodes(context, nodes, &mut etymology);
+    let etymology = if node_index > 0 {
+        Some(etymology)
+    } else {
+        None
+    };
+    parse_numbered_section(
+        context,
+        heading_node,
+        nodes,
+        output,
+        node_index,
+        etymology,
+        None,
+    )
+}
+
+// A numbered pronunciation section has the same subsections as a numbered etymology section, but begins with the content of a pronunciation section instead of an etymology.
+fn parse_numbered_pronunciation<'a>(
+    context: &mut ::Context<'a, '_>,
+    heading_node: &::Node,
+    nodes: &[::Node<'a>],
+    output: &mut Vec<::EtymologyEntry<'a>>,
+) -> usize {
+    let mut pronunciation = None;
+    let node_index =
+        ::pronunciation::parse_pronunciation(context, heading_node, nodes, &mut pronunciation);
+    parse_numbered_section(
+        context,
+        heading_node,
+        nodes,
+        output,
+        node_index,
+        None,
+        pronunciation,
+    )
+}
+
+fn parse_numbered_section<'a>(
+    context: &mut ::Context<'a, '_>,
+    heading_node: &::Node,
+    nodes: &[::Node<'a>],
+    output: &mut Vec<::EtymologyEntry<'a>>,
+    mut node_index: usize,
+    etymology: Option<::etymology::Etymology<'a>>,
+    mut pronunciation: Option<::Pronunciation<'a>>,
+) -> usize {
+    let mut alternative_forms = false;
+    let mut pos_entries = vec![];
+    let mut root = vec![];
+    while let Some(node) = nodes.get(node_index) {
+        // The rest of the section is not parsed once the output is too large, since the section is left out of the output, or once the visitor has stopped the parsing.
+        if context.visit_stopped || ::stats::is_output_too_large(context) {
+            break;
+        }
+        macro_rules! parse_section {
+            ( $output:tt, $function:path ) => {{
                 node_index += 1;
                 node_index += $function(context, node, &nodes[node_index..], &mut $output);
                 continue;
-            } } }
-            match node {
-                ::Node::Heading { level, nodes: heading_child_nodes, .. } if *level < 5 => {
-                    if *level < 4 {
-                        break;
+            }};
+        }
+        match node {
+            ::Node::Heading {
+                level,
+                nodes: heading_child_nodes,
+                ..
+            } if *level < 5 => {
+                if *level < 4 {
+                    break;
+                }
+                if let Some(heading_text) = ::parse_text(heading_child_nodes) {
+                    if ::is_skipped_section(context, &heading_text) {
+                        node_index += 1;
+                        node_index +=
+                            ::supplementary::skip_section(context, *level, &nodes[node_index..]);
+                        continue;
                     }
-                    if let Some(heading_text) = ::parse_text(heading_child_nodes) {
-                        match &heading_text as _ {
-                            "Alternative forms" => parse_section!(alternative_forms ::supplementary::parse_supplementary),
-                            "Pronunciation" => parse_section!(pronunciation ::pronunciation::parse_pronunciation),
-                            $( $name => {
+                    match &heading_text as _ {
+                        "Alternative forms" => {
+                            parse_section!(alternative_forms, ::supplementary::parse_supplementary)
+                        }
+                        "Pronunciation" => {
+                            parse_section!(pronunciation, ::pronunciation::parse_pronunciation)
+                        }
+                        // On a page in the namespace `Reconstruction`, the heading is the part of speech of a reconstructed root.
+                        "Root" if context.reconstruction => {
+                            node_index += 1;
+                            node_index += ::pos::parse_pos(
+                                context,
+                                node,
+                                &nodes[node_index..],
+                                &mut pos_entries,
+                                5,
+                                ::pos::PosHeading {
+                                    name: None,
+                                    number: None,
+                                    pos: ::Pos::Root,
+                                },
+                            );
+                            continue;
+                        }
+                        "Root" => parse_section!(root, ::root::parse_root),
+                        _ => {
+                            if let Some(heading) =
+                                parse_pos_heading(context, &heading_text, &nodes[node_index + 1..])
+                            {
                                 node_index += 1;
-                                node_index += ::pos::parse_pos(context, node, &nodes[node_index..], &mut pos_entries, 5, ::Pos::$variant);
+                                node_index += ::pos::parse_pos(
+                                    context,
+                                    node,
+                                    &nodes[node_index..],
+                                    &mut pos_entries,
+                                    5,
+                                    heading,
+                                );
                                 continue;
-                            } )+
-                            _ => {}
+                            }
                         }
                     }
                 }
-                _ => {}
             }
-            node_index += 1;
-            ::add_warning(context, node, ::WarningMessage::Unrecognized);
+            ::Node::Template {
+                name, parameters, ..
+            } => {
+                if let Some(name) = ::parse_text(name) {
+                    if ::category::parse_category(context, node, &name, parameters) {
+                        node_index += 1;
+                        continue;
+                    }
+                }
+            }
+            _ => {}
         }
-        if pos_entries.is_empty() {
-            ::add_warning(context, heading_node, ::WarningMessage::SectionEmpty);
+        node_index += 1;
+        ::add_warning(context, node, ::WarningMessage::Unrecognized);
+    }
+    if pos_entries.is_empty() {
+        ::add_warning(context, heading_node, ::WarningMessage::SectionEmpty);
+    }
+    let has_etymology = etymology.is_some();
+    let etymology = etymology.unwrap_or_default();
+    output.push(::EtymologyEntry {
+        alternative_forms,
+        cognates: etymology.cognates,
+        derivations: etymology.derivations,
+        doublets: etymology.doublets,
+        etymology: if has_etymology {
+            Some(etymology.text)
+        } else {
+            None
+        },
+        onomatopoeic: etymology.onomatopoeic,
+        pie_roots: etymology.pie_roots,
+        pos_entries,
+        pronunciation,
+        requests: etymology.requests,
+        root,
+        uncertain_origin: etymology.uncertain_origin,
+        unknown_origin: etymology.unknown_origin,
+        word_formations: etymology.word_formations,
+    });
+    node_index
+}
+
+pub fn merge_language_entry<'a>(target: &mut ::LanguageEntry<'a>, source: ::LanguageEntry<'a>) {
+    target.anagrams |= source.anagrams;
+    for category in source.categories {
+        if !target.categories.contains(&category) {
+            target.categories.push(category);
         }
-        let pronunciation = pronunciation.unwrap_or_default();
-        output.push(::EtymologyEntry {
-            alternative_forms,
-            audio: pronunciation.audio,
-            etymology,
-            homophones: pronunciation.homophones,
-            hyphenation: pronunciation.hyphenation,
-            ipa: pronunciation.ipa,
-            pos_entries,
-            rhymes: pronunciation.rhymes
-        });
-        node_index
     }
-}}
+    target.etymology_entries.extend(source.etymology_entries);
+    merge_etymology_entry(&mut target.etymology_entry, source.etymology_entry);
+    if target.forms.is_none() {
+        target.forms = source.forms;
+    }
+    target.further_reading |= source.further_reading;
+    match (&mut target.glyph_origin, source.glyph_origin) {
+        (_, None) => {}
+        (Some(target), Some(source)) => target.extend(source),
+        (target, source) => *target = source,
+    }
+    target.resources.extend(source.resources);
+}
 
-module! {
-    "Adjective" Adjective,
-    "Adverb" Adverb,
-    "Article" Article,
-    "Conjunction" Conjunction,
-    "Interjection" Interjection,
-    "Noun" Noun,
-    "Numeral" Numeral,
-    "Particle" Particle,
-    "Phrase" Phrase,
-    "Preposition" Preposition,
-    "Pronoun" Pronoun,
-    "Proper noun" ProperNoun,
-    "Verb" Verb
+fn merge_etymology_entry<'a>(target: &mut ::EtymologyEntry<'a>, source: ::EtymologyEntry<'a>) {
+    target.alternative_forms |= source.alternative_forms;
+    for cognate in source.cognates {
+        if !target.cognates.contains(&cognate) {
+            target.cognates.push(cognate);
+        }
+    }
+    for derivation in source.derivations {
+        if !target.derivations.contains(&derivation) {
+            target.derivations.push(derivation);
+        }
+    }
+    for doublet in source.doublets {
+        if !target.doublets.contains(&doublet) {
+            target.doublets.push(doublet);
+        }
+    }
+    match (&mut target.etymology, source.etymology) {
+        (_, None) => {}
+        (Some(target), Some(source)) => target.extend(source),
+        (target, source) => *target = source,
+    }
+    target.onomatopoeic |= source.onomatopoeic;
+    for root in source.pie_roots {
+        if !target.pie_roots.contains(&root) {
+            target.pie_roots.push(root);
+        }
+    }
+    target.pos_entries.extend(source.pos_entries);
+    match (&mut target.pronunciation, source.pronunciation) {
+        (_, None) => {}
+        (Some(target), Some(source)) => merge_pronunciation(target, source),
+        (target, source) => *target = source,
+    }
+    for request in source.requests {
+        if !target.requests.contains(&request) {
+            target.requests.push(request);
+        }
+    }
+    if target.root.is_empty() {
+        target.root = source.root;
+    }
+    target.uncertain_origin |= source.uncertain_origin;
+    target.unknown_origin |= source.unknown_origin;
+    for word_formation in source.word_formations {
+        if !target.word_formations.contains(&word_formation) {
+            target.word_formations.push(word_formation);
+        }
+    }
+}
+
+fn merge_pronunciation<'a>(target: &mut ::Pronunciation<'a>, source: ::Pronunciation<'a>) {
+    for audio in source.audio {
+        if !target.audio.contains(&audio) {
+            target.audio.push(audio);
+        }
+    }
+    for transcription in source.enpr {
+        if !target.enpr.contains(&transcription) {
+            target.enpr.push(transcription);
+        }
+    }
+    for homophone in source.homophones {
+        if !target.homophones.contains(&homophone) {
+            target.homophones.push(homophone);
+        }
+    }
+    for syllables in source.hyphenation {
+        if !target.hyphenation.contains(&syllables) {
+            target.hyphenation.push(syllables);
+        }
+    }
+    for transcription in source.ipa {
+        if !target.ipa.contains(&transcription) {
+            target.ipa.push(transcription);
+        }
+    }
+    target.ipa_templates.extend(source.ipa_templates);
+    for rhyme in source.rhymes {
+        if !target.rhymes.contains(&rhyme) {
+            target.rhymes.push(rhyme);
+        }
+    }
+    for transcription in source.x_sampa {
+        if !target.x_sampa.contains(&transcription) {
+            target.x_sampa.push(transcription);
+        }
+    }
 }
@@ -11,7 +11,6 @@
 //! This example prints all definitions found in an article, together with the language and part of speech of the entry.
 //!
 //! ```
-//! # extern crate parse_wiki_text;
 //! # extern crate parse_wiktionary_en;
 //! #
 //! let wiki_text = concat!(
@@ -30,11 +29,7 @@
 //!                 "The word 'commodification' of language {language:?} and part of speech {pos:?} has the definition: {definition}",
 //!                 language = language_entry.language,
 //!                 pos = pos_entry.pos,
-//!                 definition = &definition.definition.iter().map(|node| match node {
-//!                     parse_wiktionary_en::Flowing::Link { target, text } => text,
-//!                     parse_wiktionary_en::Flowing::Text { value } => value,
-//!                     _ => ""
-//!                 }).collect::<String>()
+//!                 definition = parse_wiktionary_en::render_plain(&definition.definition)
 //!             );
 //! #           found = true;
 //!         }
@@ -46,27 +41,243 @@
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
 
-extern crate parse_wiki_text;
+/// The crate [Parse Wiki Text](https://github.com/portstrom/parse_wiki_text), re-exported so the nodes passed to [`parse`](fn.parse.html) can be obtained from the same version of it as this crate uses.
+pub extern crate parse_wiki_text;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
 
+mod category;
 mod configuration;
+mod conjugation;
+mod coverage;
+mod date;
+mod declension;
 mod definition;
+mod etymology;
+mod flowing;
+mod head;
 mod inflection;
+#[cfg(feature = "label-kinds")]
+mod label;
 mod language;
+mod markdown;
+mod owned;
 mod pos;
 mod pronunciation;
+mod request;
+mod resource;
+mod root;
+mod stats;
 mod supplementary;
 mod template;
+mod template_section;
+mod text;
 mod usage_notes;
 mod util;
+mod visitor;
+mod wiki_text;
+mod x_sampa;
 
 pub use configuration::create_configuration;
-use parse_wiki_text::{ListItem, Node, Parameter};
-use std::{borrow::Cow, collections::HashMap};
+pub use markdown::render_markdown;
+pub use text::render_plain;
+pub use x_sampa::x_sampa_to_ipa;
+use parse_wiki_text::{ListItem, Node, Parameter, Positioned};
+use std::{borrow::Cow, collections::BTreeMap, ops::Range, sync::OnceLock};
 use util::*;
 
+/// Receives the entries of an article as they are parsed by [`parse_with_visitor`](fn.parse_with_visitor.html).
+///
+/// Each method is called for each entry of its kind in the order they occur in the article, as soon as the entry has been parsed, and returns whether to continue. Parsing stops as soon as a method returns [`Stop`](enum.Visit.html#variant.Stop). The default implementations do nothing and continue.
+pub trait ArticleVisitor<'a> {
+    /// Receives an entry for a language, after the whole section has been parsed and its entries for parts of speech have been visited.
+    ///
+    /// Since the entries contained in the language entry have already been visited, returning [`Skip`](enum.Visit.html#variant.Skip) is the same as returning [`Continue`](enum.Visit.html#variant.Continue). A language entry is not visited when parsing stopped within its section.
+    fn visit_language_entry(&mut self, _language_entry: &LanguageEntry<'a>) -> Visit {
+        Visit::Continue
+    }
+
+    /// Receives an entry for a part of speech of the given language, before its definitions are visited.
+    ///
+    /// The entries for parts of speech of all etymologies of the language section are visited in the order they occur in the section.
+    fn visit_pos_entry(&mut self, _language: Language, _pos_entry: &PosEntry<'a>) -> Visit {
+        Visit::Continue
+    }
+
+    /// Receives a definition of an entry for a part of speech of the given language.
+    ///
+    /// Nested definitions are not visited separately, but are included in the definition they're nested in.
+    fn visit_definition(
+        &mut self,
+        _language: Language,
+        _pos_entry: &PosEntry<'a>,
+        _definition: &Definition<'a>,
+    ) -> Visit {
+        Visit::Continue
+    }
+}
+
+/// Audio sample of the pronunciation of an entry, from one of the templates [`audio`](https://en.wiktionary.org/wiki/Template:audio) and [`audio-IPA`](https://en.wiktionary.org/wiki/Template:audio-IPA).
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Audio<'a> {
+    /// Accents the audio sample applies to, such as `UK` or `US`, from the template [`a`](https://en.wiktionary.org/wiki/Template:a) preceding the audio sample or a parameter of the template of the audio sample.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub accents: Vec<Cow<'a, str>>,
+
+    /// The text to display for the audio sample, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Cow<'a, str>>,
+
+    /// The name of the audio file in Wikimedia Commons, without the namespace.
+    pub file: Cow<'a, str>,
+}
+
+/// Grammatical case.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Case {
+    /// Accusative
+    Accusative,
+
+    /// Dative
+    Dative,
+
+    /// Genitive
+    Genitive,
+
+    /// Instrumental
+    Instrumental,
+
+    /// Locative
+    Locative,
+
+    /// Nominative
+    Nominative,
+
+    /// Vocative
+    Vocative,
+}
+
+/// Category the entry belongs to, from one of the templates [`C`](https://en.wiktionary.org/wiki/Template:C) and [`catlangname`](https://en.wiktionary.org/wiki/Template:catlangname).
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Category<'a> {
+    /// The kind of category.
+    pub kind: CategoryKind,
+
+    /// The name of the category, without the language.
+    pub name: Cow<'a, str>,
+}
+
+/// Term in another language related to the entry, from one of the templates [`cog`](https://en.wiktionary.org/wiki/Template:cognate) and [`noncog`](https://en.wiktionary.org/wiki/Template:noncognate).
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Cognate<'a> {
+    /// Gloss translating or explaining the term, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gloss: Option<Cow<'a, str>>,
+
+    /// The language code of the term.
+    pub language: Cow<'a, str>,
+
+    /// Whether the term is explicitly not a cognate, from the template [`noncog`](https://en.wiktionary.org/wiki/Template:noncognate), as when the term is compared to the entry but has a different origin.
+    pub noncognate: bool,
+
+    /// The term, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub term: Option<Cow<'a, str>>,
+}
+
+/// Kind of category.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CategoryKind {
+    /// Category named after the language, such as `English uncountable nouns`, from the template [`catlangname`](https://en.wiktionary.org/wiki/Template:catlangname).
+    ///
+    /// The name is the part after the language name, such as `uncountable nouns`.
+    LanguageName,
+
+    /// Topical category, such as `en:Medicine`, from the template [`C`](https://en.wiktionary.org/wiki/Template:C).
+    ///
+    /// The name is the part after the language code, such as `Medicine`.
+    Topic,
+}
+
+/// Form in the conjugation of a verb, interpreted from the parameters of a conjugation template.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ConjugatedForm<'a> {
+    /// The form itself, such as `hablamos`.
+    pub form: Cow<'a, str>,
+
+    /// The mood of the form, or the kind of non-finite form, such as an infinitive or participle.
+    pub mood: Mood,
+
+    /// The grammatical number of the form, if it's a personal form.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<Number>,
+
+    /// The grammatical person of the form, if it's a personal form.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub person: Option<Person>,
+
+    /// The tense of the form, if it has one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tense: Option<Tense>,
+}
+
+/// Whether a noun is countable, as indicated by the head template [`en-noun`](https://en.wiktionary.org/wiki/Template:en-noun).
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Countability {
+    /// Countable
+    Countable,
+
+    /// Countable and uncountable, depending on the sense.
+    CountableAndUncountable,
+
+    /// Uncountable
+    Uncountable,
+
+    /// Usually uncountable, but with plurals for the senses that are countable.
+    UsuallyUncountable,
+}
+
+/// How much of a language section was parsed into the output.
+///
+/// The fractions are fractions of the bytes of wiki text in the section, including its heading, and add up to one.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Coverage {
+    /// The language of the section.
+    pub language: Language,
+
+    /// The fraction of the section that was parsed into the output.
+    ///
+    /// This includes everything that doesn't have any of the warnings counted as supplementary or unrecognized.
+    pub parsed: f64,
+
+    /// The fraction of the section that has the warning [`Supplementary`](enum.WarningMessage.html#variant.Supplementary).
+    pub supplementary: f64,
+
+    /// The fraction of the section that has any of the warnings [`Unrecognized`](enum.WarningMessage.html#variant.Unrecognized), [`ValueConflicting`](enum.WarningMessage.html#variant.ValueConflicting) and [`ValueUnrecognized`](enum.WarningMessage.html#variant.ValueUnrecognized).
+    pub unrecognized: f64,
+}
+
+/// Form in the declension of a noun, interpreted from the parameters of a declension template.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct DeclinedForm<'a> {
+    /// The case of the form.
+    pub case: Case,
+
+    /// Whether the form is definite, for languages that decline nouns for definiteness, such as Swedish.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub definite: Option<bool>,
+
+    /// The form itself, such as `Hundes`.
+    pub form: Cow<'a, str>,
+
+    /// The grammatical number of the form.
+    pub number: Number,
+}
+
 /// A single definition from a list of definitions of an entry.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Definition<'a> {
@@ -83,28 +294,86 @@ pub struct Definition<'a> {
 
     /// Number of quotations the definition has.
     pub quotations: u32,
+
+    /// Requests for improvement of the definition.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub requests: Vec<Request>,
+}
+
+/// Event in the history of an entry, such as inheritance or borrowing from another language, from one of the templates [`inh`](https://en.wiktionary.org/wiki/Template:inherited), [`bor`](https://en.wiktionary.org/wiki/Template:borrowed), [`calque`](https://en.wiktionary.org/wiki/Template:calque), [`der`](https://en.wiktionary.org/wiki/Template:derived) and [`lbor`](https://en.wiktionary.org/wiki/Template:learned_borrowing).
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Derivation<'a> {
+    /// Gloss translating or explaining the source term, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gloss: Option<Cow<'a, str>>,
+
+    /// The kind of derivation.
+    pub kind: DerivationKind,
+
+    /// The language code of the source language.
+    pub language: Cow<'a, str>,
+
+    /// The source term, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub term: Option<Cow<'a, str>>,
+}
+
+/// Kind of derivation.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DerivationKind {
+    /// Borrowing from another language, from the template [`bor`](https://en.wiktionary.org/wiki/Template:borrowed).
+    Borrowed,
+
+    /// Translation of the parts of a term of another language, from the template [`calque`](https://en.wiktionary.org/wiki/Template:calque).
+    Calque,
+
+    /// Derivation from another language that is not specified further, from the template [`der`](https://en.wiktionary.org/wiki/Template:derived).
+    Derived,
+
+    /// Inheritance from an earlier stage of the language, from the template [`inh`](https://en.wiktionary.org/wiki/Template:inherited).
+    Inherited,
+
+    /// Learned borrowing from a classical language, from the template [`lbor`](https://en.wiktionary.org/wiki/Template:learned_borrowing).
+    LearnedBorrowing,
 }
 
 /// Details related to a specific etymology, either one that has a numbered etymology heading or the same format of information directly in the language entry.
+///
+/// A section with a numbered pronunciation heading, such as `Pronunciation 1`, is also parsed into an etymology entry. Its pronunciation is the content before its first subsection, and it has no etymology.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct EtymologyEntry<'a> {
-    /// Whether the entry has audio samples.
-    pub audio: bool,
-
     /// Whether the entry has alternative forms.
     pub alternative_forms: bool,
 
-    /// Whether the entry has a description of its etymology.
-    pub etymology: bool,
+    /// Cognates and other related terms in other languages the etymology compares the entry to, in the order they appear in the description of the etymology.
+    ///
+    /// The term of a cognate, if any, also appears as the element [`Term`](enum.Flowing.html#variant.Term) in the description of the etymology.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cognates: Vec<Cognate<'a>>,
+
+    /// Derivations in the etymology of the entry, in the order they appear in the description of the etymology.
+    ///
+    /// The source term of a derivation, if any, also appears as the element [`Term`](enum.Flowing.html#variant.Term) in the description of the etymology.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub derivations: Vec<Derivation<'a>>,
+
+    /// Terms of the same language with the same origin as the entry, from the template [`doublet`](https://en.wiktionary.org/wiki/Template:doublet).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub doublets: Vec<Cow<'a, str>>,
 
-    /// Whether the entry has homophones.
-    pub homophones: bool,
+    /// Description of the etymology of the entry, from the subsection `Etymology`, or from the beginning of a section with a numbered etymology heading.
+    ///
+    /// Templates that are recognized as parts of the etymology, such as [`PIE root`](https://en.wiktionary.org/wiki/Template:PIE_root), are not included in the description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub etymology: Option<Vec<Flowing<'a>>>,
 
-    /// Whether the entry has hyphenations.
-    pub hyphenation: bool,
+    /// Whether the etymology states the entry is onomatopoeic, from the template [`onomatopoeic`](https://en.wiktionary.org/wiki/Template:onomatopoeic).
+    pub onomatopoeic: bool,
 
-    /// Whether the entry has a pronunciation written in IPA.
-    pub ipa: bool,
+    /// Proto-Indo-European roots the entry derives from, from the template [`PIE root`](https://en.wiktionary.org/wiki/Template:PIE_root).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pie_roots: Vec<Cow<'a, str>>,
 
     /// Entries for parts of speech for this etymology.
     ///
@@ -112,8 +381,29 @@ pub struct EtymologyEntry<'a> {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub pos_entries: Vec<PosEntry<'a>>,
 
-    /// Whether the entry has rhymes.
-    pub rhymes: bool,
+    /// Pronunciation of the entry, from the subsection `Pronunciation`, if present.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pronunciation: Option<Pronunciation<'a>>,
+
+    /// Requests for improvement of the etymology.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub requests: Vec<Request>,
+
+    /// The radicals of the consonantal root of the entry, from the subsection `Root` with one of the templates [`ar-root`](https://en.wiktionary.org/wiki/Template:ar-root) for Arabic and [`HE root`](https://en.wiktionary.org/wiki/Template:HE_root) for Hebrew.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub root: Vec<Cow<'a, str>>,
+
+    /// Whether the etymology states the origin of the entry is uncertain, from the template [`uncertain`](https://en.wiktionary.org/wiki/Template:uncertain).
+    pub uncertain_origin: bool,
+
+    /// Whether the etymology states the origin of the entry is unknown, from the template [`unknown`](https://en.wiktionary.org/wiki/Template:unknown).
+    pub unknown_origin: bool,
+
+    /// Formations of the entry from parts, in the order they appear in the description of the etymology.
+    ///
+    /// Each part also appears as the element [`Term`](enum.Flowing.html#variant.Term) in the description of the etymology.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub word_formations: Vec<WordFormation<'a>>,
 }
 
 /// An element in a sequence that allows different kinds of elements.
@@ -125,24 +415,68 @@ pub enum Flowing<'a> {
     /// Parsed from the wiki text `'''`.
     Bold,
 
+    /// Character entity.
+    ///
+    /// Parsed from wiki text starting with `&`. The character is kept separate from adjacent text to avoid allocating a combined string.
+    CharacterEntity {
+        /// The character represented.
+        character: char,
+    },
+
     /// Definition date, from the template [`defdate`](https://en.wiktionary.org/wiki/Template:defdate).
     DefinitionDate {
+        /// The years of the first attestation of the definition, if recognized.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        first_attestation: Option<YearRange>,
+
+        /// The years when the definition became obsolete, if recognized.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        obsolescence: Option<YearRange>,
+
+        /// The text to display as the date when the definition became obsolete, if any.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        obsolescence_value: Option<Cow<'a, str>>,
+
         /// The text to display as the definition date.
         value: Cow<'a, str>,
     },
 
+    /// Link to a page outside of Wiktionary.
+    ///
+    /// Parsed from wiki text starting with `[` followed by a URL.
+    ExternalLink {
+        /// The text to display for the link.
+        ///
+        /// This is empty if the link has no text, in which case it's displayed as a number.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        text: Vec<Flowing<'a>>,
+
+        /// The URL the link refers to.
+        url: Cow<'a, str>,
+    },
+
     /// Toggle italic.
     ///
     /// Parsed from the wiki text `''`.
     Italic,
 
     /// List of labels, from the template [`label`](https://en.wiktionary.org/wiki/Template:label).
+    ///
+    /// The separator `_` joins the labels on both sides of it into a single label separated by a space. The separators `and`, `&` and `or` only separate labels.
     Labels {
+        /// Whether the labels are separated by `or`, meaning any one of them applies, rather than all of them.
+        disjunctive: bool,
+
         /// The labels.
         #[serde(default, skip_serializing_if = "Vec::is_empty")]
-        labels: Vec<Cow<'a, str>>,
+        labels: Vec<Label<'a>>,
     },
 
+    /// Line break.
+    ///
+    /// Parsed from the HTML tag `br`.
+    LineBreak,
+
     /// Link.
     ///
     /// Parsed from wiki text starting with `[[`.
@@ -154,6 +488,20 @@ pub enum Flowing<'a> {
         text: Cow<'a, str>,
     },
 
+    /// Image or other media file.
+    ///
+    /// Parsed from wiki text starting with `[[` followed by the namespace `File` or `Image`.
+    Media {
+        /// The caption of the file.
+        ///
+        /// Formatting options such as `thumb` and `200px` are not included.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        caption: Vec<Flowing<'a>>,
+
+        /// The name of the file, without the namespace.
+        file: Cow<'a, str>,
+    },
+
     /// Non-gloss definition, from the template [`non-gloss definition`](https://en.wiktionary.org/wiki/Template:non-gloss_definition).
     NonGlossDefinition {
         /// The text to display.
@@ -161,21 +509,96 @@ pub enum Flowing<'a> {
         value: Vec<Flowing<'a>>,
     },
 
+    /// Paragraph break.
+    ///
+    /// Parsed from an empty line between paragraphs.
+    ParagraphBreak,
+
     /// Indication of a reference.
     ///
     /// Parsed from the extension tag `ref`. The content if the reference is not parsed. This element is added to the output just to indicate the existence of a reference.
     Reference,
 
-    /// Link to another dictionary entry, from the template [`mention`](https://en.wiktionary.org/wiki/Template:mention).
+    /// Text in small capitals, from the template [`smallcaps`](https://en.wiktionary.org/wiki/Template:smallcaps).
+    SmallCapitals {
+        /// The text to display in small capitals.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        value: Vec<Flowing<'a>>,
+    },
+
+    /// Subscript text.
+    ///
+    /// Parsed from the HTML tag `sub` or the template [`sub`](https://en.wiktionary.org/wiki/Template:sub).
+    Subscript {
+        /// The text to display in subscript.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        value: Vec<Flowing<'a>>,
+    },
+
+    /// Superscript text.
+    ///
+    /// Parsed from the HTML tag `sup` or the template [`sup`](https://en.wiktionary.org/wiki/Template:sup).
+    Superscript {
+        /// The text to display in superscript.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        value: Vec<Flowing<'a>>,
+    },
+
+    /// Table.
+    ///
+    /// Parsed from wiki text starting with `{|`. The attributes of the table, its rows and its cells are not parsed.
+    Table {
+        /// The captions of the table.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        captions: Vec<Vec<Flowing<'a>>>,
+
+        /// The cells of each row of the table.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        rows: Vec<Vec<TableCell<'a>>>,
+    },
+
+    /// Link to another dictionary entry, from the template [`mention`](https://en.wiktionary.org/wiki/Template:mention) or [`link`](https://en.wiktionary.org/wiki/Template:link).
     Term {
+        /// Alternative text to display instead of the term, if any.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        alternative: Option<Cow<'a, str>>,
+
+        /// Gloss translating or explaining the term, if any.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        gloss: Option<Cow<'a, str>>,
+
+        /// Identifier of the sense or etymology of the entry the link refers to, if any.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<Cow<'a, str>>,
+
         /// The language of the entry the link refers to.
         language: Cow<'a, str>,
 
+        /// Literal meaning of the term, if any.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        literal: Option<Cow<'a, str>>,
+
+        /// Part of speech of the term, if any.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        part_of_speech: Option<Cow<'a, str>>,
+
         /// The term the link refers to.
+        ///
+        /// This is empty if the link refers to no entry and only displays the alternative text.
         term: Cow<'a, str>,
+
+        /// Transcription of the term, if any.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        transcription: Option<Cow<'a, str>>,
+
+        /// Transliteration of the term into the Latin script, if any.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        transliteration: Option<Cow<'a, str>>,
     },
 
     /// Chunk of plain text.
+    ///
+    /// Also produced from templates that only display a fixed piece of text, such as [`,`](https://en.wiktionary.org/wiki/Template:,) and [`nbsp`](https://en.wiktionary.org/wiki/Template:nbsp).
     Text {
         /// The text to display.
         value: Cow<'a, str>,
@@ -192,183 +615,2204 @@ pub enum Flowing<'a> {
         /// The list items of the list.
         items: Vec<Vec<Flowing<'a>>>,
     },
+
+    /// Link to an article in Wikipedia.
+    ///
+    /// Parsed from the template [`w`](https://en.wiktionary.org/wiki/Template:w) or from wiki text starting with `[[w:`.
+    WikipediaLink {
+        /// The language code of the edition of Wikipedia the link refers to, if other than English.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        language: Option<Cow<'a, str>>,
+
+        /// The title of the article the link refers to.
+        target: Cow<'a, str>,
+
+        /// The text to display for the link.
+        text: Cow<'a, str>,
+    },
 }
 
-/// Identifier for a language.
+/// Grammatical gender.
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[serde(rename_all = "snake_case")]
-pub enum Language {
-    /// Czech
-    Cs,
+pub enum Gender {
+    /// Common gender, the merger of masculine and feminine in languages such as Danish, Dutch and Swedish.
+    Common,
 
-    /// German
-    De,
+    /// Feminine
+    Feminine,
 
-    /// English
-    En,
+    /// Masculine
+    Masculine,
 
-    /// Esperanto
-    Eo,
+    /// Neuter
+    Neuter,
+}
 
-    /// Spanish
-    Es,
+/// Homophone of an entry, from the template [`homophones`](https://en.wiktionary.org/wiki/Template:homophones).
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Homophone<'a> {
+    /// Qualifiers restricting when the term is a homophone, such as accents it's a homophone in.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub qualifiers: Vec<Cow<'a, str>>,
 
-    /// French
-    Fr,
+    /// The homophonous term.
+    pub term: Cow<'a, str>,
+}
 
-    /// Italian
-    It,
+/// Label from the template [`label`](https://en.wiktionary.org/wiki/Template:label).
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Label<'a> {
+    /// The category of the label, if known.
+    ///
+    /// Labels are only classified when the feature `label-kinds`, which is enabled by default, is enabled. Otherwise this is always `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<LabelKind>,
 
-    /// Dutch
-    Nl,
+    /// The text of the label.
+    pub text: Cow<'a, str>,
+}
 
-    /// Portuguese
-    Pt,
+/// Category of a label.
+///
+/// The categories are based on the [label data](https://en.wiktionary.org/wiki/Module:labels/data) of Wiktionary. Only the most common labels are recognized.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LabelKind {
+    /// Grammatical property, such as `transitive` or `uncountable`.
+    Grammar,
 
-    /// Russian
-    Ru,
+    /// Region where the term is used, such as `US` or `Scotland`.
+    Region,
 
-    /// Swedish
-    Sv,
+    /// Register or style, such as `informal` or `vulgar`.
+    Register,
+
+    /// Period when the term is used, such as `archaic` or `obsolete`.
+    Temporal,
+
+    /// Field of knowledge, such as `chemistry` or `law`.
+    Topic,
 }
 
-/// Dictionary entry for a single language.
-#[derive(Debug, Deserialize, Serialize)]
-pub struct LanguageEntry<'a> {
-    /// Whether the subsection `Anagrams` is present in the section.
-    pub anagrams: bool,
+/// Defines the enum `Language` together with the names and codes of the languages, and the list of all of them.
+macro_rules! languages {
+    ($($(#[$attribute:meta])* $variant:ident = $code:tt $name:tt,)*) => {
+        /// Identifier for a language.
+        ///
+        /// The languages are a selection of those in Wiktionary's language data modules, each identified by its Wiktionary language code. The selection is maintained by hand rather than generated from the modules, so it covers only some of the languages Wiktionary has sections for. Sections of other languages can be parsed with [`ParseOptions::parse_unknown_languages`](struct.ParseOptions.html#structfield.parse_unknown_languages) or [`ParseOptions::registered_languages`](struct.ParseOptions.html#structfield.registered_languages).
+        ///
+        /// A language is serialized as its language code. More languages may be added in later versions. When deserializing, an unknown language is deserialized as [`Other`](#variant.Other).
+        #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+        #[non_exhaustive]
+        pub enum Language {
+            $($(#[$attribute])* #[serde(rename = $code)] $variant,)*
+
+            /// Language that is not any of the other variants, parsed when [`ParseOptions::parse_unknown_languages`](struct.ParseOptions.html#structfield.parse_unknown_languages) is enabled.
+            ///
+            /// The name of the language from the heading is in [`LanguageEntry::language_name`](struct.LanguageEntry.html#structfield.language_name). This is also the result of deserializing a language added in a later version.
+            #[serde(other, rename = "und")]
+            Other,
+        }
 
-    /// Entries for each numbered etymology for this language.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub etymology_entries: Vec<EtymologyEntry<'a>>,
+        impl Language {
+            /// All languages except [`Other`](#variant.Other), in alphabetical order of their codes.
+            pub const ALL: &'static [Language] = &[$(Language::$variant,)*];
 
-    /// Entry for the etymology that is directly in the language entry.
-    pub etymology_entry: EtymologyEntry<'a>,
+            /// Returns the language corresponding to the given language code if any.
+            pub fn from_code(code: &str) -> Option<Self> {
+                Some(match code {
+                    $($code => Language::$variant,)*
+                    _ => return None,
+                })
+            }
 
-    /// Whether the subsection `Further reading` is present in the section.
-    pub further_reading: bool,
+            /// Returns the language corresponding to the given language name if any.
+            ///
+            /// The names of the standard varieties of Serbo-Croatian are also recognized, as the language [`Sh`](#variant.Sh).
+            pub fn from_name(name: &str) -> Option<Self> {
+                Some(match name {
+                    $($name => Language::$variant,)*
+                    // Wiktionary treats these as a single pluricentric language, but older sections may still use the name of a standard variety as the heading.
+                    "Bosnian" | "Croatian" | "Montenegrin" | "Serbian" => Language::Sh,
+                    _ => return None,
+                })
+            }
 
-    /// The language of the entry.
-    pub language: Language,
-}
+            /// Returns an iterator over all languages except [`Other`](#variant.Other), in the same order as [`ALL`](#associatedconstant.ALL).
+            pub fn iter() -> ::std::iter::Copied<::std::slice::Iter<'static, Language>> {
+                Language::ALL.iter().copied()
+            }
 
-/// Output of parsing a page.
-#[derive(Debug, Deserialize, Serialize)]
-pub struct Output<'a> {
-    /// The dictionary entries by language.
-    ///
-    /// Parsed from the sections with the name of the language as title.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub language_entries: Vec<LanguageEntry<'a>>,
+            /// Returns the language code for the language.
+            ///
+            /// For [`Other`](#variant.Other) the code is `und`, the code Wiktionary uses for an undetermined language.
+            pub fn language_code(self) -> &'static str {
+                match self {
+                    $(Language::$variant => $code,)*
+                    Language::Other => "und",
+                }
+            }
 
-    /// Warnings from the parser telling that something is not well-formed.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub warnings: Vec<Warning>,
+            /// Returns the name of the language as used in the headings of language sections.
+            ///
+            /// For [`Other`](#variant.Other) the name is `Undetermined`, the name Wiktionary uses for an undetermined language. The name from the heading of such a section is in [`LanguageEntry::language_name`](struct.LanguageEntry.html#structfield.language_name).
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Language::$variant => $name,)*
+                    Language::Other => "Undetermined",
+                }
+            }
+
+            /// Returns the title of the page of the given term in the language.
+            ///
+            /// A term prefixed with `*` is a reconstructed term, such as `*wulfaz` in Proto-Germanic, and its page is in the namespace `Reconstruction` under the name of the language, as in `Reconstruction:Proto-Germanic/wulfaz`. Other terms are their own page titles.
+            pub fn page_title<'a>(self, term: &'a str) -> Cow<'a, str> {
+                match term.strip_prefix('*') {
+                    None => Cow::Borrowed(term),
+                    Some(term) => Cow::Owned(format!("Reconstruction:{}/{}", self.name(), term)),
+                }
+            }
+        }
+    };
 }
 
-/// Part of speech.
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
-#[serde(rename_all = "snake_case")]
-pub enum Pos {
-    /// Adjective
-    Adjective,
+languages! {
+    /// Afar
+    Aa = "aa" "Afar",
 
-    /// Adverb
-    Adverb,
+    /// Abkhaz
+    Ab = "ab" "Abkhaz",
+
+    /// Abaza
+    Abq = "abq" "Abaza",
+
+    /// Acehnese
+    Ace = "ace" "Acehnese",
+
+    /// Adyghe
+    Ady = "ady" "Adyghe",
+
+    /// Avestan
+    Ae = "ae" "Avestan",
+
+    /// Afrikaans
+    Af = "af" "Afrikaans",
+
+    /// Proto-Afroasiatic
+    AfaPro = "afa-pro" "Proto-Afroasiatic",
+
+    /// Ainu
+    Ain = "ain" "Ainu",
+
+    /// Akan
+    Ak = "ak" "Akan",
+
+    /// Akkadian
+    Akk = "akk" "Akkadian",
+
+    /// Southern Altai
+    Alt = "alt" "Southern Altai",
+
+    /// Amharic
+    Am = "am" "Amharic",
+
+    /// Aragonese
+    An = "an" "Aragonese",
+
+    /// Old English
+    Ang = "ang" "Old English",
+
+    /// Arabic
+    Ar = "ar" "Arabic",
+
+    /// Aramaic
+    Arc = "arc" "Aramaic",
+
+    /// Mapudungun
+    Arn = "arn" "Mapudungun",
+
+    /// Moroccan Arabic
+    Ary = "ary" "Moroccan Arabic",
+
+    /// Egyptian Arabic
+    Arz = "arz" "Egyptian Arabic",
+
+    /// Assamese
+    As = "as" "Assamese",
+
+    /// Asturian
+    Ast = "ast" "Asturian",
+
+    /// Avar
+    Av = "av" "Avar",
+
+    /// Awadhi
+    Awa = "awa" "Awadhi",
+
+    /// Aymara
+    Ay = "ay" "Aymara",
+
+    /// Azerbaijani
+    Az = "az" "Azerbaijani",
+
+    /// Bashkir
+    Ba = "ba" "Bashkir",
+
+    /// Balochi
+    Bal = "bal" "Balochi",
+
+    /// Balinese
+    Ban = "ban" "Balinese",
+
+    /// Bavarian
+    Bar = "bar" "Bavarian",
+
+    /// Bikol Central
+    Bcl = "bcl" "Bikol Central",
+
+    /// Belarusian
+    Be = "be" "Belarusian",
+
+    /// Bulgarian
+    Bg = "bg" "Bulgarian",
+
+    /// Bhojpuri
+    Bho = "bho" "Bhojpuri",
+
+    /// Bislama
+    Bi = "bi" "Bislama",
+
+    /// Bambara
+    Bm = "bm" "Bambara",
+
+    /// Bengali
+    Bn = "bn" "Bengali",
+
+    /// Proto-Bantu
+    BntPro = "bnt-pro" "Proto-Bantu",
+
+    /// Tibetan
+    Bo = "bo" "Tibetan",
+
+    /// Breton
+    Br = "br" "Breton",
+
+    /// Brahui
+    Brh = "brh" "Brahui",
+
+    /// Burushaski
+    Bsk = "bsk" "Burushaski",
+
+    /// Buryat
+    Bua = "bua" "Buryat",
+
+    /// Buginese
+    Bug = "bug" "Buginese",
+
+    /// Catalan
+    Ca = "ca" "Catalan",
+
+    /// Chechen
+    Ce = "ce" "Chechen",
+
+    /// Cebuano
+    Ceb = "ceb" "Cebuano",
+
+    /// Proto-Celtic
+    CelPro = "cel-pro" "Proto-Celtic",
+
+    /// Chamorro
+    Ch = "ch" "Chamorro",
+
+    /// Choctaw
+    Cho = "cho" "Choctaw",
+
+    /// Cherokee
+    Chr = "chr" "Cherokee",
+
+    /// Cheyenne
+    Chy = "chy" "Cheyenne",
+
+    /// Cimbrian
+    Cim = "cim" "Cimbrian",
+
+    /// Central Kurdish
+    Ckb = "ckb" "Central Kurdish",
+
+    /// Corsican
+    Co = "co" "Corsican",
+
+    /// Coptic
+    Cop = "cop" "Coptic",
+
+    /// Cree
+    Cr = "cr" "Cree",
+
+    /// Crimean Tatar
+    Crh = "crh" "Crimean Tatar",
+
+    /// Czech
+    Cs = "cs" "Czech",
+
+    /// Kashubian
+    Csb = "csb" "Kashubian",
+
+    /// Old Church Slavonic
+    Cu = "cu" "Old Church Slavonic",
+
+    /// Chuvash
+    Cv = "cv" "Chuvash",
+
+    /// Welsh
+    Cy = "cy" "Welsh",
+
+    /// Danish
+    Da = "da" "Danish",
+
+    /// Dakota
+    Dak = "dak" "Dakota",
+
+    /// Dargwa
+    Dar = "dar" "Dargwa",
+
+    /// German
+    De = "de" "German",
+
+    /// Dalmatian
+    Dlm = "dlm" "Dalmatian",
+
+    /// Proto-Dravidian
+    DraPro = "dra-pro" "Proto-Dravidian",
+
+    /// Lower Sorbian
+    Dsb = "dsb" "Lower Sorbian",
+
+    /// Middle Dutch
+    Dum = "dum" "Middle Dutch",
+
+    /// Dhivehi
+    Dv = "dv" "Dhivehi",
+
+    /// Dzongkha
+    Dz = "dz" "Dzongkha",
+
+    /// Ewe
+    Ee = "ee" "Ewe",
+
+    /// Emilian
+    Egl = "egl" "Emilian",
+
+    /// Egyptian
+    Egy = "egy" "Egyptian",
+
+    /// Greek
+    El = "el" "Greek",
+
+    /// Elamite
+    Elx = "elx" "Elamite",
+
+    /// English
+    En = "en" "English",
+
+    /// Middle English
+    Enm = "enm" "Middle English",
+
+    /// Esperanto
+    Eo = "eo" "Esperanto",
+
+    /// Spanish
+    Es = "es" "Spanish",
+
+    /// Estonian
+    Et = "et" "Estonian",
+
+    /// Etruscan
+    Ett = "ett" "Etruscan",
+
+    /// Basque
+    Eu = "eu" "Basque",
+
+    /// Persian
+    Fa = "fa" "Persian",
+
+    /// Fula
+    Ff = "ff" "Fula",
+
+    /// Finnish
+    Fi = "fi" "Finnish",
+
+    /// Fijian
+    Fj = "fj" "Fijian",
+
+    /// Kven
+    Fkv = "fkv" "Kven",
+
+    /// Faroese
+    Fo = "fo" "Faroese",
+
+    /// French
+    Fr = "fr" "French",
+
+    /// Middle French
+    Frm = "frm" "Middle French",
+
+    /// Old French
+    Fro = "fro" "Old French",
+
+    /// Franco-Provençal
+    Frp = "frp" "Franco-Provençal",
+
+    /// North Frisian
+    Frr = "frr" "North Frisian",
+
+    /// Friulian
+    Fur = "fur" "Friulian",
+
+    /// West Frisian
+    Fy = "fy" "West Frisian",
+
+    /// Irish
+    Ga = "ga" "Irish",
+
+    /// Gagauz
+    Gag = "gag" "Gagauz",
+
+    /// Scottish Gaelic
+    Gd = "gd" "Scottish Gaelic",
+
+    /// Proto-Germanic
+    GemPro = "gem-pro" "Proto-Germanic",
+
+    /// Ge'ez
+    Gez = "gez" "Ge'ez",
+
+    /// Gilbertese
+    Gil = "gil" "Gilbertese",
+
+    /// Galician
+    Gl = "gl" "Galician",
+
+    /// Middle High German
+    Gmh = "gmh" "Middle High German",
+
+    /// Middle Low German
+    Gml = "gml" "Middle Low German",
+
+    /// Old Danish
+    GmqOda = "gmq-oda" "Old Danish",
+
+    /// Old Swedish
+    GmqOsw = "gmq-osw" "Old Swedish",
+
+    /// Proto-Norse
+    GmqPro = "gmq-pro" "Proto-Norse",
+
+    /// Proto-West Germanic
+    GmwPro = "gmw-pro" "Proto-West Germanic",
+
+    /// Mycenaean Greek
+    Gmy = "gmy" "Mycenaean Greek",
+
+    /// Guaraní
+    Gn = "gn" "Guaraní",
+
+    /// Old High German
+    Goh = "goh" "Old High German",
+
+    /// Gothic
+    Got = "got" "Gothic",
+
+    /// Ancient Greek
+    Grc = "grc" "Ancient Greek",
+
+    /// Proto-Hellenic
+    GrkPro = "grk-pro" "Proto-Hellenic",
+
+    /// Alemannic German
+    Gsw = "gsw" "Alemannic German",
+
+    /// Gujarati
+    Gu = "gu" "Gujarati",
+
+    /// Manx
+    Gv = "gv" "Manx",
+
+    /// Hausa
+    Ha = "ha" "Hausa",
+
+    /// Hawaiian
+    Haw = "haw" "Hawaiian",
+
+    /// Hebrew
+    He = "he" "Hebrew",
+
+    /// Hindi
+    Hi = "hi" "Hindi",
+
+    /// Hiligaynon
+    Hil = "hil" "Hiligaynon",
+
+    /// Hittite
+    Hit = "hit" "Hittite",
+
+    /// Hiri Motu
+    Ho = "ho" "Hiri Motu",
+
+    /// Hopi
+    Hop = "hop" "Hopi",
+
+    /// Hunsrik
+    Hrx = "hrx" "Hunsrik",
+
+    /// Upper Sorbian
+    Hsb = "hsb" "Upper Sorbian",
+
+    /// Haitian Creole
+    Ht = "ht" "Haitian Creole",
+
+    /// Hungarian
+    Hu = "hu" "Hungarian",
+
+    /// Armenian
+    Hy = "hy" "Armenian",
+
+    /// Proto-Armenian
+    HyxPro = "hyx-pro" "Proto-Armenian",
+
+    /// Herero
+    Hz = "hz" "Herero",
+
+    /// Interlingua
+    Ia = "ia" "Interlingua",
+
+    /// Indonesian
+    Id = "id" "Indonesian",
+
+    /// Interlingue
+    Ie = "ie" "Interlingue",
+
+    /// Igbo
+    Ig = "ig" "Igbo",
+
+    /// Proto-Indo-Iranian
+    IirPro = "iir-pro" "Proto-Indo-Iranian",
+
+    /// Inupiaq
+    Ik = "ik" "Inupiaq",
+
+    /// Ilocano
+    Ilo = "ilo" "Ilocano",
+
+    /// Proto-Indo-Aryan
+    IncPro = "inc-pro" "Proto-Indo-Aryan",
+
+    /// Proto-Balto-Slavic
+    IneBslPro = "ine-bsl-pro" "Proto-Balto-Slavic",
+
+    /// Proto-Indo-European
+    InePro = "ine-pro" "Proto-Indo-European",
+
+    /// Ingush
+    Inh = "inh" "Ingush",
+
+    /// Ido
+    Io = "io" "Ido",
+
+    /// Proto-Iranian
+    IraPro = "ira-pro" "Proto-Iranian",
+
+    /// Icelandic
+    Is = "is" "Icelandic",
+
+    /// Istriot
+    Ist = "ist" "Istriot",
+
+    /// Italian
+    It = "it" "Italian",
+
+    /// Proto-Italic
+    ItcPro = "itc-pro" "Proto-Italic",
+
+    /// Inuktitut
+    Iu = "iu" "Inuktitut",
+
+    /// Ingrian
+    Izh = "izh" "Ingrian",
+
+    /// Japanese
+    Ja = "ja" "Japanese",
+
+    /// Jamaican Creole
+    Jam = "jam" "Jamaican Creole",
+
+    /// Lojban
+    Jbo = "jbo" "Lojban",
+
+    /// Javanese
+    Jv = "jv" "Javanese",
+
+    /// Georgian
+    Ka = "ka" "Georgian",
+
+    /// Karakalpak
+    Kaa = "kaa" "Karakalpak",
+
+    /// Kabyle
+    Kab = "kab" "Kabyle",
+
+    /// Kabardian
+    Kbd = "kbd" "Kabardian",
+
+    /// Khanty
+    Kca = "kca" "Khanty",
+
+    /// Ket
+    Ket = "ket" "Ket",
+
+    /// Kongo
+    Kg = "kg" "Kongo",
+
+    /// Khotanese
+    Kho = "kho" "Khotanese",
+
+    /// Kikuyu
+    Ki = "ki" "Kikuyu",
+
+    /// Kwanyama
+    Kj = "kj" "Kwanyama",
+
+    /// Khakas
+    Kjh = "kjh" "Khakas",
+
+    /// Kazakh
+    Kk = "kk" "Kazakh",
+
+    /// Greenlandic
+    Kl = "kl" "Greenlandic",
+
+    /// Khmer
+    Km = "km" "Khmer",
+
+    /// Northern Kurdish
+    Kmr = "kmr" "Northern Kurdish",
+
+    /// Kannada
+    Kn = "kn" "Kannada",
+
+    /// Korean
+    Ko = "ko" "Korean",
+
+    /// Komi-Permyak
+    Koi = "koi" "Komi-Permyak",
+
+    /// Konkani
+    Kok = "kok" "Konkani",
+
+    /// Kanuri
+    Kr = "kr" "Kanuri",
+
+    /// Karachay-Balkar
+    Krc = "krc" "Karachay-Balkar",
+
+    /// Karelian
+    Krl = "krl" "Karelian",
+
+    /// Kashmiri
+    Ks = "ks" "Kashmiri",
+
+    /// Kumyk
+    Kum = "kum" "Kumyk",
+
+    /// Komi-Zyrian
+    Kv = "kv" "Komi-Zyrian",
+
+    /// Cornish
+    Kw = "kw" "Cornish",
+
+    /// Kyrgyz
+    Ky = "ky" "Kyrgyz",
+
+    /// Latin
+    La = "la" "Latin",
+
+    /// Ladino
+    Lad = "lad" "Ladino",
+
+    /// Luxembourgish
+    Lb = "lb" "Luxembourgish",
+
+    /// Lak
+    Lbe = "lbe" "Lak",
+
+    /// Lezgi
+    Lez = "lez" "Lezgi",
+
+    /// Luganda
+    Lg = "lg" "Luganda",
+
+    /// Limburgish
+    Li = "li" "Limburgish",
+
+    /// Ligurian
+    Lij = "lij" "Ligurian",
+
+    /// Livonian
+    Liv = "liv" "Livonian",
+
+    /// Lakota
+    Lkt = "lkt" "Lakota",
+
+    /// Ladin
+    Lld = "lld" "Ladin",
+
+    /// Lombard
+    Lmo = "lmo" "Lombard",
+
+    /// Lingala
+    Ln = "ln" "Lingala",
+
+    /// Lao
+    Lo = "lo" "Lao",
+
+    /// Lithuanian
+    Lt = "lt" "Lithuanian",
+
+    /// Latgalian
+    Ltg = "ltg" "Latgalian",
+
+    /// Luba-Katanga
+    Lu = "lu" "Luba-Katanga",
+
+    /// Latvian
+    Lv = "lv" "Latvian",
+
+    /// Laz
+    Lzz = "lzz" "Laz",
+
+    /// Madurese
+    Mad = "mad" "Madurese",
+
+    /// Maithili
+    Mai = "mai" "Maithili",
+
+    /// Proto-Austronesian
+    MapPro = "map-pro" "Proto-Austronesian",
+
+    /// Moksha
+    Mdf = "mdf" "Moksha",
+
+    /// Malagasy
+    Mg = "mg" "Malagasy",
+
+    /// Middle Irish
+    Mga = "mga" "Middle Irish",
+
+    /// Marshallese
+    Mh = "mh" "Marshallese",
+
+    /// Eastern Mari
+    Mhr = "mhr" "Eastern Mari",
+
+    /// Maori
+    Mi = "mi" "Maori",
+
+    /// Minangkabau
+    Min = "min" "Minangkabau",
+
+    /// Macedonian
+    Mk = "mk" "Macedonian",
+
+    /// Malayalam
+    Ml = "ml" "Malayalam",
+
+    /// Mongolian
+    Mn = "mn" "Mongolian",
+
+    /// Manchu
+    Mnc = "mnc" "Manchu",
+
+    /// Mansi
+    Mns = "mns" "Mansi",
+
+    /// Mohawk
+    Moh = "moh" "Mohawk",
+
+    /// Marathi
+    Mr = "mr" "Marathi",
+
+    /// Western Mari
+    Mrj = "mrj" "Western Mari",
+
+    /// Malay
+    Ms = "ms" "Malay",
+
+    /// Maltese
+    Mt = "mt" "Maltese",
+
+    /// Translingual
+    Mul = "mul" "Translingual",
+
+    /// Creek
+    Mus = "mus" "Creek",
+
+    /// Mirandese
+    Mwl = "mwl" "Mirandese",
+
+    /// Burmese
+    My = "my" "Burmese",
+
+    /// Erzya
+    Myv = "myv" "Erzya",
+
+    /// Nauruan
+    Na = "na" "Nauruan",
+
+    /// Neapolitan
+    Nap = "nap" "Neapolitan",
+
+    /// Norwegian Bokmål
+    Nb = "nb" "Norwegian Bokmål",
+
+    /// Classical Nahuatl
+    Nci = "nci" "Classical Nahuatl",
+
+    /// Northern Ndebele
+    Nd = "nd" "Northern Ndebele",
+
+    /// Low German
+    Nds = "nds" "Low German",
+
+    /// Nepali
+    Ne = "ne" "Nepali",
+
+    /// Ndonga
+    Ng = "ng" "Ndonga",
+
+    /// Dutch
+    Nl = "nl" "Dutch",
+
+    /// Norwegian Nynorsk
+    Nn = "nn" "Norwegian Nynorsk",
+
+    /// Norwegian
+    No = "no" "Norwegian",
+
+    /// Nogai
+    Nog = "nog" "Nogai",
+
+    /// Old Norse
+    Non = "non" "Old Norse",
+
+    /// Novial
+    Nov = "nov" "Novial",
+
+    /// Southern Ndebele
+    Nr = "nr" "Southern Ndebele",
+
+    /// Norman
+    Nrf = "nrf" "Norman",
+
+    /// Norn
+    Nrn = "nrn" "Norn",
+
+    /// Navajo
+    Nv = "nv" "Navajo",
+
+    /// Chichewa
+    Ny = "ny" "Chichewa",
+
+    /// Occitan
+    Oc = "oc" "Occitan",
+
+    /// Old Dutch
+    Odt = "odt" "Old Dutch",
+
+    /// Old Frisian
+    Ofs = "ofs" "Old Frisian",
+
+    /// Old Georgian
+    Oge = "oge" "Old Georgian",
+
+    /// Ojibwe
+    Oj = "oj" "Ojibwe",
+
+    /// Old Japanese
+    Ojp = "ojp" "Old Japanese",
+
+    /// Middle Korean
+    Okm = "okm" "Middle Korean",
+
+    /// Oromo
+    Om = "om" "Oromo",
+
+    /// Odia
+    Or = "or" "Odia",
+
+    /// Old East Slavic
+    Orv = "orv" "Old East Slavic",
+
+    /// Ossetian
+    Os = "os" "Ossetian",
+
+    /// Oscan
+    Osc = "osc" "Oscan",
+
+    /// Old Spanish
+    Osp = "osp" "Old Spanish",
+
+    /// Old Saxon
+    Osx = "osx" "Old Saxon",
+
+    /// Ottoman Turkish
+    Ota = "ota" "Ottoman Turkish",
+
+    /// Old Turkic
+    Otk = "otk" "Old Turkic",
+
+    /// Old Tamil
+    Oty = "oty" "Old Tamil",
+
+    /// Elfdalian
+    Ovd = "ovd" "Elfdalian",
+
+    /// Old Welsh
+    Owl = "owl" "Old Welsh",
+
+    /// Punjabi
+    Pa = "pa" "Punjabi",
+
+    /// Pangasinan
+    Pag = "pag" "Pangasinan",
+
+    /// Middle Persian
+    Pal = "pal" "Middle Persian",
+
+    /// Kapampangan
+    Pam = "pam" "Kapampangan",
+
+    /// Papiamentu
+    Pap = "pap" "Papiamentu",
+
+    /// Palauan
+    Pau = "pau" "Palauan",
+
+    /// Picard
+    Pcd = "pcd" "Picard",
+
+    /// Pennsylvania German
+    Pdc = "pdc" "Pennsylvania German",
+
+    /// Plautdietsch
+    Pdt = "pdt" "Plautdietsch",
+
+    /// Old Persian
+    Peo = "peo" "Old Persian",
+
+    /// Phoenician
+    Phn = "phn" "Phoenician",
+
+    /// Pali
+    Pi = "pi" "Pali",
+
+    /// Polish
+    Pl = "pl" "Polish",
+
+    /// Piedmontese
+    Pms = "pms" "Piedmontese",
+
+    /// Proto-Malayo-Polynesian
+    PozPro = "poz-pro" "Proto-Malayo-Polynesian",
+
+    /// Old Prussian
+    Prg = "prg" "Old Prussian",
+
+    /// Old Occitan
+    Pro = "pro" "Old Occitan",
+
+    /// Pashto
+    Ps = "ps" "Pashto",
+
+    /// Portuguese
+    Pt = "pt" "Portuguese",
+
+    /// Quechua
+    Qu = "qu" "Quechua",
+
+    /// Rarotongan
+    Rar = "rar" "Rarotongan",
+
+    /// Romagnol
+    Rgn = "rgn" "Romagnol",
+
+    /// Tarifit
+    Rif = "rif" "Tarifit",
+
+    /// Romansch
+    Rm = "rm" "Romansch",
+
+    /// Romanian
+    Ro = "ro" "Romanian",
+
+    /// Old Galician-Portuguese
+    RoaOpt = "roa-opt" "Old Galician-Portuguese",
+
+    /// Romani
+    Rom = "rom" "Romani",
+
+    /// Russian
+    Ru = "ru" "Russian",
+
+    /// Rusyn
+    Rue = "rue" "Rusyn",
+
+    /// Aromanian
+    Rup = "rup" "Aromanian",
+
+    /// Megleno-Romanian
+    Ruq = "ruq" "Megleno-Romanian",
+
+    /// Sanskrit
+    Sa = "sa" "Sanskrit",
+
+    /// Yakut
+    Sah = "sah" "Yakut",
+
+    /// Santali
+    Sat = "sat" "Santali",
+
+    /// Sardinian
+    Sc = "sc" "Sardinian",
+
+    /// Sicilian
+    Scn = "scn" "Sicilian",
+
+    /// Scots
+    Sco = "sco" "Scots",
+
+    /// Sindhi
+    Sd = "sd" "Sindhi",
+
+    /// Northern Sami
+    Se = "se" "Northern Sami",
+
+    /// Proto-Semitic
+    SemPro = "sem-pro" "Proto-Semitic",
+
+    /// Sango
+    Sg = "sg" "Sango",
+
+    /// Old Irish
+    Sga = "sga" "Old Irish",
+
+    /// Samogitian
+    Sgs = "sgs" "Samogitian",
+
+    /// Serbo-Croatian
+    Sh = "sh" "Serbo-Croatian",
+
+    /// Tashelhit
+    Shi = "shi" "Tashelhit",
+
+    /// Sinhalese
+    Si = "si" "Sinhalese",
+
+    /// Proto-Sino-Tibetan
+    SitPro = "sit-pro" "Proto-Sino-Tibetan",
+
+    /// Slovak
+    Sk = "sk" "Slovak",
+
+    /// Slovene
+    Sl = "sl" "Slovene",
+
+    /// Proto-Slavic
+    SlaPro = "sla-pro" "Proto-Slavic",
+
+    /// Samoan
+    Sm = "sm" "Samoan",
+
+    /// Southern Sami
+    Sma = "sma" "Southern Sami",
+
+    /// Proto-Samic
+    SmiPro = "smi-pro" "Proto-Samic",
+
+    /// Lule Sami
+    Smj = "smj" "Lule Sami",
+
+    /// Inari Sami
+    Smn = "smn" "Inari Sami",
+
+    /// Skolt Sami
+    Sms = "sms" "Skolt Sami",
+
+    /// Shona
+    Sn = "sn" "Shona",
+
+    /// Somali
+    So = "so" "Somali",
+
+    /// Sogdian
+    Sog = "sog" "Sogdian",
+
+    /// Albanian
+    Sq = "sq" "Albanian",
+
+    /// Sranan Tongo
+    Srn = "srn" "Sranan Tongo",
+
+    /// Swazi
+    Ss = "ss" "Swazi",
+
+    /// Sotho
+    St = "st" "Sotho",
+
+    /// Saterland Frisian
+    Stq = "stq" "Saterland Frisian",
+
+    /// Sundanese
+    Su = "su" "Sundanese",
+
+    /// Sumerian
+    Sux = "sux" "Sumerian",
+
+    /// Swedish
+    Sv = "sv" "Swedish",
+
+    /// Svan
+    Sva = "sva" "Svan",
+
+    /// Swahili
+    Sw = "sw" "Swahili",
+
+    /// Classical Syriac
+    Syc = "syc" "Classical Syriac",
+
+    /// Silesian
+    Szl = "szl" "Silesian",
+
+    /// Tamil
+    Ta = "ta" "Tamil",
+
+    /// Tabasaran
+    Tab = "tab" "Tabasaran",
+
+    /// Tulu
+    Tcy = "tcy" "Tulu",
+
+    /// Telugu
+    Te = "te" "Telugu",
+
+    /// Tetum
+    Tet = "tet" "Tetum",
+
+    /// Tajik
+    Tg = "tg" "Tajik",
+
+    /// Thai
+    Th = "th" "Thai",
+
+    /// Tigrinya
+    Ti = "ti" "Tigrinya",
+
+    /// Tigre
+    Tig = "tig" "Tigre",
+
+    /// Turkmen
+    Tk = "tk" "Turkmen",
+
+    /// Tagalog
+    Tl = "tl" "Tagalog",
+
+    /// Tswana
+    Tn = "tn" "Tswana",
+
+    /// Tongan
+    To = "to" "Tongan",
+
+    /// Toki Pona
+    Tok = "tok" "Toki Pona",
+
+    /// Tok Pisin
+    Tpi = "tpi" "Tok Pisin",
+
+    /// Turkish
+    Tr = "tr" "Turkish",
+
+    /// Proto-Turkic
+    TrkPro = "trk-pro" "Proto-Turkic",
+
+    /// Tsonga
+    Ts = "ts" "Tsonga",
+
+    /// Tatar
+    Tt = "tt" "Tatar",
+
+    /// Tocharian B
+    Txb = "txb" "Tocharian B",
+
+    /// Tahitian
+    Ty = "ty" "Tahitian",
+
+    /// Tuvan
+    Tyv = "tyv" "Tuvan",
+
+    /// Central Atlas Tamazight
+    Tzm = "tzm" "Central Atlas Tamazight",
+
+    /// Ubykh
+    Uby = "uby" "Ubykh",
+
+    /// Udi
+    Udi = "udi" "Udi",
+
+    /// Udmurt
+    Udm = "udm" "Udmurt",
+
+    /// Uyghur
+    Ug = "ug" "Uyghur",
+
+    /// Ugaritic
+    Uga = "uga" "Ugaritic",
+
+    /// Ukrainian
+    Uk = "uk" "Ukrainian",
+
+    /// Urdu
+    Ur = "ur" "Urdu",
+
+    /// Proto-Finnic
+    UrjFinPro = "urj-fin-pro" "Proto-Finnic",
+
+    /// Proto-Uralic
+    UrjPro = "urj-pro" "Proto-Uralic",
+
+    /// Uzbek
+    Uz = "uz" "Uzbek",
+
+    /// Venda
+    Ve = "ve" "Venda",
+
+    /// Venetan
+    Vec = "vec" "Venetan",
+
+    /// Veps
+    Vep = "vep" "Veps",
+
+    /// Vietnamese
+    Vi = "vi" "Vietnamese",
+
+    /// West Flemish
+    Vls = "vls" "West Flemish",
+
+    /// Volapük
+    Vo = "vo" "Volapük",
+
+    /// Votic
+    Vot = "vot" "Votic",
+
+    /// Võro
+    Vro = "vro" "Võro",
+
+    /// Walloon
+    Wa = "wa" "Walloon",
+
+    /// Waray-Waray
+    War = "war" "Waray-Waray",
+
+    /// Middle Welsh
+    Wlm = "wlm" "Middle Welsh",
+
+    /// Wolof
+    Wo = "wo" "Wolof",
+
+    /// Kalmyk
+    Xal = "xal" "Kalmyk",
+
+    /// Middle Breton
+    Xbm = "xbm" "Middle Breton",
+
+    /// Old Armenian
+    Xcl = "xcl" "Old Armenian",
+
+    /// Xhosa
+    Xh = "xh" "Xhosa",
+
+    /// Hurrian
+    Xhu = "xhu" "Hurrian",
+
+    /// Lycian
+    Xlc = "xlc" "Lycian",
+
+    /// Lydian
+    Xld = "xld" "Lydian",
+
+    /// Luwian
+    Xlu = "xlu" "Luwian",
+
+    /// Mingrelian
+    Xmf = "xmf" "Mingrelian",
+
+    /// Anglo-Norman
+    Xno = "xno" "Anglo-Norman",
+
+    /// Parthian
+    Xpr = "xpr" "Parthian",
+
+    /// Gaulish
+    Xtg = "xtg" "Gaulish",
+
+    /// Tocharian A
+    Xto = "xto" "Tocharian A",
+
+    /// Umbrian
+    Xum = "xum" "Umbrian",
+
+    /// Yiddish
+    Yi = "yi" "Yiddish",
+
+    /// Yoruba
+    Yo = "yo" "Yoruba",
+
+    /// Yola
+    Yol = "yol" "Yola",
+
+    /// Tundra Nenets
+    Yrk = "yrk" "Tundra Nenets",
+
+    /// Yucatec Maya
+    Yua = "yua" "Yucatec Maya",
+
+    /// Zhuang
+    Za = "za" "Zhuang",
+
+    /// Zealandic
+    Zea = "zea" "Zealandic",
+
+    /// Chinese
+    Zh = "zh" "Chinese",
+
+    /// Old Czech
+    ZlwOcs = "zlw-ocs" "Old Czech",
+
+    /// Old Polish
+    ZlwOpl = "zlw-opl" "Old Polish",
+
+    /// Zulu
+    Zu = "zu" "Zulu",
+
+    /// Zazaki
+    Zza = "zza" "Zazaki",
+}
+
+/// Dictionary entry for a single language.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LanguageEntry<'a> {
+    /// Whether the subsection `Anagrams` is present in the section.
+    pub anagrams: bool,
+
+    /// Categories the entry belongs to, from templates anywhere in the section.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub categories: Vec<Category<'a>>,
+
+    /// Entries for each numbered etymology and each numbered pronunciation for this language, in the order of the sections.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub etymology_entries: Vec<EtymologyEntry<'a>>,
+
+    /// Entry for the etymology that is directly in the language entry.
+    pub etymology_entry: EtymologyEntry<'a>,
+
+    /// Details about the template for displaying the written forms of the entry, such as [`zh-forms`](https://en.wiktionary.org/wiki/Template:zh-forms) for Chinese, if any.
+    ///
+    /// Such a template is recognized if its name is the language code of the entry followed by `-forms`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forms: Option<Template<'a>>,
+
+    /// Whether the subsection `Further reading` is present in the section.
+    pub further_reading: bool,
+
+    /// Content of the subsection `Glyph origin` within the section, if any, describing the origin of the shape of a character.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub glyph_origin: Option<Vec<Flowing<'a>>>,
+
+    /// The language of the entry.
+    pub language: Language,
+
+    /// The language code of the registered language, if the language is [`Other`](enum.Language.html#variant.Other) and the heading is the name of a language in [`ParseOptions::registered_languages`](struct.ParseOptions.html#structfield.registered_languages).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<Cow<'a, str>>,
+
+    /// The name of the language from the heading, if the language is [`Other`](enum.Language.html#variant.Other).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_name: Option<Cow<'a, str>>,
+
+    /// Links to resources outside the entry, from the lists in the subsections `Further reading` and `References`, in the order they appear.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub resources: Vec<Resource<'a>>,
+}
+
+/// Grammatical mood of a verb form, or the kind of non-finite verb form.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Mood {
+    /// Conditional
+    Conditional,
+
+    /// Gerund
+    Gerund,
+
+    /// Imperative
+    Imperative,
+
+    /// Indicative
+    Indicative,
+
+    /// Infinitive
+    Infinitive,
+
+    /// Participle
+    Participle,
+
+    /// Subjunctive
+    Subjunctive,
+
+    /// Supine, a non-finite form used to form the perfect tenses in Swedish.
+    Supine,
+}
+
+/// Term or part of a term that a word is formed from in a word formation.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Morpheme<'a> {
+    /// Gloss translating or explaining the part, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gloss: Option<Cow<'a, str>>,
+
+    /// The language code of the part.
+    ///
+    /// This is the language of the entry unless another language is given for the part.
+    pub language: Cow<'a, str>,
+
+    /// The part, such as `un-`, `happy` or `-ness`.
+    ///
+    /// Affixes have hyphens marking the side they attach to, even if the template is written without them.
+    pub term: Cow<'a, str>,
+}
+
+/// Grammatical number.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Number {
+    /// Plural
+    Plural,
+
+    /// Singular
+    Singular,
+}
+
+/// Output of parsing a page.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Output<'a> {
+    /// How much of each language section was parsed.
+    #[serde(skip)]
+    coverage: Vec<Coverage>,
+
+    /// The dictionary entries by language.
+    ///
+    /// Parsed from the sections with the name of the language as title.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub language_entries: Vec<LanguageEntry<'a>>,
+
+    /// The headings of the language sections that were skipped because the language is not recognized and [`ParseOptions::parse_unknown_languages`](struct.ParseOptions.html#structfield.parse_unknown_languages) is disabled, and the byte range in the wiki text of each of those sections, including the heading.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped_languages: Vec<(Cow<'a, str>, Range<usize>)>,
+
+    /// Approximate size of the output.
+    #[serde(skip)]
+    stats: Stats,
+
+    /// Warnings from the parser telling that something is not well-formed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<Warning>,
+
+    /// The word the article describes, which is the page title given in [`ParseOptions::title`](struct.ParseOptions.html#structfield.title), if any.
+    ///
+    /// The word is not inferred from the wiki text, so without a title this is `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub word: Option<String>,
+}
+
+/// Options for parsing a page.
+///
+/// The default options are the ones used by [`parse`](fn.parse.html). The options can be set either through the fields or by chaining the methods of the same names starting from the default options.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate parse_wiktionary_en;
+/// let options = parse_wiktionary_en::ParseOptions::default()
+///     .languages(vec![parse_wiktionary_en::Language::En])
+///     .skip_section("Pronunciation")
+///     .title("cat");
+/// let wiki_text = "==English==\n===Pronunciation===\n* {{IPA|en|/kæt/}}\n\n===Noun===\n{{en-noun}}\n\n# A [[feline]].";
+/// let parsed_wiki_text = parse_wiktionary_en::create_configuration().parse(wiki_text);
+/// let output =
+///     parse_wiktionary_en::parse_with_options(wiki_text, &parsed_wiki_text.nodes, &options);
+/// let etymology_entry = &output.language_entries[0].etymology_entry;
+/// assert!(etymology_entry.pronunciation.is_none());
+/// assert_eq!(etymology_entry.pos_entries[0].plurals, ["cats"]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ParseOptions {
+    /// Whether to leave out the warnings from the output.
+    ///
+    /// When this is `true`, [`Output::warnings`](struct.Output.html#structfield.warnings) is always empty, including when parsing stopped because of [`OutputTooLarge`](enum.WarningMessage.html#variant.OutputTooLarge). The warnings are still used to compute [`Output::coverage`](struct.Output.html#method.coverage).
+    pub discard_warnings: bool,
+
+    /// The languages to parse, if limited.
+    ///
+    /// Sections of other languages are skipped without being parsed and without causing any warnings. Sections of recognized languages that are skipped this way are not listed in [`Output::skipped_languages`](struct.Output.html#structfield.skipped_languages), while sections of unrecognized languages are listed there as usual. Sections of unrecognized languages are instead parsed if the list contains [`Other`](enum.Language.html#variant.Other) and [`parse_unknown_languages`](#structfield.parse_unknown_languages) is enabled.
+    pub languages: Option<Vec<Language>>,
+
+    /// The maximum depth of nested definitions to keep, if limited.
+    ///
+    /// With the value `0`, only the top level definitions are kept, with the value `1` also the definitions nested directly in them, and so on. Deeper definitions are left out after parsing.
+    pub max_definition_depth: Option<usize>,
+
+    /// The maximum number of elements the output may contain, if limited.
+    ///
+    /// Elements are counted as described for [`Stats::elements`](struct.Stats.html#structfield.elements). The limit is checked while parsing, after each part of speech, and again after each language section. When the limit is exceeded, parsing stops in the language section that made the output exceed the limit, that section is left out of the output, and the warning [`OutputTooLarge`](enum.WarningMessage.html#variant.OutputTooLarge) is added.
+    pub max_elements: Option<usize>,
+
+    /// The maximum number of bytes of text the output may contain, if limited.
+    ///
+    /// Bytes are counted as described for [`Stats::text_bytes`](struct.Stats.html#structfield.text_bytes). When the limit is exceeded, the same happens as when exceeding [`max_elements`](#structfield.max_elements).
+    pub max_text_bytes: Option<usize>,
+
+    /// Whether to merge a language section into the previous section with the same language, if any.
+    ///
+    /// Two sections with the same language is an editing error and always causes the warning [`Duplicate`](enum.WarningMessage.html#variant.Duplicate). When this is `false`, each section produces its own entry in [`Output::language_entries`](struct.Output.html#structfield.language_entries). When this is `true`, the parts of speech and numbered etymologies of the later section are appended to the entry of the earlier section, and the flags of both sections are combined.
+    pub merge_duplicate_languages: bool,
+
+    /// Whether to parse sections for languages that are not recognized.
+    ///
+    /// When this is `false`, such sections are left out and listed in [`Output::skipped_languages`](struct.Output.html#structfield.skipped_languages). When this is `true`, they are parsed like any other section, with the language [`Other`](enum.Language.html#variant.Other). Templates with a language parameter don't match the language of such a section, so the language specific parts are parsed less completely than for recognized languages.
+    pub parse_unknown_languages: bool,
+
+    /// Whether to parse sections with unrecognized headings as parts of speech.
+    ///
+    /// When this is `true`, a section within a language or etymology section that has an unrecognized heading and contains a list of definitions is parsed like any other part of speech section, with the part of speech [`Other`](enum.Pos.html#variant.Other). When this is `false`, such sections are left out, causing the warning [`Unrecognized`](enum.WarningMessage.html#variant.Unrecognized).
+    pub parse_unknown_parts_of_speech: bool,
+
+    /// Whether the page is in the namespace `Reconstruction`, where the entries are reconstructed terms of proto-languages.
+    ///
+    /// When this is `true`, the heading `Root` is parsed as the part of speech [`Root`](enum.Pos.html#variant.Root) of a reconstructed root, rather than as the consonantal root of a Semitic entry in [`EtymologyEntry::root`](struct.EtymologyEntry.html#structfield.root).
+    pub reconstruction: bool,
+
+    /// Languages to recognize in addition to the variants of [`Language`](enum.Language.html).
+    ///
+    /// This allows parsing sections of languages that are not yet supported by this version of the crate. A section whose heading is the name of a registered language is parsed with the language [`Other`](enum.Language.html#variant.Other) and the name from the heading in [`LanguageEntry::language_name`](struct.LanguageEntry.html#structfield.language_name), like when [`parse_unknown_languages`](#structfield.parse_unknown_languages) is enabled, except that templates are matched against the registered language code and head templates. A heading that is the name of a variant of `Language` is never matched against the registered languages.
+    pub registered_languages: Vec<RegisteredLanguage>,
+
+    /// Headings of sections to skip without parsing them, such as `Pronunciation` or `Usage notes`.
+    ///
+    /// A section with any of these headings is skipped together with its subsections wherever it occurs, and its content is treated as supplementary, as for sections like `Synonyms` that are only recorded as present. The parts of the output parsed from the section are left empty.
+    pub skipped_sections: Vec<String>,
+
+    /// Whether to only accept head templates that are known to this crate.
+    ///
+    /// When this is `false`, any template named by the language code followed by `-`, such as `fi-noun`, is accepted as a head template, except for templates known to have other purposes. When this is `true`, only the head templates known for each language are accepted, and other templates cause the warning [`Unrecognized`](enum.WarningMessage.html#variant.Unrecognized).
+    pub strict_head_templates: bool,
+
+    /// The title of the page, if known.
+    ///
+    /// This is used to compute inflected forms that head templates form from the page name, such as the plural `cats` from [`en-noun`](https://en.wiktionary.org/wiki/Template:en-noun) on the page `cat`, and the default [`PosEntry::headword`](struct.PosEntry.html#structfield.headword). It's included in the output as [`Output::word`](struct.Output.html#structfield.word). Conjugation and declension templates whose parameters don't match the title cause the warning [`ValueConflicting`](enum.WarningMessage.html#variant.ValueConflicting).
+    pub title: Option<String>,
+}
+
+/// Grammatical person.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Person {
+    /// First person
+    First,
+
+    /// Second person
+    Second,
+
+    /// Third person
+    Third,
+}
+
+/// Part of speech.
+///
+/// More parts of speech may be added in later versions. When deserializing, an unknown part of speech is deserialized as [`Other`](#variant.Other).
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "snake_case")]
+pub enum Pos {
+    /// Abbreviation, from the heading `Abbreviation` used on older pages.
+    ///
+    /// Current pages instead have the abbreviation under the heading of its part of speech.
+    Abbreviation,
+
+    /// Acronym, from the heading `Acronym` used on older pages.
+    ///
+    /// Current pages instead have the acronym under the heading of its part of speech.
+    Acronym,
+
+    /// Adjective
+    Adjective,
+
+    /// Adverb
+    Adverb,
+
+    /// Article
+    Article,
+
+    /// Circumfix, an affix with one part before and one part after the stem, such as the German `ge-` … `-t`.
+    Circumfix,
+
+    /// Conjunction
+    Conjunction,
+
+    /// Definitions without a specific part of speech, from the heading `Definitions` used for Chinese characters.
+    Definitions,
+
+    /// Diacritical mark
+    DiacriticalMark,
+
+    /// Hanja, a Chinese character used in Korean, from the heading `Hanja`.
+    Hanja,
+
+    /// Infix, an affix inserted into the stem, such as the Tagalog `-um-`.
+    Infix,
+
+    /// Initialism, from the heading `Initialism` used on older pages.
+    ///
+    /// Current pages instead have the initialism under the heading of its part of speech.
+    Initialism,
+
+    /// Interjection
+    Interjection,
+
+    /// Interfix, an affix joining two stems, such as the `-o-` in `speedometer`.
+    Interfix,
+
+    /// Kanji, a Chinese character used in Japanese, from the heading `Kanji`.
+    Kanji,
+
+    /// Letter of an alphabet or other script, such as `a`.
+    Letter,
+
+    /// Noun
+    Noun,
+
+    /// Numeral
+    Numeral,
+
+    /// Participle
+    Participle,
+
+    /// Particle
+    Particle,
+
+    /// Phrase
+    Phrase,
+
+    /// Prefix, such as `un-`.
+    Prefix,
+
+    /// Preposition
+    Preposition,
+
+    /// Prepositional phrase
+    PrepositionalPhrase,
+
+    /// Pronoun
+    Pronoun,
+
+    /// Proverb
+    Proverb,
+
+    /// Punctuation mark
+    PunctuationMark,
+
+    /// Proper noun
+    ProperNoun,
+
+    /// Romanization, such as the rōmaji spelling of a Japanese term, from the heading `Romanization`.
+    Romanization,
+
+    /// Reconstructed root of a proto-language, from the heading `Root` on a page in the namespace `Reconstruction`.
+    Root,
+
+    /// Suffix, such as `-ness`.
+    Suffix,
+
+    /// Symbol, such as `%`.
+    Symbol,
+
+    /// Verb
+    Verb,
+
+    /// Part of speech not known to this version of the library.
+    ///
+    /// This is produced by the parser for a section with an unrecognized heading when [`ParseOptions::parse_unknown_parts_of_speech`](struct.ParseOptions.html#structfield.parse_unknown_parts_of_speech) is enabled, in which case the heading is in [`PosEntry::pos_name`](struct.PosEntry.html#structfield.pos_name). This is also the result of deserializing output that has a part of speech added in a later version.
+    #[serde(other)]
+    Other,
+}
+
+/// The entry for a part of speech within the entry for a language.
+///
+/// Parsed from the section with the part of speech as its heading.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PosEntry<'a> {
+    /// Terms that the entry is an alternative spelling of, from a template such as [`ja-see`](https://en.wiktionary.org/wiki/Template:ja-see) used in Japanese entries spelled in kana, or [`sh-see`](https://en.wiktionary.org/wiki/Template:sh-see) used in Serbo-Croatian entries spelled in Cyrillic.
+    ///
+    /// Such a template is recognized if its name is the language code of the entry followed by `-see`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alternative_form_of: Vec<Cow<'a, str>>,
+
+    /// Whether the subsection `Antonyms` is present in the section.
+    pub antonyms: bool,
+
+    /// Whether the entry is comparable, interpreted from the parameters of the head template [`en-adj`](https://en.wiktionary.org/wiki/Template:en-adj) or [`en-adv`](https://en.wiktionary.org/wiki/Template:en-adv).
+    ///
+    /// For an adjective or adverb with another head template, this is `Some(false)` if all definitions have the label `not comparable`. Otherwise it's `None`, as well as when the comparability is marked as unknown with the parameter `?`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comparable: Option<bool>,
+
+    /// Comparative forms of the entry, such as `bigger` or `more beautiful`, interpreted from the parameters of the head template [`en-adj`](https://en.wiktionary.org/wiki/Template:en-adj) or [`en-adv`](https://en.wiktionary.org/wiki/Template:en-adv).
+    ///
+    /// Forms made from the page name, such as by the parameters `er` and `more` or by omitting the comparative, are only included if the page title is given in [`ParseOptions::title`](struct.ParseOptions.html#structfield.title).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub comparatives: Vec<Cow<'a, str>>,
+
+    /// Forms of the conjugation of the entry, interpreted from the parameters of the conjugation templates in the inflection, such as [`es-conj-ar`](https://en.wiktionary.org/wiki/Template:es-conj-ar).
+    ///
+    /// Only templates for regular conjugation classes are interpreted: [`cs-conj-dělat`](https://en.wiktionary.org/wiki/Template:cs-conj-dělat), [`cs-conj-prosit`](https://en.wiktionary.org/wiki/Template:cs-conj-prosit), [`de-conj`](https://en.wiktionary.org/wiki/Template:de-conj) without parameters, [`de-conj-weak`](https://en.wiktionary.org/wiki/Template:de-conj-weak), [`es-conj-ar`](https://en.wiktionary.org/wiki/Template:es-conj-ar), [`es-conj-er`](https://en.wiktionary.org/wiki/Template:es-conj-er), [`es-conj-ir`](https://en.wiktionary.org/wiki/Template:es-conj-ir) and [`sv-conj-wk`](https://en.wiktionary.org/wiki/Template:sv-conj-wk). When the template doesn't give the stem, it's formed from the page title given in [`ParseOptions::title`](struct.ParseOptions.html#structfield.title).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conjugation: Vec<ConjugatedForm<'a>>,
+
+    /// Whether the entry is countable, interpreted from the parameters of the head template [`en-noun`](https://en.wiktionary.org/wiki/Template:en-noun).
+    ///
+    /// This is `None` for other head templates, and when the countability is marked as unknown with the parameter `?`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub countability: Option<Countability>,
+
+    /// Forms of the declension of the entry, interpreted from the parameters of the declension templates in the inflection, such as [`de-decl-noun-m`](https://en.wiktionary.org/wiki/Template:de-decl-noun-m).
+    ///
+    /// The templates interpreted are [`cs-decl-noun`](https://en.wiktionary.org/wiki/Template:cs-decl-noun), [`de-decl-noun-f`](https://en.wiktionary.org/wiki/Template:de-decl-noun-f), [`de-decl-noun-m`](https://en.wiktionary.org/wiki/Template:de-decl-noun-m), [`de-decl-noun-n`](https://en.wiktionary.org/wiki/Template:de-decl-noun-n) and [`sv-infl-noun`](https://en.wiktionary.org/wiki/Template:sv-infl-noun). The German templates only give the forms that differ from the page name, so their forms are only included if the page title is given in [`ParseOptions::title`](struct.ParseOptions.html#structfield.title). Russian declensions are instead described by [`russian_declensions`](#structfield.russian_declensions).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub declension: Vec<DeclinedForm<'a>>,
+
+    /// Definitions of the entry.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub definitions: Vec<Definition<'a>>,
+
+    /// Whether the subsection `Derived terms` is present in the section.
+    pub derived_terms: bool,
+
+    /// Whether the subsection `Descendants` is present in the section.
+    pub descendants: bool,
+
+    /// Diminutive forms of the entry, interpreted from the parameters of the head template [`de-noun`](https://en.wiktionary.org/wiki/Template:de-noun).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub diminutives: Vec<Cow<'a, str>>,
+
+    /// Grammatical genders of the entry, interpreted from the parameters of the head template.
+    ///
+    /// The genders are taken from the parameters `g`, `g2` and so on of any head template, such as [`head`](https://en.wiktionary.org/wiki/Template:head), and from the positional parameters of head templates that take the gender that way, such as [`de-noun`](https://en.wiktionary.org/wiki/Template:de-noun), [`es-noun`](https://en.wiktionary.org/wiki/Template:es-noun), [`fr-noun`](https://en.wiktionary.org/wiki/Template:fr-noun), [`it-noun`](https://en.wiktionary.org/wiki/Template:it-noun), [`nl-noun`](https://en.wiktionary.org/wiki/Template:nl-noun), [`pt-noun`](https://en.wiktionary.org/wiki/Template:pt-noun) and [`ru-noun`](https://en.wiktionary.org/wiki/Template:ru-noun). Number and animacy given together with the gender, such as in `m-an`, are not included.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub genders: Vec<Gender>,
+
+    /// Genitive singular forms of the entry, interpreted from the parameters of the head template [`de-noun`](https://en.wiktionary.org/wiki/Template:de-noun).
+    ///
+    /// Forms made from the page name by an ending are only included if the page title is given in [`ParseOptions::title`](struct.ParseOptions.html#structfield.title).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub genitives: Vec<Cow<'a, str>>,
+
+    /// Details about the template for displaying the word head for the entry, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head: Option<Template<'a>>,
+
+    /// The headword of the entry as displayed by the head template, if known.
+    ///
+    /// This is the parameter `head` of the head template if given, with links replaced by their text, and otherwise the page title if given in [`ParseOptions::title`](struct.ParseOptions.html#structfield.title), which is what head templates display by default. Without the parameter `head` and without a title, this is `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headword: Option<Cow<'a, str>>,
+
+    /// Whether the subsection `Hypernyms` is present in the section.
+    pub hypernyms: bool,
+
+    /// Whether the subsection `Hyponyms` is present in the section.
+    pub hyponyms: bool,
+
+    /// Details about each template for displaying an inflection for the entry.
+    ///
+    /// An inflection section may have several templates, such as one for each stem of an entry with alternative stems.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inflection: Vec<Template<'a>>,
+
+    /// Tables written directly in the subsections `Conjugation` and `Declension` instead of templates.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inflection_tables: Vec<Table<'a>>,
+
+    /// Details about the template in the subsection `Mutation` for displaying the initial consonant mutations of the entry, such as [`cy-mut`](https://en.wiktionary.org/wiki/Template:cy-mut) for Welsh, if any.
+    ///
+    /// Such a template is recognized if its name is the language code of the entry followed by `-mut`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mutation: Option<Template<'a>>,
+
+    /// The number of the heading of the section, if numbered, such as `2` for the heading `Noun 2`.
+    ///
+    /// Some pages have several numbered sections with the same part of speech instead of numbered etymologies.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<u32>,
+
+    /// Whether the entry is a noun that only occurs in the plural, such as `scissors`, as indicated by the head template [`en-plural noun`](https://en.wiktionary.org/wiki/Template:en-plural_noun).
+    pub plural_only: bool,
+
+    /// Plural forms of the entry, interpreted from the parameters of the head template [`en-noun`](https://en.wiktionary.org/wiki/Template:en-noun), [`en-proper noun`](https://en.wiktionary.org/wiki/Template:en-proper_noun), [`de-noun`](https://en.wiktionary.org/wiki/Template:de-noun), [`es-noun`](https://en.wiktionary.org/wiki/Template:es-noun), [`fr-noun`](https://en.wiktionary.org/wiki/Template:fr-noun), [`it-noun`](https://en.wiktionary.org/wiki/Template:it-noun) or [`pt-noun`](https://en.wiktionary.org/wiki/Template:pt-noun).
+    ///
+    /// Plurals formed from the page name, such as by the parameters `s` and `es` or by omitting the plural, are only included if the page title is given in [`ParseOptions::title`](struct.ParseOptions.html#structfield.title). The regular plural of a Spanish, French, Italian or Portuguese noun is only included if the spelling of the page name determines it, and is otherwise left out rather than guessed. Plurals that are unattested or unknown are not included.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub plurals: Vec<Cow<'a, str>>,
+
+    /// Part of speech of the entry.
+    pub pos: Pos,
+
+    /// The heading of the section, if the part of speech is [`Other`](enum.Pos.html#variant.Other).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pos_name: Option<Cow<'a, str>>,
+
+    /// Details about the template in the subsection `Readings` for displaying the readings of a character, such as [`ja-readings`](https://en.wiktionary.org/wiki/Template:ja-readings) for Japanese, if any.
+    ///
+    /// Such a template is recognized if its name is the language code of the entry followed by `-readings`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub readings: Option<Template<'a>>,
+
+    /// Whether the subsection `Related terms` is present in the section.
+    pub related_terms: bool,
+
+    /// Requests for improvement of the entry as a whole, written outside of any definition.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub requests: Vec<Request>,
+
+    /// Declensions of a Russian entry, interpreted from the parameters of the head template [`ru-noun+`](https://en.wiktionary.org/wiki/Template:ru-noun+) or from the templates in the subsection `Declension`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub russian_declensions: Vec<RussianDeclension<'a>>,
+
+    /// Superlative forms of the entry, such as `biggest` or `most beautiful`, interpreted the same way as [`comparatives`](#structfield.comparatives).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub superlatives: Vec<Cow<'a, str>>,
+
+    /// Whether the subsection `Synonyms` is present in the section.
+    pub synonyms: bool,
+
+    /// Whether the subsection `Translations` is present in the section.
+    pub translations: bool,
+
+    /// Content of the subsection `User notes` within the section, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage_notes: Option<Vec<Flowing<'a>>>,
+
+    /// Inflected forms of the entry, interpreted from the parameters of the head template [`en-verb`](https://en.wiktionary.org/wiki/Template:en-verb), if any are known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verb_forms: Option<VerbForms<'a>>,
+}
+
+/// Pronunciation of an entry, from the subsection `Pronunciation`.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Pronunciation<'a> {
+    /// Audio samples of the entry, from one of the templates [`audio`](https://en.wiktionary.org/wiki/Template:audio) and [`audio-IPA`](https://en.wiktionary.org/wiki/Template:audio-IPA).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub audio: Vec<Audio<'a>>,
+
+    /// Transcriptions of the pronunciation written in the respelling system [enPR](https://en.wiktionary.org/wiki/Appendix:English_pronunciation), from the template [`enPR`](https://en.wiktionary.org/wiki/Template:enPR).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub enpr: Vec<Transcription<'a>>,
+
+    /// Homophones of the entry, from the template [`homophones`](https://en.wiktionary.org/wiki/Template:homophones).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub homophones: Vec<Homophone<'a>>,
+
+    /// Hyphenations of the entry, each a list of syllables, from the template [`hyphenation`](https://en.wiktionary.org/wiki/Template:hyphenation).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hyphenation: Vec<Vec<Cow<'a, str>>>,
+
+    /// Transcriptions of the pronunciation written in IPA, from one of the templates [`IPA`](https://en.wiktionary.org/wiki/Template:IPA) and [`audio-IPA`](https://en.wiktionary.org/wiki/Template:audio-IPA).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ipa: Vec<Transcription<'a>>,
+
+    /// Templates that generate a pronunciation in IPA from the spelling of the entry, such as [`fr-IPA`](https://en.wiktionary.org/wiki/Template:fr-IPA) for French, or from romanizations given as parameters, such as [`zh-pron`](https://en.wiktionary.org/wiki/Template:zh-pron) for Chinese.
+    ///
+    /// Such a template is recognized if its name is the language code of the entry followed by `-IPA` or `-pron`. A template such as `zh-pron` is also recognized when written directly in the section instead of in a list.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ipa_templates: Vec<Template<'a>>,
+
+    /// Rhymes of the entry, from the template [`rhymes`](https://en.wiktionary.org/wiki/Template:rhymes).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rhymes: Vec<Rhyme<'a>>,
+
+    /// Transcriptions of the pronunciation written in [X-SAMPA](https://en.wikipedia.org/wiki/X-SAMPA), from the template [`X-SAMPA`](https://en.wiktionary.org/wiki/Template:X-SAMPA).
+    ///
+    /// The function [`x_sampa_to_ipa`](fn.x_sampa_to_ipa.html) converts them to IPA.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub x_sampa: Vec<Transcription<'a>>,
+}
+
+/// Language registered at runtime through [`ParseOptions::registered_languages`](struct.ParseOptions.html#structfield.registered_languages).
+#[derive(Clone, Debug, Default)]
+pub struct RegisteredLanguage {
+    /// The language code, as used in the language parameter of templates and in the names of language specific templates.
+    pub code: String,
+
+    /// Prefixes of the names of the head templates of the language, such as `tlh-` to recognize `tlh-noun` and `tlh-verb`.
+    ///
+    /// The template `head` is recognized for every language regardless of this list.
+    pub head_template_prefixes: Vec<String>,
+
+    /// The name of the language, as used in the heading of the language section.
+    pub name: String,
+}
+
+/// Request for improvement of an entry, from one of the [request templates](https://en.wiktionary.org/wiki/Category:Request_templates).
+///
+/// A request indicates that the information it applies to is incomplete or may be incorrect.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Request {
+    /// Request for cleanup, from the templates [`rfc`](https://en.wiktionary.org/wiki/Template:rfc) and [`rfc-sense`](https://en.wiktionary.org/wiki/Template:rfc-sense).
+    Cleanup,
+
+    /// Request for clarification, from the template [`rfclarify`](https://en.wiktionary.org/wiki/Template:rfclarify).
+    Clarification,
+
+    /// Request for a definition, from the template [`rfdef`](https://en.wiktionary.org/wiki/Template:rfdef).
+    Definition,
+
+    /// Request for deletion, from the template [`rfd-sense`](https://en.wiktionary.org/wiki/Template:rfd-sense).
+    Deletion,
+
+    /// Request for an etymology, from the template [`rfe`](https://en.wiktionary.org/wiki/Template:rfe).
+    Etymology,
+
+    /// Request for an example, from the template [`rfex`](https://en.wiktionary.org/wiki/Template:rfex).
+    Example,
+
+    /// Request for inflection, from the template [`rfinfl`](https://en.wiktionary.org/wiki/Template:rfinfl).
+    Inflection,
+
+    /// Request for a pronunciation, from the template [`rfp`](https://en.wiktionary.org/wiki/Template:rfp).
+    Pronunciation,
+
+    /// Request for a quotation, from the templates [`rfquote`](https://en.wiktionary.org/wiki/Template:rfquote) and [`rfquote-sense`](https://en.wiktionary.org/wiki/Template:rfquote-sense).
+    Quotation,
+
+    /// Request for verification, from the template [`rfv-sense`](https://en.wiktionary.org/wiki/Template:rfv-sense).
+    Verification,
+}
+
+/// Link to a resource outside the entry, from the subsections `Further reading` and `References`.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum Resource<'a> {
+    /// External link.
+    ///
+    /// Parsed from wiki text starting with `[`.
+    ExternalLink {
+        /// The text of the link, which is empty if the link has no text.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        text: Vec<Flowing<'a>>,
+
+        /// The URL of the link.
+        url: Cow<'a, str>,
+    },
+
+    /// Template linking to a resource, either a reference template with a name starting with `R:`, such as [`R:Webster 1913`](https://en.wiktionary.org/wiki/Template:R:Webster_1913), or a template linking to a sister project, such as [`pedia`](https://en.wiktionary.org/wiki/Template:pedia).
+    Template {
+        /// Details about the template.
+        template: Template<'a>,
+    },
+}
+
+/// Rhyme of an entry, from the template [`rhymes`](https://en.wiktionary.org/wiki/Template:rhymes).
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Rhyme<'a> {
+    /// The numbers of syllables of the entry with the rhyme, if given.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub syllables: Vec<u32>,
+
+    /// The rhyme, such as `æt`, displayed with a preceding hyphen.
+    pub value: Cow<'a, str>,
+}
+
+/// Declension of a Russian noun or adjective, from the parameters of the head template [`ru-noun+`](https://en.wiktionary.org/wiki/Template:ru-noun+) or the declension template [`ru-noun-table`](https://en.wiktionary.org/wiki/Template:ru-noun-table) or [`ru-decl-adj`](https://en.wiktionary.org/wiki/Template:ru-decl-adj).
+///
+/// The templates take one or more sets of parameters separated by the parameter `or`, each set describing one way the entry is declined.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct RussianDeclension<'a> {
+    /// The declension type, such as `*` for a reducible stem or `ий` for an adjective, if given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub declension_type: Option<Cow<'a, str>>,
+
+    /// The lemma or stem with stress marks, such as `стол`, if given.
+    ///
+    /// When this is not given, the lemma is the page name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lemma: Option<Cow<'a, str>>,
+
+    /// The accent pattern, such as `b` or `d'`, if given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stress_pattern: Option<Cow<'a, str>>,
+}
 
-    /// Article
-    Article,
+/// Approximate measurements of the size of the output of parsing a page.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Stats {
+    /// The number of elements in the output.
+    ///
+    /// Each entry, definition, template and element of flowing text counts as one element.
+    pub elements: usize,
 
-    /// Conjunction
-    Conjunction,
+    /// The number of bytes of text in the output.
+    ///
+    /// This counts the length of each piece of text in the output, including [`Output::word`](struct.Output.html#structfield.word), whether it's borrowed from the wiki text or not.
+    pub text_bytes: usize,
+}
 
-    /// Interjection
-    Interjection,
+/// Table written in wiki text.
+///
+/// Parsed from wiki text starting with `{|`. The attributes of the table, its rows and its cells are not parsed.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Table<'a> {
+    /// The captions of the table.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub captions: Vec<Vec<Flowing<'a>>>,
 
-    /// Noun
-    Noun,
+    /// The cells of each row of the table.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rows: Vec<Vec<TableCell<'a>>>,
+}
 
-    /// Numeral
-    Numeral,
+/// Cell of a table.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TableCell<'a> {
+    /// Whether the cell is a heading cell, parsed from wiki text starting with `!`, rather than an ordinary cell.
+    pub heading: bool,
 
-    /// Particle
-    Particle,
+    /// The content of the cell.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub value: Vec<Flowing<'a>>,
+}
 
-    /// Phrase
-    Phrase,
+/// Details about a template.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Template<'a> {
+    /// The byte position in the wiki text where the template ends.
+    pub end: usize,
 
-    /// Preposition
-    Preposition,
+    /// The name of the template.
+    pub name: Cow<'a, str>,
 
-    /// Pronoun
-    Pronoun,
+    /// The byte ranges in the wiki text of the named parameters to the template, ordered by name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub named_parameter_ranges: BTreeMap<Cow<'a, str>, Range<usize>>,
 
-    /// Proper noun
-    ProperNoun,
+    /// The values of the named parameters to the template, ordered by name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub named_parameters: BTreeMap<Cow<'a, str>, Vec<Flowing<'a>>>,
 
-    /// Verb
-    Verb,
-}
+    /// The byte position in the wiki text where the template starts.
+    pub start: usize,
 
-/// The entry for a part of speech within the entry for a language.
-///
-/// Parsed from the section with the part of speech as its heading.
-#[derive(Debug, Deserialize, Serialize)]
-pub struct PosEntry<'a> {
-    /// Whether the subsection `Antonyms` is present in the section.
-    pub antonyms: bool,
+    /// The byte ranges in the wiki text of the unnamed parameters to the template in order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unnamed_parameter_ranges: Vec<Range<usize>>,
 
-    /// Definitions of the entry.
+    /// The values of the unnamed parameters to the template in order.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub definitions: Vec<Definition<'a>>,
+    pub unnamed_parameters: Vec<Vec<Flowing<'a>>>,
+}
 
-    /// Whether the subsection `Derived terms` is present in the section.
-    pub derived_terms: bool,
+/// Tense of a verb form.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Tense {
+    /// Future
+    Future,
 
-    /// Details about the template for displaying the word head for the entry, if any.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub head: Option<Template<'a>>,
+    /// Imperfect, the past tense of ongoing or habitual actions in languages such as Spanish.
+    Imperfect,
 
-    /// Whether the subsection `Hypernyms` is present in the section.
-    pub hypernyms: bool,
+    /// Past, used for past participles.
+    Past,
 
-    /// Whether the subsection `Hyponyms` is present in the section.
-    pub hyponyms: bool,
+    /// Present
+    Present,
 
-    /// Details about each template for displaying an inflection for the entry.
+    /// Preterite, the simple past tense.
+    Preterite,
+}
+
+/// Transcription of the pronunciation of an entry.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Transcription<'a> {
+    /// Accents the transcription applies to, such as `UK` or `US`, from the template [`a`](https://en.wiktionary.org/wiki/Template:a) preceding the transcription or a parameter of the template of the transcription.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub inflection: Vec<Template<'a>>,
+    pub accents: Vec<Cow<'a, str>>,
 
-    /// Part of speech of the entry.
-    pub pos: Pos,
+    /// The transcription, including the surrounding slashes or brackets.
+    pub value: Cow<'a, str>,
+}
 
-    /// Whether the subsection `Related terms` is present in the section.
-    pub related_terms: bool,
+/// Inflected forms of an English verb, interpreted from the parameters of the head template [`en-verb`](https://en.wiktionary.org/wiki/Template:en-verb).
+///
+/// Regular forms the template forms from the page name, either by default or from the parameter `+` or `++`, are only present if the page title is given in [`ParseOptions::title`](struct.ParseOptions.html#structfield.title).
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct VerbForms<'a> {
+    /// The past participle, such as `stopped`, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub past_participle: Option<Cow<'a, str>>,
 
-    /// Whether the subsection `Synonyms` is present in the section.
-    pub synonyms: bool,
+    /// The simple past tense, such as `stopped`, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub past_tense: Option<Cow<'a, str>>,
 
-    /// Whether the subsection `Translations` is present in the section.
-    pub translations: bool,
+    /// The present participle, such as `stopping`, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub present_participle: Option<Cow<'a, str>>,
 
-    /// Content of the subsection `User notes` within the section, if any.
+    /// The third-person singular simple present, such as `stops`, if known.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub usage_notes: Option<Vec<Flowing<'a>>>,
+    pub third_person_singular: Option<Cow<'a, str>>,
 }
 
-/// Details about a template.
-#[derive(Debug, Deserialize, Serialize)]
-pub struct Template<'a> {
-    /// The name of the template.
-    pub name: Cow<'a, str>,
+/// Range of years, both inclusive.
+///
+/// Years are parsed from dates such as `1880`, `1880s`, `15th c.` and `early 15th century`, optionally preceded by `from` or `c.`, and from ranges of such dates separated by a dash. A century is taken to start with the year ending in `00`, so the 15th century is the range from 1400 to 1499, and the early, mid and late parts of a century or decade are its thirds.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct YearRange {
+    /// The last year of the range.
+    pub end: i32,
+
+    /// The first year of the range.
+    pub start: i32,
+}
 
-    /// The named parameters to the template by name.
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
-    pub named_parameters: HashMap<Cow<'a, str>, Cow<'a, str>>,
+/// What to do after visiting an entry, returned by the methods of [`ArticleVisitor`](trait.ArticleVisitor.html).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Visit {
+    /// Continue with the entries contained in the entry, then with the following entries.
+    Continue,
 
-    /// The unnamed parameters to the template in order.
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub unnamed_parameters: Vec<Cow<'a, str>>,
+    /// Continue with the following entries, skipping the entries contained in the entry.
+    Skip,
+
+    /// Stop parsing the article.
+    Stop,
 }
 
 /// Warning from the parser telling that something is not well-formed.
@@ -391,7 +2835,10 @@ pub struct Warning {
 }
 
 /// Identifier for a kind of warning from the parser.
+///
+/// More kinds of warnings may be added in later versions. When deserializing, an unknown kind of warning is deserialized as [`Other`](#variant.Other).
 #[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[non_exhaustive]
 #[serde(rename_all = "snake_case")]
 pub enum WarningMessage {
     /// The element is a duplicate of something that comes before it.
@@ -402,6 +2849,11 @@ pub enum WarningMessage {
     /// The element is missing some required content.
     Empty,
 
+    /// The output exceeded a limit given in [`ParseOptions`](struct.ParseOptions.html) when parsing the language section following the heading.
+    ///
+    /// The language section and everything after it is left out of the output.
+    OutputTooLarge,
+
     /// The section following the heading is missing some required content.
     SectionEmpty,
 
@@ -428,25 +2880,284 @@ pub enum WarningMessage {
     ///
     /// On template parameters it means that a parameter with this name (or lack of name) is valid in this position, but something about the value of the parameter is not recognized.
     ValueUnrecognized,
+
+    /// Kind of warning not known to this version of the library.
+    ///
+    /// This is never produced by the parser, only when deserializing output that has a kind of warning added in a later version.
+    #[serde(other)]
+    Other,
+}
+
+/// Formation of a word from other terms of the same language, from one of the templates [`affix`](https://en.wiktionary.org/wiki/Template:affix), [`back-formation`](https://en.wiktionary.org/wiki/Template:back-formation), [`blend`](https://en.wiktionary.org/wiki/Template:blend), [`clipping`](https://en.wiktionary.org/wiki/Template:clipping), [`compound`](https://en.wiktionary.org/wiki/Template:compound), [`confix`](https://en.wiktionary.org/wiki/Template:confix), [`prefix`](https://en.wiktionary.org/wiki/Template:prefix) and [`suffix`](https://en.wiktionary.org/wiki/Template:suffix).
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct WordFormation<'a> {
+    /// The kind of word formation.
+    pub kind: WordFormationKind,
+
+    /// The parts the word is formed from, in order.
+    pub morphemes: Vec<Morpheme<'a>>,
+}
+
+/// Kind of word formation.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WordFormationKind {
+    /// Any combination of affixes and stems, from the template [`affix`](https://en.wiktionary.org/wiki/Template:affix).
+    Affix,
+
+    /// Removal of an apparent affix from a term, from the template [`back-formation`](https://en.wiktionary.org/wiki/Template:back-formation).
+    ///
+    /// The only part is the term the entry is formed from.
+    BackFormation,
+
+    /// Combination of parts of terms, from the template [`blend`](https://en.wiktionary.org/wiki/Template:blend).
+    Blend,
+
+    /// Shortening of a term, from the template [`clipping`](https://en.wiktionary.org/wiki/Template:clipping).
+    ///
+    /// The only part is the term the entry is formed from.
+    Clipping,
+
+    /// Combination of stems, from the template [`compound`](https://en.wiktionary.org/wiki/Template:compound).
+    Compound,
+
+    /// Prefix and suffix attached to a stem at the same time, from the template [`confix`](https://en.wiktionary.org/wiki/Template:confix).
+    Confix,
+
+    /// Prefix attached to a stem, from the template [`prefix`](https://en.wiktionary.org/wiki/Template:prefix).
+    Prefix,
+
+    /// Suffix attached to a stem, from the template [`suffix`](https://en.wiktionary.org/wiki/Template:suffix).
+    Suffix,
 }
 
 /// Parses an article from the English language version of Wiktionary into structured data.
 ///
-/// `wiki_text` is the wiki text of the article. `nodes` is the sequence of nodes obtained by parsing the wiki text with the crate [Parse Wiki Text](https://github.com/portstrom/parse_wiki_text).
+/// `wiki_text` is the wiki text of the article. `nodes` is the sequence of nodes obtained by parsing the wiki text with the crate [Parse Wiki Text](https://github.com/portstrom/parse_wiki_text), which is re-exported as [`parse_wiki_text`](parse_wiki_text/index.html).
+///
+/// The output borrows from `wiki_text` only, not from `nodes`, so an application that keeps the output of Parse Wiki Text for other purposes, such as syntax highlighting in an editor, can pass its nodes here without parsing the wiki text again, and can modify or drop them while the output is still in use.
 #[must_use]
 pub fn parse<'a>(wiki_text: &'a str, nodes: &[Node<'a>]) -> Output<'a> {
-    let mut context = Context {
-        language: None,
-        warnings: vec![],
-        wiki_text,
+    parse_with_options(wiki_text, nodes, &ParseOptions::default())
+}
+
+/// Parses an article from the English language version of Wiktionary into structured data, parsing the wiki text with [Parse Wiki Text](https://github.com/portstrom/parse_wiki_text) internally.
+///
+/// The configuration for Parse Wiki Text is created by [`create_configuration`](fn.create_configuration.html) on the first call and reused by later calls. The warnings from Parse Wiki Text are not included in the output.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate parse_wiktionary_en;
+/// let output = parse_wiktionary_en::parse_article("==English==\n===Noun===\n# A word.");
+/// assert_eq!(output.language_entries.len(), 1);
+/// ```
+#[must_use]
+pub fn parse_article<'a>(wiki_text: &'a str) -> Output<'a> {
+    static CONFIGURATION: OnceLock<parse_wiki_text::Configuration> = OnceLock::new();
+    let configuration = CONFIGURATION.get_or_init(create_configuration);
+    parse(wiki_text, &configuration.parse(wiki_text).nodes)
+}
+
+/// Parses the definitions in a list of definitions of the given language, such as in the wiki text of a section obtained with the parameter `section` of the [parse API](https://www.mediawiki.org/wiki/API:Parsing_wikitext) of MediaWiki.
+///
+/// `wiki_text` and `nodes` are the same as for [`parse`](fn.parse.html), except that they need not be a whole article. The first list of definitions at the top level of the nodes is parsed, and the definitions are returned, or nothing if there is no list of definitions. The default options are used, and warnings are discarded.
+#[must_use]
+pub fn parse_definition_list<'a>(
+    wiki_text: &'a str,
+    nodes: &[Node<'a>],
+    language: Language,
+) -> Vec<Definition<'a>> {
+    let mut context = create_context(wiki_text, &ParseOptions::default());
+    context.discard_warnings = true;
+    context.language = Some(language);
+    nodes
+        .iter()
+        .find_map(|node| match node {
+            Node::OrderedList { items, .. } => Some(
+                items
+                    .iter()
+                    .map(|item| definition::parse_definition(&mut context, item))
+                    .collect(),
+            ),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+/// Parses only the section of the given language in an article from the English language version of Wiktionary, returning its entry if the article has a section for the language.
+///
+/// `wiki_text` and `nodes` are the same as for [`parse`](fn.parse.html). The headings of the article are scanned for the section of the language, recognizing the same names as [`Language::from_name`](enum.Language.html#method.from_name), and the rest of the article is skipped without being parsed. If the language has more than one section, only the first one is parsed. The default options are used, and no warnings are collected.
+///
+/// This is much faster than [`parse`](fn.parse.html) when only one language is wanted, such as when extracting the English entries from a dump of Wiktionary.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate parse_wiktionary_en;
+/// let wiki_text = "==English==\n===Noun===\n# A word.\n==German==\n===Noun===\n# Ein Wort.";
+/// let parsed_wiki_text = parse_wiktionary_en::create_configuration().parse(wiki_text);
+/// let language_entry = parse_wiktionary_en::parse_language_only(
+///     wiki_text,
+///     &parsed_wiki_text.nodes,
+///     parse_wiktionary_en::Language::De,
+/// )
+/// .unwrap();
+/// assert_eq!(language_entry.language, parse_wiktionary_en::Language::De);
+/// ```
+#[must_use]
+pub fn parse_language_only<'a>(
+    wiki_text: &'a str,
+    nodes: &[Node<'a>],
+    language: Language,
+) -> Option<LanguageEntry<'a>> {
+    let heading_index = nodes.iter().position(|node| match node {
+        Node::Heading {
+            level: 2,
+            nodes: heading_child_nodes,
+            ..
+        } => parse_text(heading_child_nodes)
+            .is_some_and(|heading_text| Language::from_name(&heading_text) == Some(language)),
+        _ => false,
+    })?;
+    let mut context = create_context(wiki_text, &ParseOptions::default());
+    context.discard_warnings = true;
+    context.language = Some(language);
+    let mut language_entries = vec![];
+    language::parse_language(
+        &mut context,
+        &nodes[heading_index],
+        &nodes[heading_index + 1..],
+        &mut language_entries,
+        language,
+        None,
+    );
+    language_entries.pop()
+}
+
+/// Parses a section of a part of speech of the given language, such as the wiki text of a section obtained with the parameter `section` of the [parse API](https://www.mediawiki.org/wiki/API:Parsing_wikitext) of MediaWiki.
+///
+/// `wiki_text` and `nodes` are the same as for [`parse`](fn.parse.html), except that the nodes start with the heading of the section, which is a part of speech such as `Noun`, at any level. The section is parsed with its subsections, such as usage notes and inflection, and the entry is returned, or nothing if the heading is not a part of speech. The default options are used, and warnings are discarded.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate parse_wiktionary_en;
+/// let wiki_text = "====Noun====\n{{de-noun|n,es,^er}}\n\n# [[house]]";
+/// let parsed_wiki_text = parse_wiktionary_en::create_configuration().parse(wiki_text);
+/// let pos_entry = parse_wiktionary_en::parse_pos_section(
+///     wiki_text,
+///     &parsed_wiki_text.nodes,
+///     parse_wiktionary_en::Language::De,
+/// )
+/// .unwrap();
+/// assert_eq!(pos_entry.pos, parse_wiktionary_en::Pos::Noun);
+/// assert_eq!(pos_entry.definitions.len(), 1);
+/// ```
+#[must_use]
+pub fn parse_pos_section<'a>(
+    wiki_text: &'a str,
+    nodes: &[Node<'a>],
+    language: Language,
+) -> Option<PosEntry<'a>> {
+    let (heading_node, level, heading_text) = match nodes.first()? {
+        node @ Node::Heading {
+            level,
+            nodes: heading_child_nodes,
+            ..
+        } => (node, *level, parse_text(heading_child_nodes)?),
+        _ => return None,
     };
+    let mut context = create_context(wiki_text, &ParseOptions::default());
+    context.discard_warnings = true;
+    context.language = Some(language);
+    let heading = language::parse_pos_heading(&context, &heading_text, &nodes[1..])?;
+    let mut pos_entries = vec![];
+    pos::parse_pos(&mut context, heading_node, &nodes[1..], &mut pos_entries, level + 1, heading);
+    pos_entries.pop()
+}
+
+/// Parses an article from the English language version of Wiktionary into structured data, using the given options.
+///
+/// `wiki_text` and `nodes` are the same as for [`parse`](fn.parse.html).
+#[must_use]
+pub fn parse_with_options<'a>(
+    wiki_text: &'a str,
+    nodes: &[Node<'a>],
+    options: &ParseOptions,
+) -> Output<'a> {
+    parse_sections(wiki_text, nodes, options, None)
+}
+
+/// Parses an article from the English language version of Wiktionary, passing the entries to the visitor as they are parsed instead of collecting them in the output.
+///
+/// `wiki_text` and `nodes` are the same as for [`parse`](fn.parse.html), and `options` are the same as for [`parse_with_options`](fn.parse_with_options.html). Each entry for a part of speech is visited as soon as it has been parsed, and each language entry after its section has been parsed, after which the language entry is dropped before the next section is parsed, so the whole article is never held in memory at once. Parsing stops as soon as the visitor returns [`Stop`](enum.Visit.html#variant.Stop). No warnings are collected.
+///
+/// Duplicate language sections are visited separately, since the entries of earlier sections are no longer available to merge them into.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate parse_wiktionary_en;
+/// struct FirstDefinition(Option<String>);
+///
+/// impl<'a> parse_wiktionary_en::ArticleVisitor<'a> for FirstDefinition {
+///     fn visit_definition(
+///         &mut self,
+///         _language: parse_wiktionary_en::Language,
+///         _pos_entry: &parse_wiktionary_en::PosEntry<'a>,
+///         definition: &parse_wiktionary_en::Definition<'a>,
+///     ) -> parse_wiktionary_en::Visit {
+///         self.0 = Some(definition.gloss_text());
+///         parse_wiktionary_en::Visit::Stop
+///     }
+/// }
+///
+/// let wiki_text = "==English==\n===Noun===\n# A word.\n# Another word.";
+/// let parsed_wiki_text = parse_wiktionary_en::create_configuration().parse(wiki_text);
+/// let mut visitor = FirstDefinition(None);
+/// parse_wiktionary_en::parse_with_visitor(
+///     wiki_text,
+///     &parsed_wiki_text.nodes,
+///     &Default::default(),
+///     &mut visitor,
+/// );
+/// assert_eq!(visitor.0.unwrap(), "A word.");
+/// ```
+pub fn parse_with_visitor<'a, V: ArticleVisitor<'a>>(
+    wiki_text: &'a str,
+    nodes: &[Node<'a>],
+    options: &ParseOptions,
+    visitor: &mut V,
+) {
+    parse_sections(wiki_text, nodes, options, Some(visitor));
+}
+
+// Parses the language sections of an article. With a visitor, the entries are visited as they are parsed, and each language entry is dropped instead of being included in the output.
+fn parse_sections<'a>(
+    wiki_text: &'a str,
+    nodes: &[Node<'a>],
+    options: &ParseOptions,
+    visitor: Option<&mut dyn ArticleVisitor<'a>>,
+) -> Output<'a> {
+    let mut context = create_context(wiki_text, options);
+    context.discard_warnings = visitor.is_some();
+    context.visitor = visitor;
+    let mut coverage = vec![];
     let mut language_entries = vec![];
     let mut node_index = 0;
+    let mut skipped_languages = vec![];
+    let mut stats = Stats::default();
+    if let Some(word) = &options.title {
+        stats.text_bytes += word.len();
+    }
+    context.stats = stats;
     while let Some(node) = nodes.get(node_index) {
         match node {
             Node::Heading {
                 level,
                 nodes: heading_child_nodes,
+                start,
                 ..
             } if *level < 3 =>
             {
@@ -455,17 +3166,94 @@ pub fn parse<'a>(wiki_text: &'a str, nodes: &[Node<'a>]) -> Output<'a> {
                     break;
                 }
                 if let Some(heading_text) = parse_text(heading_child_nodes) {
-                    if let Some(language) = Language::from_name(&heading_text) {
+                    let registered_language = options
+                        .registered_languages
+                        .iter()
+                        .find(|language| language.name == heading_text);
+                    let language = match Language::from_name(&heading_text) {
+                        Some(language) => Some((language, None)),
+                        None if options.parse_unknown_languages || registered_language.is_some() => {
+                            Some((Language::Other, Some(heading_text.clone())))
+                        }
+                        None => None,
+                    };
+                    // The number of nodes in the section after the heading.
+                    let section_length = nodes[node_index + 1..]
+                        .iter()
+                        .position(|node| matches!(node, Node::Heading { level, .. } if *level < 3))
+                        .unwrap_or(nodes.len() - node_index - 1);
+                    if let Some((language, language_name)) = language {
+                        if options
+                            .languages
+                            .as_ref()
+                            .is_some_and(|languages| !languages.contains(&language))
+                        {
+                            node_index += 1 + section_length;
+                            continue;
+                        }
                         node_index += 1;
                         context.language = Some(language);
+                        if language_name.is_some() {
+                            context.registered_language = registered_language.cloned();
+                        }
+                        let warning_index = context.warnings.len();
                         node_index += language::parse_language(
                             &mut context,
                             node,
                             &nodes[node_index..],
                             &mut language_entries,
                             language,
+                            language_name,
                         );
+                        // The language entry is incomplete when the visitor stopped the parsing within its section.
+                        if context.visit_stopped {
+                            break;
+                        }
+                        stats::measure_language_entry(&mut stats, language_entries.last().unwrap());
+                        context.stats = stats;
+                        if stats::is_output_too_large(&context) {
+                            language_entries.pop();
+                            add_warning(&mut context, node, WarningMessage::OutputTooLarge);
+                            context.language = None;
+                            context.registered_language = None;
+                            break;
+                        }
                         context.language = None;
+                        context.registered_language = None;
+                        coverage.push(coverage::measure_coverage(
+                            language,
+                            node.start()..nodes.get(node_index).map_or(wiki_text.len(), Node::start),
+                            &context.warnings[warning_index..],
+                        ));
+                        if context.visitor.is_some() {
+                            let language_entry = language_entries.pop().unwrap();
+                            visitor::visit_language_entry(&mut context, &language_entry);
+                            if context.visit_stopped {
+                                break;
+                            }
+                            continue;
+                        }
+                        if options.merge_duplicate_languages {
+                            let last_index = language_entries.len() - 1;
+                            let language_name = &language_entries[last_index].language_name;
+                            if let Some(index) =
+                                language_entries[..last_index].iter().position(|entry| {
+                                    entry.language == language && &entry.language_name == language_name
+                                })
+                            {
+                                let duplicate = language_entries.pop().unwrap();
+                                language::merge_language_entry(&mut language_entries[index], duplicate);
+                            }
+                        }
+                        continue;
+                    }
+                    let end = nodes
+                        .get(node_index + 1 + section_length)
+                        .map_or(wiki_text.len(), Node::start);
+                    skipped_languages.push((heading_text, *start..end));
+                    // When only some languages are parsed, the sections of unrecognized languages are skipped as a whole instead of warning about each node.
+                    if options.languages.is_some() {
+                        node_index += 1 + section_length;
                         continue;
                     }
                 }
@@ -483,44 +3271,374 @@ pub fn parse<'a>(wiki_text: &'a str, nodes: &[Node<'a>]) -> Output<'a> {
         add_warning(&mut context, node, WarningMessage::Unrecognized);
     }
     Output {
+        coverage,
         language_entries,
-        warnings: context.warnings,
-    }
-}
-
-impl Language {
-    /// Returns the language corresponding to the given language name if any.
-    pub fn from_name(name: &str) -> Option<Self> {
-        Some(match name {
-            "Czech" => Language::Cs,
-            "Dutch" => Language::Nl,
-            "English" => Language::En,
-            "Esperanto" => Language::Eo,
-            "French" => Language::Fr,
-            "German" => Language::De,
-            "Italian" => Language::It,
-            "Portuguese" => Language::Pt,
-            "Russian" => Language::Ru,
-            "Spanish" => Language::Es,
-            "Swedish" => Language::Sv,
-            _ => return None,
+        skipped_languages,
+        stats,
+        warnings: if options.discard_warnings {
+            vec![]
+        } else {
+            context.warnings
+        },
+        word: options.title.clone(),
+    }
+}
+
+impl<'a> Audio<'a> {
+    /// Returns the URL of the audio file in Wikimedia Commons.
+    ///
+    /// The URL refers to the file itself rather than its description page, and is redirected to the location where the file is stored.
+    pub fn commons_url(&self) -> String {
+        let mut url = "https://commons.wikimedia.org/wiki/Special:FilePath/".to_string();
+        for byte in self.file.trim().bytes() {
+            match byte {
+                b' ' => url.push('_'),
+                b'-' | b'.' | b'0'..=b'9' | b'A'..=b'Z' | b'_' | b'a'..=b'z' | b'~' => {
+                    url.push(byte as char)
+                }
+                _ => url.push_str(&format!("%{:02X}", byte)),
+            }
+        }
+        url
+    }
+}
+
+impl<'a> Definition<'a> {
+    /// Returns the definition as plain text, suitable for displaying as a gloss.
+    ///
+    /// Labels, definition dates, references and elements that could not be recognized are left out. Formatting is removed, links are replaced by their text and terms by the term itself. Nested definitions are not included.
+    pub fn gloss_text(&self) -> String {
+        text::gloss_text(self)
+    }
+
+    /// Returns the definition as [Markdown](https://commonmark.org/).
+    ///
+    /// The text is rendered as by [`render_markdown`](fn.render_markdown.html), followed by the nested definitions as a numbered list.
+    pub fn to_markdown(&self) -> String {
+        markdown::definition_markdown(self)
+    }
+}
+
+impl<'a> EtymologyEntry<'a> {
+    /// Returns the chain of languages the entry descends from, from the most recent to the oldest, such as Middle English, Old English and Proto-Germanic for an English entry.
+    ///
+    /// The chain is made of the [derivations](#structfield.derivations) in the order they appear in the description of the etymology, which by convention describes the history from the most recent stage to the oldest. The chain ends before the first derivation from a language that is already in the chain, since that is taken to be part of a separate remark, such as a comparison.
+    pub fn ancestry(&self) -> Vec<&Derivation<'a>> {
+        let mut ancestry: Vec<&Derivation<'a>> = vec![];
+        for derivation in &self.derivations {
+            if ancestry
+                .iter()
+                .any(|ancestor| ancestor.language == derivation.language)
+            {
+                break;
+            }
+            ancestry.push(derivation);
+        }
+        ancestry
+    }
+}
+
+impl<'a> LanguageEntry<'a> {
+    /// Returns the entries for the etymologies of the language entry, starting with [`etymology_entry`](#structfield.etymology_entry) followed by the [numbered ones](#structfield.etymology_entries).
+    pub fn etymologies<'b>(&'b self) -> impl Iterator<Item = &'b EtymologyEntry<'a>> + 'b {
+        std::iter::once(&self.etymology_entry).chain(&self.etymology_entries)
+    }
+
+    /// Returns the entries for parts of speech of all etymologies of the language entry, in the order of [`etymologies`](#method.etymologies).
+    pub fn pos_entries<'b>(&'b self) -> impl Iterator<Item = &'b PosEntry<'a>> + 'b {
+        self.etymologies()
+            .flat_map(|etymology_entry| &etymology_entry.pos_entries)
+    }
+
+    /// Returns the language entry as wiki text in the canonical layout of Wiktionary.
+    ///
+    /// Only the parts of the entry whose content is kept in the output are included: the headings, the glyph origin, the etymologies, the head templates, the definitions and the usage notes. Sections that are only recorded as present, such as synonyms and translations, the content of references and horizontal rules are left out. Parsing the wiki text gives the same values for the included parts, except that [`Flowing::Term`](enum.Flowing.html#variant.Term) is always written as the template `m` and character entities as numeric character references. Templates that take the language code of the entry, such as `lb`, are written with [`language_code`](#structfield.language_code) if any, otherwise with the code of [`language`](#structfield.language).
+    pub fn to_wiki_text(&self) -> String {
+        wiki_text::language_entry_wiki_text(self)
+    }
+}
+
+impl<'a> Output<'a> {
+    /// Returns how much of each language section was parsed, in the order the sections occur in the wiki text.
+    ///
+    /// Sections that were skipped because the language is not recognized are not included.
+    pub fn coverage(&self) -> &[Coverage] {
+        &self.coverage
+    }
+
+    /// Returns all definitions of all entries, each together with the entries for the language and part of speech it belongs to.
+    ///
+    /// Nested definitions are not returned separately, but are included in the definition they're nested in.
+    pub fn definitions<'b>(
+        &'b self,
+    ) -> impl Iterator<Item = (&'b LanguageEntry<'a>, &'b PosEntry<'a>, &'b Definition<'a>)> + 'b
+    {
+        self.language_entries.iter().flat_map(|language_entry| {
+            language_entry.pos_entries().flat_map(move |pos_entry| {
+                pos_entry
+                    .definitions
+                    .iter()
+                    .map(move |definition| (language_entry, pos_entry, definition))
+            })
         })
     }
 
-    /// Returns the language code for the language.
-    pub fn language_code(self) -> &'static str {
+    /// Returns the entries for the given part of speech in the entries for the given language, across all etymologies.
+    pub fn entry<'b>(
+        &'b self,
+        language: Language,
+        pos: Pos,
+    ) -> impl Iterator<Item = &'b PosEntry<'a>> + 'b {
+        self.language_entries
+            .iter()
+            .filter(move |language_entry| language_entry.language == language)
+            .flat_map(LanguageEntry::pos_entries)
+            .filter(move |pos_entry| pos_entry.pos == pos)
+    }
+
+    /// Converts the output into output that owns all its text, so it can be kept after the wiki text it was parsed from is dropped.
+    ///
+    /// All text is copied, also text that was already owned.
+    pub fn into_owned(self) -> Output<'static> {
+        owned::IntoOwned::into_owned(self)
+    }
+
+    /// Returns the first entry for the given language.
+    ///
+    /// Unless [`merge_duplicate_languages`](struct.ParseOptions.html#structfield.merge_duplicate_languages) is set, there may be further entries for the same language, which are only returned by [`entry`](#method.entry).
+    pub fn language(&self, language: Language) -> Option<&LanguageEntry<'a>> {
+        self.language_entries
+            .iter()
+            .find(|language_entry| language_entry.language == language)
+    }
+
+    /// Returns the approximate size of the output.
+    ///
+    /// When parsing stopped because of [`OutputTooLarge`](enum.WarningMessage.html#variant.OutputTooLarge), this includes the part of the language section that was parsed before it was left out.
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    /// Returns the output as wiki text, with the language entries written as by [`LanguageEntry::to_wiki_text`](struct.LanguageEntry.html#method.to_wiki_text) and separated by horizontal rules.
+    ///
+    /// Editing a value in the output and converting it back into wiki text allows the article to be modified programmatically, but since the output doesn't keep every part of the article, the result shouldn't replace the original article without care.
+    pub fn to_wiki_text(&self) -> String {
+        wiki_text::output_wiki_text(self)
+    }
+}
+
+impl ParseOptions {
+    /// Sets [`discard_warnings`](#structfield.discard_warnings).
+    #[must_use]
+    pub fn discard_warnings(mut self, discard_warnings: bool) -> Self {
+        self.discard_warnings = discard_warnings;
+        self
+    }
+
+    /// Limits parsing to the given languages, as described for [`languages`](#structfield.languages).
+    #[must_use]
+    pub fn languages(mut self, languages: Vec<Language>) -> Self {
+        self.languages = Some(languages);
+        self
+    }
+
+    /// Sets the limit [`max_definition_depth`](#structfield.max_definition_depth).
+    #[must_use]
+    pub fn max_definition_depth(mut self, depth: usize) -> Self {
+        self.max_definition_depth = Some(depth);
+        self
+    }
+
+    /// Sets the limit [`max_elements`](#structfield.max_elements).
+    #[must_use]
+    pub fn max_elements(mut self, limit: usize) -> Self {
+        self.max_elements = Some(limit);
+        self
+    }
+
+    /// Sets the limit [`max_text_bytes`](#structfield.max_text_bytes).
+    #[must_use]
+    pub fn max_text_bytes(mut self, limit: usize) -> Self {
+        self.max_text_bytes = Some(limit);
+        self
+    }
+
+    /// Sets [`merge_duplicate_languages`](#structfield.merge_duplicate_languages).
+    #[must_use]
+    pub fn merge_duplicate_languages(mut self, merge_duplicate_languages: bool) -> Self {
+        self.merge_duplicate_languages = merge_duplicate_languages;
+        self
+    }
+
+    /// Sets [`parse_unknown_languages`](#structfield.parse_unknown_languages).
+    #[must_use]
+    pub fn parse_unknown_languages(mut self, parse_unknown_languages: bool) -> Self {
+        self.parse_unknown_languages = parse_unknown_languages;
+        self
+    }
+
+    /// Sets [`parse_unknown_parts_of_speech`](#structfield.parse_unknown_parts_of_speech).
+    #[must_use]
+    pub fn parse_unknown_parts_of_speech(mut self, parse_unknown_parts_of_speech: bool) -> Self {
+        self.parse_unknown_parts_of_speech = parse_unknown_parts_of_speech;
+        self
+    }
+
+    /// Sets [`reconstruction`](#structfield.reconstruction).
+    #[must_use]
+    pub fn reconstruction(mut self, reconstruction: bool) -> Self {
+        self.reconstruction = reconstruction;
+        self
+    }
+
+    /// Adds a language to [`registered_languages`](#structfield.registered_languages).
+    #[must_use]
+    pub fn register_language(mut self, language: RegisteredLanguage) -> Self {
+        self.registered_languages.push(language);
+        self
+    }
+
+    /// Adds a heading to [`skipped_sections`](#structfield.skipped_sections).
+    #[must_use]
+    pub fn skip_section(mut self, heading: impl Into<String>) -> Self {
+        self.skipped_sections.push(heading.into());
+        self
+    }
+
+    /// Sets [`strict_head_templates`](#structfield.strict_head_templates).
+    #[must_use]
+    pub fn strict_head_templates(mut self, strict_head_templates: bool) -> Self {
+        self.strict_head_templates = strict_head_templates;
+        self
+    }
+
+    /// Sets the [`title`](#structfield.title) of the page.
+    #[must_use]
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+}
+
+impl Pos {
+    /// Returns the part of speech with the given heading, such as [`ProperNoun`](#variant.ProperNoun) for `Proper noun`, if recognized.
+    ///
+    /// The heading `Root` gives [`Root`](#variant.Root), although the parser only takes it to be a part of speech on a page in the namespace `Reconstruction`. Numbered headings such as `Noun 2` are not recognized by this function.
+    pub fn from_heading(heading: &str) -> Option<Self> {
+        match heading {
+            "Abbreviation" => Some(Pos::Abbreviation),
+            "Acronym" => Some(Pos::Acronym),
+            "Adjective" => Some(Pos::Adjective),
+            "Adverb" => Some(Pos::Adverb),
+            "Article" => Some(Pos::Article),
+            "Circumfix" => Some(Pos::Circumfix),
+            "Conjunction" => Some(Pos::Conjunction),
+            "Definitions" => Some(Pos::Definitions),
+            "Diacritical mark" => Some(Pos::DiacriticalMark),
+            "Hanja" => Some(Pos::Hanja),
+            "Infix" => Some(Pos::Infix),
+            "Initialism" => Some(Pos::Initialism),
+            "Interfix" => Some(Pos::Interfix),
+            "Interjection" => Some(Pos::Interjection),
+            "Kanji" => Some(Pos::Kanji),
+            "Letter" => Some(Pos::Letter),
+            "Noun" => Some(Pos::Noun),
+            "Numeral" => Some(Pos::Numeral),
+            "Participle" => Some(Pos::Participle),
+            "Particle" => Some(Pos::Particle),
+            "Phrase" => Some(Pos::Phrase),
+            "Prefix" => Some(Pos::Prefix),
+            "Preposition" => Some(Pos::Preposition),
+            "Prepositional phrase" => Some(Pos::PrepositionalPhrase),
+            "Pronoun" => Some(Pos::Pronoun),
+            "Proper noun" => Some(Pos::ProperNoun),
+            "Proverb" => Some(Pos::Proverb),
+            "Punctuation mark" => Some(Pos::PunctuationMark),
+            "Romanization" => Some(Pos::Romanization),
+            "Root" => Some(Pos::Root),
+            "Suffix" => Some(Pos::Suffix),
+            "Symbol" => Some(Pos::Symbol),
+            "Verb" => Some(Pos::Verb),
+            _ => None,
+        }
+    }
+
+    /// Returns the heading of the part of speech, such as `Proper noun` for [`ProperNoun`](#variant.ProperNoun).
+    ///
+    /// For [`Other`](#variant.Other) the name is `Other`. The heading of such a section is in [`PosEntry::pos_name`](struct.PosEntry.html#structfield.pos_name).
+    pub fn name(self) -> &'static str {
         match self {
-            Language::Cs => "cs",
-            Language::Nl => "nl",
-            Language::En => "en",
-            Language::Eo => "eo",
-            Language::Fr => "fr",
-            Language::De => "de",
-            Language::It => "it",
-            Language::Pt => "pt",
-            Language::Ru => "ru",
-            Language::Es => "es",
-            Language::Sv => "sv",
+            Pos::Abbreviation => "Abbreviation",
+            Pos::Acronym => "Acronym",
+            Pos::Adjective => "Adjective",
+            Pos::Adverb => "Adverb",
+            Pos::Article => "Article",
+            Pos::Circumfix => "Circumfix",
+            Pos::Conjunction => "Conjunction",
+            Pos::Definitions => "Definitions",
+            Pos::DiacriticalMark => "Diacritical mark",
+            Pos::Hanja => "Hanja",
+            Pos::Infix => "Infix",
+            Pos::Initialism => "Initialism",
+            Pos::Interfix => "Interfix",
+            Pos::Interjection => "Interjection",
+            Pos::Kanji => "Kanji",
+            Pos::Letter => "Letter",
+            Pos::Noun => "Noun",
+            Pos::Numeral => "Numeral",
+            Pos::Participle => "Participle",
+            Pos::Particle => "Particle",
+            Pos::Phrase => "Phrase",
+            Pos::Prefix => "Prefix",
+            Pos::Preposition => "Preposition",
+            Pos::PrepositionalPhrase => "Prepositional phrase",
+            Pos::Pronoun => "Pronoun",
+            Pos::ProperNoun => "Proper noun",
+            Pos::Proverb => "Proverb",
+            Pos::PunctuationMark => "Punctuation mark",
+            Pos::Romanization => "Romanization",
+            Pos::Root => "Root",
+            Pos::Suffix => "Suffix",
+            Pos::Symbol => "Symbol",
+            Pos::Verb => "Verb",
+            Pos::Other => "Other",
         }
     }
 }
+
+impl<'a> PosEntry<'a> {
+    /// Returns the entry as [Markdown](https://commonmark.org/), suitable for displaying the definitions of the entry.
+    ///
+    /// The entry is rendered as a level 3 heading with the part of speech followed by the definitions as a numbered list and, if present, the usage notes under a level 4 heading, matching the heading levels of Wiktionary. The text is rendered as by [`render_markdown`](fn.render_markdown.html). Other information in the entry is left out.
+    pub fn to_markdown(&self) -> String {
+        markdown::pos_entry_markdown(self)
+    }
+}
+
+impl<'a> Template<'a> {
+    /// Returns the value of the named parameter with the given name as plain text.
+    ///
+    /// Returns `None` if the parameter is not present or its value contains anything other than text and character entities.
+    pub fn named_text(&self, name: &str) -> Option<Cow<'a, str>> {
+        self.named_parameters
+            .get(name)
+            .and_then(|value| text::plain_text(value))
+    }
+
+    /// Returns the template as wiki text, such as `{{en-noun|es}}`.
+    ///
+    /// The named parameters follow the unnamed parameters and are sorted by name, since their original order is not kept.
+    pub fn to_wiki_text(&self) -> String {
+        wiki_text::template_wiki_text(self)
+    }
+
+    /// Returns the value of the unnamed parameter at the given index, counting from zero, as plain text.
+    ///
+    /// Returns `None` if the parameter is not present or its value contains anything other than text and character entities.
+    pub fn unnamed_text(&self, index: usize) -> Option<Cow<'a, str>> {
+        self.unnamed_parameters
+            .get(index)
+            .and_then(|value| text::plain_text(value))
+    }
+}
+
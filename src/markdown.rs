@@ -0,0 +1,201 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+// Characters that are escaped with a backslash in text, since they may otherwise be interpreted as Markdown syntax.
+const SPECIAL_CHARACTERS: &str = "#*<>[\\]_`|";
+
+/// Converts flowing text into [Markdown](https://commonmark.org/).
+///
+/// Bold and italic text is formatted, links become Markdown links to Wiktionary, Wikipedia or the external URL, terms are italicized and labels are italicized within parentheses, such as `*(informal, dated)*`. Subscripts and superscripts become the HTML elements `sub` and `sup`. Definition dates, references, media, tables and elements that could not be recognized are left out. Whitespace at the start and end is removed.
+pub fn render_markdown(elements: &[::Flowing]) -> String {
+    let mut output = String::new();
+    push_markdown(&mut output, elements);
+    output.trim().to_string()
+}
+
+pub fn definition_markdown(definition: &::Definition) -> String {
+    let mut output = render_markdown(&definition.definition);
+    if !definition.definitions.is_empty() {
+        output.push('\n');
+        push_definitions(&mut output, &definition.definitions, "");
+    }
+    output
+}
+
+pub fn pos_entry_markdown(pos_entry: &::PosEntry) -> String {
+    let mut output = "### ".to_string();
+    match (pos_entry.pos, &pos_entry.pos_name) {
+        (::Pos::Other, Some(pos_name)) => push_escaped(&mut output, pos_name),
+        (pos, _) => output.push_str(pos.name()),
+    }
+    output.push_str("\n\n");
+    push_definitions(&mut output, &pos_entry.definitions, "");
+    if let Some(usage_notes) = &pos_entry.usage_notes {
+        output.push_str("\n#### Usage notes\n\n");
+        output.push_str(&render_markdown(usage_notes));
+        output.push('\n');
+    }
+    output
+}
+
+// Pushes the definitions as a numbered list, with each line indented by the given indentation and nested definitions as nested lists.
+fn push_definitions(output: &mut String, definitions: &[::Definition], indentation: &str) {
+    for (index, definition) in definitions.iter().enumerate() {
+        let marker = format!("{}. ", index + 1);
+        let content_indentation = format!("{}{:2$}", indentation, "", marker.len());
+        output.push_str(indentation);
+        output.push_str(&marker);
+        push_indented(output, &render_markdown(&definition.definition), &content_indentation);
+        output.push('\n');
+        push_definitions(output, &definition.definitions, &content_indentation);
+    }
+}
+
+fn push_escaped(output: &mut String, text: &str) {
+    for character in text.chars() {
+        if SPECIAL_CHARACTERS.contains(character) {
+            output.push('\\');
+        }
+        output.push(character);
+    }
+}
+
+// Pushes the text with every line except the first indented, so it continues the list item it's in. Empty lines are not indented.
+fn push_indented(output: &mut String, text: &str, indentation: &str) {
+    for (index, line) in text.split('\n').enumerate() {
+        if index > 0 {
+            output.push('\n');
+            if !line.is_empty() {
+                output.push_str(indentation);
+            }
+        }
+        output.push_str(line);
+    }
+}
+
+fn push_link(output: &mut String, text: &str, url: &str) {
+    output.push('[');
+    push_escaped(output, text);
+    output.push_str("](");
+    for character in url.chars() {
+        match character {
+            ' ' => output.push_str("%20"),
+            '(' => output.push_str("%28"),
+            ')' => output.push_str("%29"),
+            _ => output.push(character),
+        }
+    }
+    output.push(')');
+}
+
+fn push_markdown(output: &mut String, elements: &[::Flowing]) {
+    for element in elements {
+        match element {
+            ::Flowing::Bold => output.push_str("**"),
+            ::Flowing::CharacterEntity { character } => {
+                push_escaped(output, character.encode_utf8(&mut [0; 4]))
+            }
+            ::Flowing::DefinitionDate { .. }
+            | ::Flowing::Media { .. }
+            | ::Flowing::Reference
+            | ::Flowing::Table { .. }
+            | ::Flowing::Unknown { .. } => {}
+            ::Flowing::ExternalLink { text, url } => {
+                let text = if text.is_empty() {
+                    url.to_string()
+                } else {
+                    ::text::render_plain(text)
+                };
+                push_link(output, &text, url);
+            }
+            ::Flowing::Italic => output.push('*'),
+            ::Flowing::Labels {
+                disjunctive,
+                labels,
+            } => if !labels.is_empty() {
+                let separator = if *disjunctive { " or " } else { ", " };
+                output.push_str("*(");
+                for (index, label) in labels.iter().enumerate() {
+                    if index > 0 {
+                        output.push_str(separator);
+                    }
+                    push_escaped(output, &label.text);
+                }
+                output.push_str(")*");
+            },
+            ::Flowing::LineBreak => output.push_str("\\\n"),
+            ::Flowing::Link { target, text } => {
+                push_link(output, text, &page_url("https://en.wiktionary.org/wiki/", target))
+            }
+            ::Flowing::NonGlossDefinition { value } => {
+                output.push('*');
+                push_markdown(output, value);
+                output.push('*');
+            }
+            ::Flowing::ParagraphBreak => output.push_str("\n\n"),
+            ::Flowing::SmallCapitals { value } => push_markdown(output, value),
+            ::Flowing::Subscript { value } => {
+                output.push_str("<sub>");
+                push_markdown(output, value);
+                output.push_str("</sub>");
+            }
+            ::Flowing::Superscript { value } => {
+                output.push_str("<sup>");
+                push_markdown(output, value);
+                output.push_str("</sup>");
+            }
+            ::Flowing::Term {
+                alternative, term, ..
+            } => {
+                output.push('*');
+                push_escaped(output, alternative.as_ref().unwrap_or(term));
+                output.push('*');
+            }
+            ::Flowing::Text { value } => push_escaped(output, value),
+            ::Flowing::UnorderedList { items } => {
+                if !output.is_empty() && !output.ends_with('\n') {
+                    output.push('\n');
+                }
+                for item in items {
+                    output.push_str("- ");
+                    push_indented(output, &render_markdown(item), "  ");
+                    output.push('\n');
+                }
+            }
+            ::Flowing::WikipediaLink {
+                language,
+                target,
+                text,
+            } => {
+                let base = format!(
+                    "https://{}.wikipedia.org/wiki/",
+                    language.as_ref().map_or("en", |language| language)
+                );
+                push_link(output, text, &page_url(&base, target));
+            }
+        }
+    }
+}
+
+// Returns the URL of the page with the given title, encoded the same way as MediaWiki encodes titles in URLs. The fragment of the title, if any, is kept.
+fn page_url(base: &str, title: &str) -> String {
+    let mut url = base.to_string();
+    for byte in title.trim().bytes() {
+        match byte {
+            b' ' => url.push('_'),
+            b'#'
+            | b'-'
+            | b'.'
+            | b'/'
+            | b'0'..=b'9'
+            | b':'
+            | b'A'..=b'Z'
+            | b'_'
+            | b'a'..=b'z'
+            | b'~' => url.push(byte as char),
+            _ => url.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    url
+}
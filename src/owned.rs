@@ -0,0 +1,316 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+use std::collections::BTreeMap;
+
+// Conversion into a value that owns all its text rather than borrowing from the wiki text.
+//
+// The method is called with the syntax `IntoOwned::into_owned(value)`, since `Cow` has an inherent method with the same name returning the owned string.
+pub trait IntoOwned {
+    type Owned;
+
+    fn into_owned(self) -> Self::Owned;
+}
+
+impl<'a> IntoOwned for ::Cow<'a, str> {
+    type Owned = ::Cow<'static, str>;
+
+    fn into_owned(self) -> ::Cow<'static, str> {
+        ::Cow::Owned(::Cow::into_owned(self))
+    }
+}
+
+impl<K: IntoOwned, V: IntoOwned> IntoOwned for BTreeMap<K, V>
+where
+    K::Owned: Ord,
+{
+    type Owned = BTreeMap<K::Owned, V::Owned>;
+
+    fn into_owned(self) -> Self::Owned {
+        self.into_iter()
+            .map(|(key, value)| (IntoOwned::into_owned(key), IntoOwned::into_owned(value)))
+            .collect()
+    }
+}
+
+impl<T: IntoOwned> IntoOwned for Option<T> {
+    type Owned = Option<T::Owned>;
+
+    fn into_owned(self) -> Self::Owned {
+        self.map(IntoOwned::into_owned)
+    }
+}
+
+impl<T: IntoOwned> IntoOwned for Vec<T> {
+    type Owned = Vec<T::Owned>;
+
+    fn into_owned(self) -> Self::Owned {
+        self.into_iter().map(IntoOwned::into_owned).collect()
+    }
+}
+
+impl IntoOwned for ::Range<usize> {
+    type Owned = Self;
+
+    fn into_owned(self) -> Self {
+        self
+    }
+}
+
+impl<A: IntoOwned, B: IntoOwned> IntoOwned for (A, B) {
+    type Owned = (A::Owned, B::Owned);
+
+    fn into_owned(self) -> Self::Owned {
+        (IntoOwned::into_owned(self.0), IntoOwned::into_owned(self.1))
+    }
+}
+
+// Implements the conversion for a struct by converting the fields in the first list and moving the fields in the second list, which don't borrow anything.
+macro_rules! into_owned_struct {
+    ($name:ident { $($converted:ident),* } { $($moved:ident),* }) => {
+        impl<'a> IntoOwned for ::$name<'a> {
+            type Owned = ::$name<'static>;
+
+            fn into_owned(self) -> Self::Owned {
+                ::$name {
+                    $($converted: IntoOwned::into_owned(self.$converted),)*
+                    $($moved: self.$moved,)*
+                }
+            }
+        }
+    };
+}
+
+into_owned_struct!(Audio { accents, caption, file } {});
+into_owned_struct!(Category { name } { kind });
+into_owned_struct!(Cognate { gloss, language, term } { noncognate });
+into_owned_struct!(ConjugatedForm { form } { mood, number, person, tense });
+into_owned_struct!(DeclinedForm { form } { case, definite, number });
+into_owned_struct!(Definition { definition, definitions } { examples, quotations, requests });
+into_owned_struct!(Derivation { gloss, language, term } { kind });
+into_owned_struct!(EtymologyEntry {
+    cognates,
+    derivations,
+    doublets,
+    etymology,
+    pie_roots,
+    pos_entries,
+    pronunciation,
+    root,
+    word_formations
+} {
+    alternative_forms,
+    onomatopoeic,
+    requests,
+    uncertain_origin,
+    unknown_origin
+});
+into_owned_struct!(Homophone { qualifiers, term } {});
+into_owned_struct!(Label { text } { kind });
+into_owned_struct!(LanguageEntry {
+    categories,
+    etymology_entries,
+    etymology_entry,
+    forms,
+    glyph_origin,
+    language_code,
+    language_name,
+    resources
+} {
+    anagrams,
+    further_reading,
+    language
+});
+into_owned_struct!(Morpheme { gloss, language, term } {});
+into_owned_struct!(Output { language_entries, skipped_languages } {
+    coverage,
+    stats,
+    warnings,
+    word
+});
+into_owned_struct!(PosEntry {
+    alternative_form_of,
+    comparatives,
+    conjugation,
+    declension,
+    definitions,
+    diminutives,
+    genitives,
+    head,
+    headword,
+    inflection,
+    inflection_tables,
+    mutation,
+    plurals,
+    pos_name,
+    readings,
+    russian_declensions,
+    superlatives,
+    usage_notes,
+    verb_forms
+} {
+    antonyms,
+    comparable,
+    countability,
+    derived_terms,
+    descendants,
+    genders,
+    hypernyms,
+    hyponyms,
+    number,
+    plural_only,
+    pos,
+    related_terms,
+    requests,
+    synonyms,
+    translations
+});
+into_owned_struct!(Pronunciation {
+    audio,
+    enpr,
+    homophones,
+    hyphenation,
+    ipa,
+    ipa_templates,
+    rhymes,
+    x_sampa
+} {});
+into_owned_struct!(Rhyme { value } { syllables });
+into_owned_struct!(RussianDeclension { declension_type, lemma, stress_pattern } {});
+into_owned_struct!(Table { captions, rows } {});
+into_owned_struct!(TableCell { value } { heading });
+into_owned_struct!(Template {
+    name,
+    named_parameter_ranges,
+    named_parameters,
+    unnamed_parameters
+} {
+    end,
+    start,
+    unnamed_parameter_ranges
+});
+into_owned_struct!(Transcription { accents, value } {});
+into_owned_struct!(VerbForms {
+    past_participle,
+    past_tense,
+    present_participle,
+    third_person_singular
+} {});
+into_owned_struct!(WordFormation { morphemes } { kind });
+
+impl<'a> IntoOwned for ::Flowing<'a> {
+    type Owned = ::Flowing<'static>;
+
+    fn into_owned(self) -> Self::Owned {
+        match self {
+            ::Flowing::Bold => ::Flowing::Bold,
+            ::Flowing::CharacterEntity { character } => ::Flowing::CharacterEntity { character },
+            ::Flowing::DefinitionDate {
+                first_attestation,
+                obsolescence,
+                obsolescence_value,
+                value,
+            } => ::Flowing::DefinitionDate {
+                first_attestation,
+                obsolescence,
+                obsolescence_value: IntoOwned::into_owned(obsolescence_value),
+                value: IntoOwned::into_owned(value),
+            },
+            ::Flowing::ExternalLink { text, url } => ::Flowing::ExternalLink {
+                text: IntoOwned::into_owned(text),
+                url: IntoOwned::into_owned(url),
+            },
+            ::Flowing::Italic => ::Flowing::Italic,
+            ::Flowing::Labels {
+                disjunctive,
+                labels,
+            } => ::Flowing::Labels {
+                disjunctive,
+                labels: IntoOwned::into_owned(labels),
+            },
+            ::Flowing::LineBreak => ::Flowing::LineBreak,
+            ::Flowing::Link { target, text } => ::Flowing::Link {
+                target: IntoOwned::into_owned(target),
+                text: IntoOwned::into_owned(text),
+            },
+            ::Flowing::Media { caption, file } => ::Flowing::Media {
+                caption: IntoOwned::into_owned(caption),
+                file: IntoOwned::into_owned(file),
+            },
+            ::Flowing::NonGlossDefinition { value } => ::Flowing::NonGlossDefinition {
+                value: IntoOwned::into_owned(value),
+            },
+            ::Flowing::ParagraphBreak => ::Flowing::ParagraphBreak,
+            ::Flowing::Reference => ::Flowing::Reference,
+            ::Flowing::SmallCapitals { value } => ::Flowing::SmallCapitals {
+                value: IntoOwned::into_owned(value),
+            },
+            ::Flowing::Subscript { value } => ::Flowing::Subscript {
+                value: IntoOwned::into_owned(value),
+            },
+            ::Flowing::Superscript { value } => ::Flowing::Superscript {
+                value: IntoOwned::into_owned(value),
+            },
+            ::Flowing::Table { captions, rows } => ::Flowing::Table {
+                captions: IntoOwned::into_owned(captions),
+                rows: IntoOwned::into_owned(rows),
+            },
+            ::Flowing::Term {
+                alternative,
+                gloss,
+                id,
+                language,
+                literal,
+                part_of_speech,
+                term,
+                transcription,
+                transliteration,
+            } => ::Flowing::Term {
+                alternative: IntoOwned::into_owned(alternative),
+                gloss: IntoOwned::into_owned(gloss),
+                id: IntoOwned::into_owned(id),
+                language: IntoOwned::into_owned(language),
+                literal: IntoOwned::into_owned(literal),
+                part_of_speech: IntoOwned::into_owned(part_of_speech),
+                term: IntoOwned::into_owned(term),
+                transcription: IntoOwned::into_owned(transcription),
+                transliteration: IntoOwned::into_owned(transliteration),
+            },
+            ::Flowing::Text { value } => ::Flowing::Text {
+                value: IntoOwned::into_owned(value),
+            },
+            ::Flowing::Unknown { value } => ::Flowing::Unknown {
+                value: IntoOwned::into_owned(value),
+            },
+            ::Flowing::UnorderedList { items } => ::Flowing::UnorderedList {
+                items: IntoOwned::into_owned(items),
+            },
+            ::Flowing::WikipediaLink {
+                language,
+                target,
+                text,
+            } => ::Flowing::WikipediaLink {
+                language: IntoOwned::into_owned(language),
+                target: IntoOwned::into_owned(target),
+                text: IntoOwned::into_owned(text),
+            },
+        }
+    }
+}
+
+impl<'a> IntoOwned for ::Resource<'a> {
+    type Owned = ::Resource<'static>;
+
+    fn into_owned(self) -> Self::Owned {
+        match self {
+            ::Resource::ExternalLink { text, url } => ::Resource::ExternalLink {
+                text: IntoOwned::into_owned(text),
+                url: IntoOwned::into_owned(url),
+            },
+            ::Resource::Template { template } => ::Resource::Template {
+                template: IntoOwned::into_owned(template),
+            },
+        }
+    }
+}
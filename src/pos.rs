@@ -2,39 +2,70 @@
 // This is free software distributed under the terms specified in
 // the file LICENSE at the top-level directory of this distribution.
 
+pub struct PosHeading<'a> {
+    // The heading, if the part of speech is `Other`.
+    pub name: Option<::Cow<'a, str>>,
+    pub number: Option<u32>,
+    pub pos: ::Pos,
+}
+
 pub fn parse_pos<'a>(
-    context: &mut ::Context<'a>,
+    context: &mut ::Context<'a, '_>,
     heading_node: &::Node,
     nodes: &[::Node<'a>],
     pos_entries: &mut Vec<::PosEntry<'a>>,
     heading_level: u8,
-    pos: ::Pos,
+    heading: PosHeading<'a>,
 ) -> usize {
+    let PosHeading { name, number, pos } = heading;
     for entry in pos_entries.iter() {
-        if entry.pos == pos {
+        if entry.pos == pos && entry.number == number && entry.pos_name == name {
             ::add_warning(context, heading_node, ::WarningMessage::Duplicate);
             break;
         }
     }
+    let mut alternative_form_of = vec![];
     let mut definitions = None;
     let mut head = None;
     let mut node_index = 0;
+    let mut requests = vec![];
     while let Some(node) = nodes.get(node_index) {
         match node {
             ::Node::Heading { .. } => break,
             ::Node::Template {
                 name, parameters, ..
             } => if let Some(name) = ::parse_text(name) {
-                if check_head_template_name(context.language.unwrap(), &name) {
+                // Some languages have a template for referring to the entry in another spelling or script, such as `ja-see`.
+                if name.strip_suffix("-see") == Some(::language_code(context)) {
+                    node_index += 1;
+                    parse_alternative_form_of(context, node, parameters, &mut alternative_form_of);
+                    continue;
+                }
+                if check_head_template_name(context.language.unwrap(), &name)
+                    || !context.strict_head_templates
+                        && check_generic_head_template_name(::language_code(context), &name)
+                    || context.registered_language.as_ref().is_some_and(|language| {
+                        language
+                            .head_template_prefixes
+                            .iter()
+                            .any(|prefix| name.starts_with(prefix.as_str()))
+                    })
+                {
                     node_index += 1;
                     if head.is_some() {
                         head = Some(None);
                         ::add_warning(context, node, ::WarningMessage::Duplicate);
                     } else {
-                        head = Some(::template::parse_template(context, name, parameters));
+                        head = Some(::template::parse_template(context, node, name, parameters));
                     }
                     continue;
                 }
+                if ::request::parse_request(&mut requests, &name)
+                    || ::category::parse_category(context, node, &name, parameters)
+                {
+                    node_index += 1;
+                    continue;
+                }
             },
             ::Node::OrderedList { items, .. } => {
                 node_index += 1;
@@ -56,14 +87,15 @@ pub fn parse_pos<'a>(
         node_index += 1;
         ::add_warning(context, node, ::WarningMessage::Unrecognized);
     }
-    if definitions.is_none() {
-        ::add_warning(context, heading_node, ::WarningMessage::SectionEmpty);
-    }
     let mut antonyms = false;
     let mut derived_terms = false;
+    let mut descendants = false;
     let mut hypernyms = false;
     let mut hyponyms = false;
     let mut inflection = vec![];
+    let mut inflection_tables = vec![];
+    let mut mutation = None;
+    let mut readings = None;
     let mut related_terms = false;
     let mut synonyms = false;
     let mut translations = false;
@@ -84,26 +116,58 @@ pub fn parse_pos<'a>(
                 if *level < heading_level {
                     break;
                 }
-                if let Some(heading_text) = ::parse_text(&heading_child_nodes) {
+                if let Some(heading_text) = ::parse_text(heading_child_nodes) {
+                    if ::is_skipped_section(context, &heading_text) {
+                        node_index += 1;
+                        node_index +=
+                            ::supplementary::skip_section(context, *level, &nodes[node_index..]);
+                        continue;
+                    }
                     match &heading_text as _ {
                         "Antonyms" => {
                             parse_section!(::supplementary::parse_supplementary, antonyms)
                         }
-                        "Conjugation" => {
-                            parse_section!(::inflection::parse_inflection, inflection, "-conj-")
-                        }
-                        "Declension" => {
-                            parse_section!(::inflection::parse_inflection, inflection, "-decl-")
-                        }
+                        "Conjugation" => parse_section!(
+                            ::inflection::parse_inflection,
+                            inflection,
+                            &mut inflection_tables,
+                            &["-conj-"]
+                        ),
+                        "Declension" => parse_section!(
+                            ::inflection::parse_inflection,
+                            inflection,
+                            &mut inflection_tables,
+                            &["-decl-"]
+                        ),
                         "Derived terms" => {
                             parse_section!(::supplementary::parse_supplementary, derived_terms)
                         }
+                        "Descendants" => {
+                            parse_section!(::supplementary::parse_supplementary, descendants)
+                        }
                         "Hypernyms" => {
                             parse_section!(::supplementary::parse_supplementary, hypernyms)
                         }
                         "Hyponyms" => {
                             parse_section!(::supplementary::parse_supplementary, hyponyms)
                         }
+                        // Some entries use this heading for either conjugation or declension, or for templates named for inflection in general.
+                        "Inflection" => parse_section!(
+                            ::inflection::parse_inflection,
+                            inflection,
+                            &mut inflection_tables,
+                            &["-conj-", "-decl-", "-infl-"]
+                        ),
+                        "Mutation" => parse_section!(
+                            ::template_section::parse_template_section,
+                            mutation,
+                            "-mut"
+                        ),
+                        "Readings" => parse_section!(
+                            ::template_section::parse_template_section,
+                            readings,
+                            "-readings"
+                        ),
                         "Related terms" => {
                             parse_section!(::supplementary::parse_supplementary, related_terms)
                         }
@@ -124,30 +188,93 @@ pub fn parse_pos<'a>(
         node_index += 1;
         ::add_warning(context, node, ::WarningMessage::Unrecognized);
     }
-    pos_entries.push(::PosEntry {
+    // A section without definitions is kept as a partial entry as long as any of its subsections is present, such as only a table of translations.
+    if definitions.is_none()
+        && alternative_form_of.is_empty()
+        && !(antonyms
+            || derived_terms
+            || descendants
+            || hypernyms
+            || hyponyms
+            || related_terms
+            || synonyms
+            || translations
+            || !inflection.is_empty()
+            || mutation.is_some()
+            || readings.is_some()
+            || usage_notes.is_some())
+    {
+        ::add_warning(context, heading_node, ::WarningMessage::SectionEmpty);
+    }
+    let mut pos_entry = ::PosEntry {
+        alternative_form_of,
         antonyms,
+        comparable: None,
+        comparatives: vec![],
+        conjugation: vec![],
+        countability: None,
+        declension: vec![],
         definitions: definitions.unwrap_or_default(),
         derived_terms,
+        descendants,
+        diminutives: vec![],
+        genders: vec![],
+        genitives: vec![],
         head: head.unwrap_or_default(),
+        headword: None,
         hypernyms,
         hyponyms,
         inflection,
+        inflection_tables,
+        mutation,
+        number,
+        plural_only: false,
+        plurals: vec![],
         pos,
+        pos_name: name,
+        readings,
         related_terms,
+        requests,
+        russian_declensions: vec![],
+        superlatives: vec![],
         synonyms,
         translations,
         usage_notes: usage_notes.unwrap_or_default(),
-    });
+        verb_forms: None,
+    };
+    ::head::interpret_head(context, &mut pos_entry);
+    ::conjugation::interpret_conjugation(context, &mut pos_entry);
+    ::declension::interpret_declension(context, &mut pos_entry);
+    if let Some(depth) = context.max_definition_depth {
+        ::definition::limit_depth(&mut pos_entry.definitions, depth);
+    }
+    ::stats::measure_pos_entry(&mut context.stats, &pos_entry);
+    ::visitor::visit_pos_entry(context, &pos_entry);
+    pos_entries.push(pos_entry);
     node_index
 }
 
+// The list of head templates is kept as arms of a match, the same as the other lists of templates, rather than as one pattern of `matches!`.
+#[allow(clippy::match_like_matches_macro)]
 fn check_head_template_name(language: ::Language, template_name: &str) -> bool {
     match (language, template_name) {
         (_, "head")
+        | (::Language::Ang, "ang-adj")
+        | (::Language::Ang, "ang-adv")
+        | (::Language::Ang, "ang-noun")
+        | (::Language::Ang, "ang-verb")
         | (::Language::Cs, "cs-adj")
         | (::Language::Cs, "cs-adv")
         | (::Language::Cs, "cs-noun")
         | (::Language::Cs, "cs-proper noun")
+        | (::Language::Cy, "cy-adj")
+        | (::Language::Cy, "cy-noun")
+        | (::Language::Cy, "cy-verb")
+        | (::Language::Da, "da-adj")
+        | (::Language::Da, "da-adv")
+        | (::Language::Da, "da-noun")
+        | (::Language::Da, "da-proper noun")
+        | (::Language::Da, "da-verb")
         | (::Language::De, "de-adj")
         | (::Language::De, "de-adv")
         | (::Language::De, "de-noun")
@@ -155,17 +282,163 @@ fn check_head_template_name(language: ::Language, template_name: &str) -> bool {
         | (::Language::De, "de-verb-strong")
         | (::Language::De, "de-verb-weak")
         | (::Language::En, "en-adj")
+        | (::Language::En, "en-adv")
+        | (::Language::En, "en-interj")
         | (::Language::En, "en-noun")
+        | (::Language::En, "en-plural noun")
+        | (::Language::En, "en-prep phrase")
+        | (::Language::En, "en-pron")
         | (::Language::En, "en-proper noun")
         | (::Language::En, "en-verb")
+        | (::Language::Enm, "enm-adj")
+        | (::Language::Enm, "enm-adv")
+        | (::Language::Enm, "enm-noun")
+        | (::Language::Enm, "enm-proper noun")
+        | (::Language::Enm, "enm-verb")
+        | (::Language::Eo, "eo-adj")
+        | (::Language::Eo, "eo-adv")
+        | (::Language::Eo, "eo-head")
+        | (::Language::Eo, "eo-noun")
+        | (::Language::Eo, "eo-pn")
+        | (::Language::Eo, "eo-verb")
         | (::Language::Es, "es-adj")
         | (::Language::Es, "es-adv")
         | (::Language::Es, "es-noun")
+        | (::Language::Fi, "fi-adj")
+        | (::Language::Fi, "fi-adv")
+        | (::Language::Fi, "fi-noun")
+        | (::Language::Fi, "fi-proper noun")
+        | (::Language::Fi, "fi-verb")
+        | (::Language::Fr, "fr-adj")
+        | (::Language::Fr, "fr-adv")
+        | (::Language::Fr, "fr-noun")
+        | (::Language::Fr, "fr-proper noun")
+        | (::Language::Fr, "fr-verb")
+        | (::Language::Ga, "ga-adj")
+        | (::Language::Ga, "ga-noun")
+        | (::Language::Ga, "ga-verb")
+        | (::Language::Grc, "grc-adj-1&2")
+        | (::Language::Grc, "grc-adj-1&3")
+        | (::Language::Grc, "grc-adj-2nd")
+        | (::Language::Grc, "grc-adv")
+        | (::Language::Grc, "grc-noun")
+        | (::Language::Grc, "grc-proper noun")
+        | (::Language::Grc, "grc-verb")
+        | (::Language::Hu, "hu-adj")
+        | (::Language::Hu, "hu-adv")
+        | (::Language::Hu, "hu-noun")
+        | (::Language::Hu, "hu-proper noun")
+        | (::Language::Hu, "hu-verb")
+        | (::Language::It, "it-adj")
+        | (::Language::It, "it-adv")
+        | (::Language::It, "it-noun")
+        | (::Language::It, "it-proper noun")
+        | (::Language::It, "it-verb")
+        | (::Language::Ja, "ja-adj")
+        | (::Language::Ja, "ja-kanji")
+        | (::Language::Ja, "ja-noun")
+        | (::Language::Ja, "ja-pos")
+        | (::Language::Ja, "ja-verb")
+        | (::Language::Ja, "ja-verb-suru")
+        | (::Language::Ko, "ko-adj")
+        | (::Language::Ko, "ko-hanja")
+        | (::Language::Ko, "ko-noun")
+        | (::Language::Ko, "ko-verb")
+        | (::Language::La, "la-adj")
+        | (::Language::La, "la-adv")
+        | (::Language::La, "la-noun")
+        | (::Language::La, "la-part")
+        | (::Language::La, "la-proper noun")
+        | (::Language::La, "la-verb")
+        | (::Language::Nb, "nb-adj")
+        | (::Language::Nb, "nb-adv")
+        | (::Language::Nb, "nb-noun")
+        | (::Language::Nb, "nb-proper noun")
+        | (::Language::Nb, "nb-verb")
+        | (::Language::Nl, "nl-adj")
+        | (::Language::Nl, "nl-adv")
+        | (::Language::Nl, "nl-noun")
+        | (::Language::Nl, "nl-proper noun")
+        | (::Language::Nl, "nl-verb")
+        | (::Language::Nn, "nn-adj")
+        | (::Language::Nn, "nn-adv")
+        | (::Language::Nn, "nn-noun")
+        | (::Language::Nn, "nn-proper noun")
+        | (::Language::Nn, "nn-verb")
+        | (::Language::Pl, "pl-adj")
+        | (::Language::Pl, "pl-adv")
+        | (::Language::Pl, "pl-noun")
+        | (::Language::Pl, "pl-proper noun")
+        | (::Language::Pl, "pl-verb")
+        | (::Language::Pt, "pt-adj")
+        | (::Language::Pt, "pt-adv")
+        | (::Language::Pt, "pt-noun")
+        | (::Language::Pt, "pt-proper noun")
+        | (::Language::Pt, "pt-verb")
+        | (::Language::Ru, "ru-adj")
+        | (::Language::Ru, "ru-adv")
+        | (::Language::Ru, "ru-noun")
+        | (::Language::Ru, "ru-noun+")
+        | (::Language::Ru, "ru-proper noun")
+        | (::Language::Ru, "ru-proper noun+")
+        | (::Language::Ru, "ru-verb")
+        | (::Language::Sh, "sh-adjective")
+        | (::Language::Sh, "sh-noun")
+        | (::Language::Sh, "sh-proper noun")
+        | (::Language::Sh, "sh-verb")
         | (::Language::Sv, "sv-adj")
         | (::Language::Sv, "sv-adv")
         | (::Language::Sv, "sv-noun")
         | (::Language::Sv, "sv-proper noun")
-        | (::Language::Sv, "sv-verb-reg") => true,
+        | (::Language::Sv, "sv-verb-reg")
+        | (::Language::Uk, "uk-adj")
+        | (::Language::Uk, "uk-adv")
+        | (::Language::Uk, "uk-noun")
+        | (::Language::Uk, "uk-proper noun")
+        | (::Language::Uk, "uk-verb")
+        | (::Language::Zh, "zh-adj")
+        | (::Language::Zh, "zh-noun")
+        | (::Language::Zh, "zh-verb") => true,
         _ => false,
     }
 }
+
+// Most languages have their own head templates named by the language code followed by `-` and the part of speech, so any such template is accepted, except for the templates for other purposes sharing the same naming.
+fn check_generic_head_template_name(language_code: &str, template_name: &str) -> bool {
+    match template_name
+        .strip_prefix(language_code)
+        .and_then(|name| name.strip_prefix('-'))
+    {
+        None => false,
+        Some(name) => !(name.starts_with("conj")
+            || name.starts_with("decl")
+            || name.starts_with("infl")
+            || name.ends_with("-table")
+            || matches!(
+                name,
+                "" | "IPA" | "forms" | "hanja" | "kanjitab" | "mut" | "ndecl" | "pron" | "readings"
+                    | "x"
+            )),
+    }
+}
+
+fn parse_alternative_form_of<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+    output: &mut Vec<::Cow<'a, str>>,
+) {
+    if parameters.is_empty() {
+        ::add_warning(context, template_node, ::WarningMessage::ValueUnrecognized);
+    }
+    for parameter in parameters {
+        if parameter.name.is_some() {
+            ::add_warning(context, parameter, ::WarningMessage::Unrecognized);
+            continue;
+        }
+        match ::parse_text_not_empty(&parameter.value) {
+            None => ::add_warning(context, parameter, ::WarningMessage::ValueUnrecognized),
+            Some(term) => output.push(term),
+        }
+    }
+}
@@ -2,20 +2,11 @@
 // This is free software distributed under the terms specified in
 // the file LICENSE at the top-level directory of this distribution.
 
-#[derive(Default)]
-pub struct Pronunciation {
-    pub audio: bool,
-    pub homophones: bool,
-    pub hyphenation: bool,
-    pub ipa: bool,
-    pub rhymes: bool,
-}
-
-pub fn parse_pronunciation(
-    context: &mut ::Context,
+pub fn parse_pronunciation<'a>(
+    context: &mut ::Context<'a, '_>,
     heading_node: &::Node,
-    nodes: &[::Node],
-    output: &mut Option<Pronunciation>,
+    nodes: &[::Node<'a>],
+    output: &mut Option<::Pronunciation<'a>>,
 ) -> usize {
     if output.is_some() {
         ::add_warning(context, heading_node, ::WarningMessage::Duplicate);
@@ -33,15 +24,71 @@ pub fn parse_pronunciation(
                 }
                 has_list = true;
                 for item in items {
+                    // Accents apply to the transcriptions and audio samples following them in the same list item.
+                    let mut accents = vec![];
                     for node in &item.nodes {
-                        if let ::Node::Template { name, .. } = node {
+                        if let ::Node::Template {
+                            name, parameters, ..
+                        } = node
+                        {
                             if let Some(name) = ::parse_text(name) {
                                 match &name as _ {
-                                    "IPA" | "cs-IPA" => pronunciation.ipa = true,
-                                    "audio" => pronunciation.audio = true,
-                                    "homophones" => pronunciation.homophones = true,
-                                    "hyphenation" => pronunciation.hyphenation = true,
-                                    "rhymes" => pronunciation.rhymes = true,
+                                    "IPA" => parse_ipa(
+                                        context,
+                                        node,
+                                        parameters,
+                                        &accents,
+                                        &mut pronunciation.ipa,
+                                    ),
+                                    "X-SAMPA" => parse_transcriptions(
+                                        context,
+                                        node,
+                                        parameters,
+                                        &accents,
+                                        &mut pronunciation.x_sampa,
+                                    ),
+                                    "a" | "accent" => accents = parse_accents(context, node, parameters),
+                                    "audio" => parse_audio(
+                                        context,
+                                        node,
+                                        parameters,
+                                        &accents,
+                                        &mut pronunciation.audio,
+                                    ),
+                                    "audio-IPA" => parse_audio_ipa(
+                                        context,
+                                        node,
+                                        parameters,
+                                        &accents,
+                                        &mut pronunciation,
+                                    ),
+                                    "enPR" => parse_transcriptions(
+                                        context,
+                                        node,
+                                        parameters,
+                                        &accents,
+                                        &mut pronunciation.enpr,
+                                    ),
+                                    "hmp" | "homophones" => parse_homophones(
+                                        context,
+                                        node,
+                                        parameters,
+                                        &mut pronunciation.homophones,
+                                    ),
+                                    "hyph" | "hyphenation" => parse_hyphenation(
+                                        context,
+                                        node,
+                                        parameters,
+                                        &mut pronunciation.hyphenation,
+                                    ),
+                                    "rhymes" => {
+                                        parse_rhymes(context, node, parameters, &mut pronunciation.rhymes)
+                                    }
+                                    _ if check_ipa_template_name(context, &name) => {
+                                        pronunciation.ipa_templates.extend(::template::parse_template(
+                                            context, node, name, parameters,
+                                        ))
+                                    }
                                     _ => {}
                                 }
                             }
@@ -49,6 +96,21 @@ pub fn parse_pronunciation(
                     }
                 }
             }
+            // A template such as `zh-pron` may be written directly in the section instead of in a list.
+            ::Node::Template {
+                name, parameters, ..
+            } => {
+                node_index += 1;
+                match ::parse_text(name) {
+                    Some(name) if check_ipa_template_name(context, &name) => {
+                        has_list = true;
+                        pronunciation.ipa_templates.extend(::template::parse_template(
+                            context, node, name, parameters,
+                        ));
+                    }
+                    _ => ::add_warning(context, node, ::WarningMessage::Unrecognized),
+                }
+            }
             _ => {
                 node_index += 1;
                 ::add_warning(context, node, ::WarningMessage::Unrecognized);
@@ -61,3 +123,413 @@ pub fn parse_pronunciation(
     *output = Some(pronunciation);
     node_index
 }
+
+// Each language has its own template for generating IPA, such as `fr-IPA`, or for generating the pronunciation in several systems, such as `zh-pron`.
+fn check_ipa_template_name(context: &::Context, name: &str) -> bool {
+    let language_code = ::language_code(context);
+    name.strip_suffix("-IPA") == Some(language_code)
+        || name.strip_suffix("-pron") == Some(language_code)
+}
+
+fn parse_accent_parameter<'a>(
+    context: &mut ::Context<'a, '_>,
+    parameter: &::Parameter<'a>,
+    output: &mut Vec<::Cow<'a, str>>,
+) {
+    // The accents are separated by commas, as in `a=UK,US`.
+    match ::parse_text_not_empty(&parameter.value) {
+        None => ::add_warning(context, parameter, ::WarningMessage::ValueUnrecognized),
+        Some(::Cow::Borrowed(value)) => output.extend(
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|accent| !accent.is_empty())
+                .map(::Cow::Borrowed),
+        ),
+        Some(::Cow::Owned(value)) => output.extend(
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|accent| !accent.is_empty())
+                .map(|accent| ::Cow::Owned(accent.to_string())),
+        ),
+    }
+}
+
+fn parse_accents<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+) -> Vec<::Cow<'a, str>> {
+    let mut accents = vec![];
+    for parameter in parameters {
+        if parameter.name.is_some() {
+            ::add_warning(context, parameter, ::WarningMessage::Unrecognized);
+            continue;
+        }
+        match ::parse_text_not_empty(&parameter.value) {
+            None => ::add_warning(context, parameter, ::WarningMessage::ValueUnrecognized),
+            Some(value) => accents.push(value),
+        }
+    }
+    if accents.is_empty() {
+        ::add_warning(context, template_node, ::WarningMessage::Empty);
+    }
+    accents
+}
+
+fn parse_audio<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+    accents: &[::Cow<'a, str>],
+    output: &mut Vec<::Audio<'a>>,
+) {
+    let mut accents = accents.to_vec();
+    let mut unnamed_parameters = vec![];
+    for parameter in parameters {
+        if parameter.name.is_some() {
+            if ::parse_parameter_name(parameter) == Some("a") {
+                parse_accent_parameter(context, parameter, &mut accents);
+            } else {
+                ::add_warning(context, parameter, ::WarningMessage::Supplementary);
+            }
+            continue;
+        }
+        match ::parse_text(&parameter.value) {
+            None => {
+                ::add_warning(context, parameter, ::WarningMessage::ValueUnrecognized);
+                return;
+            }
+            Some(value) => unnamed_parameters.push(value),
+        }
+    }
+    let (language, file, caption) = match unnamed_parameters.as_slice() {
+        [language, file] => (language, file, None),
+        [language, file, caption] => (language, file, Some(caption)),
+        _ => {
+            ::add_warning(context, template_node, ::WarningMessage::ValueUnrecognized);
+            return;
+        }
+    };
+    if language != ::language_code(context) {
+        ::add_warning(context, template_node, ::WarningMessage::ValueConflicting);
+    } else if file.is_empty() {
+        ::add_warning(context, template_node, ::WarningMessage::Empty);
+    } else {
+        output.push(::Audio {
+            accents,
+            caption: caption.filter(|caption| !caption.is_empty()).cloned(),
+            file: file.clone(),
+        });
+    }
+}
+
+// The template `audio-IPA` has the parameters language, file and transcription, and produces both an audio sample and a transcription in IPA.
+fn parse_audio_ipa<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+    accents: &[::Cow<'a, str>],
+    pronunciation: &mut ::Pronunciation<'a>,
+) {
+    let mut accents = accents.to_vec();
+    let mut unnamed_parameters = vec![];
+    for parameter in parameters {
+        if parameter.name.is_some() {
+            if ::parse_parameter_name(parameter) == Some("a") {
+                parse_accent_parameter(context, parameter, &mut accents);
+            } else {
+                ::add_warning(context, parameter, ::WarningMessage::Supplementary);
+            }
+            continue;
+        }
+        match ::parse_text(&parameter.value) {
+            None => {
+                ::add_warning(context, parameter, ::WarningMessage::ValueUnrecognized);
+                return;
+            }
+            Some(value) => unnamed_parameters.push(value),
+        }
+    }
+    let (language, file, ipa) = match unnamed_parameters.as_slice() {
+        [language, file, ipa] => (language, file, ipa),
+        _ => {
+            ::add_warning(context, template_node, ::WarningMessage::ValueUnrecognized);
+            return;
+        }
+    };
+    if language != ::language_code(context) {
+        ::add_warning(context, template_node, ::WarningMessage::ValueConflicting);
+    } else if file.is_empty() || ipa.is_empty() {
+        ::add_warning(context, template_node, ::WarningMessage::Empty);
+    } else {
+        pronunciation.audio.push(::Audio {
+            accents: accents.clone(),
+            caption: None,
+            file: file.clone(),
+        });
+        pronunciation.ipa.push(::Transcription {
+            accents,
+            value: ipa.clone(),
+        });
+    }
+}
+
+fn parse_homophones<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+    output: &mut Vec<::Homophone<'a>>,
+) {
+    let mut has_language = false;
+    let mut homophones = vec![];
+    let mut qualifiers = vec![];
+    for parameter in parameters {
+        if parameter.name.is_some() {
+            // The parameters `q1`, `qq1` and so on are qualifiers displayed before and after the individual homophones.
+            let index = ::parse_parameter_name(parameter)
+                .and_then(|name| name.strip_prefix("qq").or_else(|| name.strip_prefix('q')))
+                .and_then(|index| index.parse::<usize>().ok());
+            match index {
+                None => ::add_warning(context, parameter, ::WarningMessage::Supplementary),
+                Some(index) => match ::parse_text_not_empty(&parameter.value) {
+                    None => ::add_warning(context, parameter, ::WarningMessage::ValueUnrecognized),
+                    Some(value) => qualifiers.push((index, value)),
+                },
+            }
+            continue;
+        }
+        match ::parse_text_not_empty(&parameter.value) {
+            None => {
+                ::add_warning(context, parameter, ::WarningMessage::ValueUnrecognized);
+                return;
+            }
+            Some(value) => if !has_language {
+                if value != ::language_code(context) {
+                    ::add_warning(context, parameter, ::WarningMessage::ValueConflicting);
+                    return;
+                }
+                has_language = true;
+            } else {
+                homophones.push(::Homophone {
+                    qualifiers: vec![],
+                    term: value,
+                });
+            },
+        }
+    }
+    if homophones.is_empty() {
+        ::add_warning(context, template_node, ::WarningMessage::Empty);
+    }
+    for (index, value) in qualifiers {
+        if let Some(homophone) = index.checked_sub(1).and_then(|index| homophones.get_mut(index)) {
+            homophone.qualifiers.push(value);
+        }
+    }
+    output.extend(homophones);
+}
+
+fn parse_hyphenation<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+    output: &mut Vec<Vec<::Cow<'a, str>>>,
+) {
+    let mut has_language = false;
+    // Alternative hyphenations are separated by an empty parameter.
+    let mut hyphenations = vec![vec![]];
+    for parameter in parameters {
+        if parameter.name.is_some() {
+            ::add_warning(context, parameter, ::WarningMessage::Supplementary);
+            continue;
+        }
+        match ::parse_text(&parameter.value) {
+            None => {
+                ::add_warning(context, parameter, ::WarningMessage::ValueUnrecognized);
+                return;
+            }
+            Some(value) => if !has_language {
+                if value != ::language_code(context) {
+                    ::add_warning(context, parameter, ::WarningMessage::ValueConflicting);
+                    return;
+                }
+                has_language = true;
+            } else if value.is_empty() {
+                hyphenations.push(vec![]);
+            } else {
+                hyphenations.last_mut().unwrap().push(value);
+            },
+        }
+    }
+    hyphenations.retain(|syllables| !syllables.is_empty());
+    if hyphenations.is_empty() {
+        ::add_warning(context, template_node, ::WarningMessage::Empty);
+    }
+    for syllables in hyphenations {
+        if !output.contains(&syllables) {
+            output.push(syllables);
+        }
+    }
+}
+
+fn parse_ipa<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+    accents: &[::Cow<'a, str>],
+    output: &mut Vec<::Transcription<'a>>,
+) {
+    let mut accents = accents.to_vec();
+    let mut has_language = false;
+    let mut transcriptions = vec![];
+    for parameter in parameters {
+        if parameter.name.is_some() {
+            if ::parse_parameter_name(parameter) == Some("a") {
+                parse_accent_parameter(context, parameter, &mut accents);
+            } else {
+                // Qualifiers, notes and references to individual transcriptions.
+                ::add_warning(context, parameter, ::WarningMessage::Supplementary);
+            }
+            continue;
+        }
+        match ::parse_text_not_empty(&parameter.value) {
+            None => {
+                ::add_warning(context, parameter, ::WarningMessage::ValueUnrecognized);
+                return;
+            }
+            Some(value) => if !has_language {
+                if value != ::language_code(context) {
+                    ::add_warning(context, parameter, ::WarningMessage::ValueConflicting);
+                    return;
+                }
+                has_language = true;
+            } else {
+                transcriptions.push(value);
+            },
+        }
+    }
+    if transcriptions.is_empty() {
+        ::add_warning(context, template_node, ::WarningMessage::Empty);
+    }
+    output.extend(
+        transcriptions
+            .into_iter()
+            .map(|value| ::Transcription {
+                accents: accents.clone(),
+                value,
+            }),
+    );
+}
+
+fn parse_rhymes<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+    output: &mut Vec<::Rhyme<'a>>,
+) {
+    let mut has_language = false;
+    let mut rhymes = vec![];
+    let mut syllables = vec![];
+    for parameter in parameters {
+        if parameter.name.is_some() {
+            // The parameter `s` gives the number of syllables of all rhymes and `s1`, `s2` and so on of individual rhymes.
+            let index = ::parse_parameter_name(parameter)
+                .and_then(|name| name.strip_prefix('s'))
+                .filter(|index| index.bytes().all(|byte| byte.is_ascii_digit()));
+            match index {
+                None => ::add_warning(context, parameter, ::WarningMessage::Supplementary),
+                Some(index) => match ::parse_text(&parameter.value)
+                    .and_then(|value| parse_syllables(&value))
+                {
+                    None => ::add_warning(context, parameter, ::WarningMessage::ValueUnrecognized),
+                    Some(value) => syllables.push((index.parse::<usize>().ok(), value)),
+                },
+            }
+            continue;
+        }
+        match ::parse_text_not_empty(&parameter.value) {
+            None => {
+                ::add_warning(context, parameter, ::WarningMessage::ValueUnrecognized);
+                return;
+            }
+            Some(value) => if !has_language {
+                if value != ::language_code(context) {
+                    ::add_warning(context, parameter, ::WarningMessage::ValueConflicting);
+                    return;
+                }
+                has_language = true;
+            } else {
+                rhymes.push(::Rhyme {
+                    syllables: vec![],
+                    value,
+                });
+            },
+        }
+    }
+    if rhymes.is_empty() {
+        ::add_warning(context, template_node, ::WarningMessage::Empty);
+    }
+    for (index, value) in syllables {
+        match index {
+            None => for rhyme in &mut rhymes {
+                if rhyme.syllables.is_empty() {
+                    rhyme.syllables = value.clone();
+                }
+            },
+            Some(index) => if let Some(rhyme) =
+                index.checked_sub(1).and_then(|index| rhymes.get_mut(index))
+            {
+                rhyme.syllables = value;
+            },
+        }
+    }
+    output.extend(rhymes);
+}
+
+fn parse_syllables(value: &str) -> Option<Vec<u32>> {
+    value
+        .split(',')
+        .map(|count| count.trim().parse().ok().filter(|count| *count > 0))
+        .collect()
+}
+
+// Parses a template with transcriptions as its unnamed parameters and no language code, such as `enPR` and `X-SAMPA`.
+fn parse_transcriptions<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+    accents: &[::Cow<'a, str>],
+    output: &mut Vec<::Transcription<'a>>,
+) {
+    let mut accents = accents.to_vec();
+    let mut transcriptions = vec![];
+    for parameter in parameters {
+        if parameter.name.is_some() {
+            if ::parse_parameter_name(parameter) == Some("a") {
+                parse_accent_parameter(context, parameter, &mut accents);
+            } else {
+                ::add_warning(context, parameter, ::WarningMessage::Supplementary);
+            }
+            continue;
+        }
+        match ::parse_text_not_empty(&parameter.value) {
+            None => {
+                ::add_warning(context, parameter, ::WarningMessage::ValueUnrecognized);
+                return;
+            }
+            Some(value) => transcriptions.push(value),
+        }
+    }
+    if transcriptions.is_empty() {
+        ::add_warning(context, template_node, ::WarningMessage::Empty);
+    }
+    output.extend(
+        transcriptions
+            .into_iter()
+            .map(|value| ::Transcription {
+                accents: accents.clone(),
+                value,
+            }),
+    );
+}
@@ -0,0 +1,23 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+pub fn parse_request(output: &mut Vec<::Request>, template_name: &str) -> bool {
+    let request = match template_name {
+        "rfc" | "rfc-sense" => ::Request::Cleanup,
+        "rfclarify" => ::Request::Clarification,
+        "rfd-sense" => ::Request::Deletion,
+        "rfdef" => ::Request::Definition,
+        "rfe" => ::Request::Etymology,
+        "rfex" => ::Request::Example,
+        "rfinfl" => ::Request::Inflection,
+        "rfp" => ::Request::Pronunciation,
+        "rfquote" | "rfquote-sense" => ::Request::Quotation,
+        "rfv-sense" => ::Request::Verification,
+        _ => return false,
+    };
+    if !output.contains(&request) {
+        output.push(request);
+    }
+    true
+}
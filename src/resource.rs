@@ -0,0 +1,79 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+// Parses the subsection `Further reading` or `References`, collecting the external links and the templates linking to resources from its lists. Everything else in the section is supplementary.
+pub fn parse_resources<'a>(
+    context: &mut ::Context<'a, '_>,
+    _heading_node: &::Node,
+    nodes: &[::Node<'a>],
+    output: &mut Vec<::Resource<'a>>,
+) -> usize {
+    let mut node_index = 0;
+    while let Some(node) = nodes.get(node_index) {
+        match node {
+            ::Node::Heading { .. } => break,
+            ::Node::Template {
+                name, parameters, ..
+            } => if let Some(name) = ::parse_text(name) {
+                if ::category::parse_category(context, node, &name, parameters) {
+                    node_index += 1;
+                    continue;
+                }
+            },
+            ::Node::UnorderedList { items, .. } => {
+                node_index += 1;
+                for item in items {
+                    parse_list_item(context, &item.nodes, output);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        node_index += 1;
+        ::add_warning(context, node, ::WarningMessage::Supplementary);
+    }
+    node_index
+}
+
+// Recognizes reference templates, such as `R:Webster 1913`, and templates linking to a sister project.
+fn is_resource_template(name: &str) -> bool {
+    name.starts_with("R:")
+        || matches!(
+            name,
+            "pedia" | "projectlink" | "wikipedia" | "wikisource" | "wikispecies"
+        )
+}
+
+fn parse_list_item<'a>(
+    context: &mut ::Context<'a, '_>,
+    nodes: &[::Node<'a>],
+    output: &mut Vec<::Resource<'a>>,
+) {
+    for node in nodes {
+        match node {
+            ::Node::ExternalLink {
+                nodes: link_nodes, ..
+            } => {
+                if let ::Flowing::ExternalLink { text, url } =
+                    ::flowing::parse_external_link(context, node, link_nodes)
+                {
+                    output.push(::Resource::ExternalLink { text, url });
+                }
+                continue;
+            }
+            ::Node::Template {
+                name, parameters, ..
+            } => if let Some(name) = ::parse_text(name) {
+                if is_resource_template(&name) {
+                    if let Some(template) = ::template::parse_template(context, node, name, parameters) {
+                        output.push(::Resource::Template { template });
+                    }
+                    continue;
+                }
+            },
+            _ => {}
+        }
+        ::add_warning(context, node, ::WarningMessage::Supplementary);
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+pub fn parse_root<'a>(
+    context: &mut ::Context<'a, '_>,
+    heading_node: &::Node,
+    nodes: &[::Node<'a>],
+    output: &mut Vec<::Cow<'a, str>>,
+) -> usize {
+    if !output.is_empty() {
+        ::add_warning(context, heading_node, ::WarningMessage::Duplicate);
+    }
+    let mut has_root = false;
+    let mut node_index = 0;
+    while let Some(node) = nodes.get(node_index) {
+        match node {
+            ::Node::Heading { .. } => break,
+            ::Node::Template {
+                name, parameters, ..
+            } => if let Some(name) = ::parse_text(name) {
+                if check_template_name(context.language.unwrap(), &name) {
+                    node_index += 1;
+                    if has_root {
+                        ::add_warning(context, node, ::WarningMessage::Duplicate);
+                    } else {
+                        has_root = true;
+                        output.clear();
+                        parse_radicals(context, node, parameters, output);
+                    }
+                    continue;
+                }
+            },
+            _ => {}
+        }
+        node_index += 1;
+        ::add_warning(context, node, ::WarningMessage::Unrecognized);
+    }
+    if !has_root {
+        ::add_warning(context, heading_node, ::WarningMessage::SectionEmpty);
+    }
+    node_index
+}
+
+fn check_template_name(language: ::Language, template_name: &str) -> bool {
+    matches!(
+        (language, template_name),
+        (::Language::Ar, "ar-root") | (::Language::He, "HE root") | (::Language::He, "he-root")
+    )
+}
+
+fn parse_radicals<'a>(
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
+    parameters: &[::Parameter<'a>],
+    output: &mut Vec<::Cow<'a, str>>,
+) {
+    for parameter in parameters {
+        if parameter.name.is_some() {
+            ::add_warning(context, parameter, ::WarningMessage::Unrecognized);
+            continue;
+        }
+        match ::parse_text_not_empty(&parameter.value) {
+            None => ::add_warning(context, parameter, ::WarningMessage::ValueUnrecognized),
+            Some(radical) => output.push(radical),
+        }
+    }
+    // The radicals may also be given in a single parameter, separated by spaces or hyphens, as in `כ־ת־ב`.
+    if output.len() == 1 {
+        match output.pop().unwrap() {
+            ::Cow::Borrowed(radicals) => {
+                output.extend(split_radicals(radicals).map(::Cow::Borrowed))
+            }
+            ::Cow::Owned(radicals) => output.extend(
+                split_radicals(&radicals).map(|radical| ::Cow::Owned(radical.to_string())),
+            ),
+        }
+    }
+    if output.is_empty() {
+        ::add_warning(context, template_node, ::WarningMessage::ValueUnrecognized);
+    }
+}
+
+fn split_radicals(radicals: &str) -> impl Iterator<Item = &str> {
+    radicals
+        .split(|character: char| character.is_whitespace() || "-־".contains(character))
+        .filter(|radical| !radical.is_empty())
+}
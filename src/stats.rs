@@ -0,0 +1,302 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+pub fn is_output_too_large(context: &::Context) -> bool {
+    context
+        .max_elements
+        .is_some_and(|limit| context.stats.elements > limit)
+        || context
+            .max_text_bytes
+            .is_some_and(|limit| context.stats.text_bytes > limit)
+}
+
+pub fn measure_language_entry(stats: &mut ::Stats, language_entry: &::LanguageEntry) {
+    stats.elements += 1;
+    for category in &language_entry.categories {
+        stats.elements += 1;
+        stats.text_bytes += category.name.len();
+    }
+    measure_etymology_entry(stats, &language_entry.etymology_entry);
+    if let Some(forms) = &language_entry.forms {
+        measure_template(stats, forms);
+    }
+    if let Some(language_code) = &language_entry.language_code {
+        stats.text_bytes += language_code.len();
+    }
+    if let Some(language_name) = &language_entry.language_name {
+        stats.text_bytes += language_name.len();
+    }
+    if let Some(glyph_origin) = &language_entry.glyph_origin {
+        measure_flowing(stats, glyph_origin);
+    }
+    for etymology_entry in &language_entry.etymology_entries {
+        measure_etymology_entry(stats, etymology_entry);
+    }
+    for resource in &language_entry.resources {
+        stats.elements += 1;
+        match resource {
+            ::Resource::ExternalLink { text, url } => {
+                stats.text_bytes += url.len();
+                measure_flowing(stats, text);
+            }
+            ::Resource::Template { template } => measure_template(stats, template),
+        }
+    }
+}
+
+pub fn measure_pos_entry(stats: &mut ::Stats, pos_entry: &::PosEntry) {
+    stats.elements += 1;
+    measure_texts(stats, &pos_entry.alternative_form_of);
+    measure_texts(stats, &pos_entry.comparatives);
+    for form in &pos_entry.conjugation {
+        stats.elements += 1;
+        stats.text_bytes += form.form.len();
+    }
+    for form in &pos_entry.declension {
+        stats.elements += 1;
+        stats.text_bytes += form.form.len();
+    }
+    measure_texts(stats, &pos_entry.diminutives);
+    stats.elements += pos_entry.genders.len();
+    measure_texts(stats, &pos_entry.genitives);
+    for definition in &pos_entry.definitions {
+        measure_definition(stats, definition);
+    }
+    if let Some(head) = &pos_entry.head {
+        measure_template(stats, head);
+    }
+    for template in &pos_entry.inflection {
+        measure_template(stats, template);
+    }
+    for table in &pos_entry.inflection_tables {
+        stats.elements += 1;
+        measure_table(stats, &table.captions, &table.rows);
+    }
+    if let Some(mutation) = &pos_entry.mutation {
+        measure_template(stats, mutation);
+    }
+    measure_texts(stats, &pos_entry.plurals);
+    if let Some(headword) = &pos_entry.headword {
+        stats.text_bytes += headword.len();
+    }
+    if let Some(pos_name) = &pos_entry.pos_name {
+        stats.text_bytes += pos_name.len();
+    }
+    if let Some(readings) = &pos_entry.readings {
+        measure_template(stats, readings);
+    }
+    for declension in &pos_entry.russian_declensions {
+        stats.elements += 1;
+        for text in &[
+            &declension.declension_type,
+            &declension.lemma,
+            &declension.stress_pattern,
+        ] {
+            stats.text_bytes += text.as_ref().map_or(0, |text| text.len());
+        }
+    }
+    measure_texts(stats, &pos_entry.superlatives);
+    if let Some(usage_notes) = &pos_entry.usage_notes {
+        measure_flowing(stats, usage_notes);
+    }
+    if let Some(verb_forms) = &pos_entry.verb_forms {
+        stats.elements += 1;
+        for form in &[
+            &verb_forms.past_participle,
+            &verb_forms.past_tense,
+            &verb_forms.present_participle,
+            &verb_forms.third_person_singular,
+        ] {
+            stats.text_bytes += form.as_ref().map_or(0, |form| form.len());
+        }
+    }
+}
+
+fn measure_definition(stats: &mut ::Stats, definition: &::Definition) {
+    stats.elements += 1;
+    measure_flowing(stats, &definition.definition);
+    for definition in &definition.definitions {
+        measure_definition(stats, definition);
+    }
+}
+
+fn measure_etymology_entry(stats: &mut ::Stats, etymology_entry: &::EtymologyEntry) {
+    stats.elements += 1;
+    for cognate in &etymology_entry.cognates {
+        stats.elements += 1;
+        stats.text_bytes += cognate.gloss.as_ref().map_or(0, |gloss| gloss.len())
+            + cognate.language.len()
+            + cognate.term.as_ref().map_or(0, |term| term.len());
+    }
+    for derivation in &etymology_entry.derivations {
+        stats.elements += 1;
+        stats.text_bytes += derivation.gloss.as_ref().map_or(0, |gloss| gloss.len())
+            + derivation.language.len()
+            + derivation.term.as_ref().map_or(0, |term| term.len());
+    }
+    measure_texts(stats, &etymology_entry.doublets);
+    if let Some(etymology) = &etymology_entry.etymology {
+        measure_flowing(stats, etymology);
+    }
+    measure_texts(stats, &etymology_entry.pie_roots);
+    measure_texts(stats, &etymology_entry.root);
+    if let Some(pronunciation) = &etymology_entry.pronunciation {
+        measure_pronunciation(stats, pronunciation);
+    }
+    for word_formation in &etymology_entry.word_formations {
+        stats.elements += 1;
+        for morpheme in &word_formation.morphemes {
+            stats.elements += 1;
+            stats.text_bytes += morpheme.gloss.as_ref().map_or(0, |gloss| gloss.len())
+                + morpheme.language.len()
+                + morpheme.term.len();
+        }
+    }
+    for pos_entry in &etymology_entry.pos_entries {
+        measure_pos_entry(stats, pos_entry);
+    }
+}
+
+fn measure_flowing(stats: &mut ::Stats, elements: &[::Flowing]) {
+    for element in elements {
+        stats.elements += 1;
+        match element {
+            ::Flowing::Bold
+            | ::Flowing::Italic
+            | ::Flowing::LineBreak
+            | ::Flowing::ParagraphBreak
+            | ::Flowing::Reference => {}
+            ::Flowing::CharacterEntity { character } => stats.text_bytes += character.len_utf8(),
+            ::Flowing::DefinitionDate {
+                obsolescence_value,
+                value,
+                ..
+            } => {
+                stats.text_bytes +=
+                    value.len() + obsolescence_value.as_ref().map_or(0, |value| value.len())
+            }
+            ::Flowing::Text { value }
+            | ::Flowing::Unknown { value } => stats.text_bytes += value.len(),
+            ::Flowing::ExternalLink { text, url } => {
+                stats.text_bytes += url.len();
+                measure_flowing(stats, text);
+            }
+            ::Flowing::Labels { labels, .. } => for label in labels {
+                stats.text_bytes += label.text.len();
+            },
+            ::Flowing::Link { target, text } => stats.text_bytes += target.len() + text.len(),
+            ::Flowing::Media { caption, file } => {
+                stats.text_bytes += file.len();
+                measure_flowing(stats, caption);
+            }
+            ::Flowing::NonGlossDefinition { value }
+            | ::Flowing::SmallCapitals { value }
+            | ::Flowing::Subscript { value }
+            | ::Flowing::Superscript { value } => measure_flowing(stats, value),
+            ::Flowing::Table { captions, rows } => measure_table(stats, captions, rows),
+            ::Flowing::Term {
+                alternative,
+                gloss,
+                id,
+                language,
+                literal,
+                part_of_speech,
+                term,
+                transcription,
+                transliteration,
+            } => {
+                stats.text_bytes += language.len() + term.len();
+                for text in &[
+                    alternative,
+                    gloss,
+                    id,
+                    literal,
+                    part_of_speech,
+                    transcription,
+                    transliteration,
+                ] {
+                    stats.text_bytes += text.as_ref().map_or(0, |text| text.len());
+                }
+            }
+            ::Flowing::UnorderedList { items } => for item in items {
+                measure_flowing(stats, item);
+            },
+            ::Flowing::WikipediaLink {
+                language,
+                target,
+                text,
+            } => {
+                stats.text_bytes += language.as_ref().map_or(0, |language| language.len())
+                    + target.len()
+                    + text.len()
+            }
+        }
+    }
+}
+
+fn measure_pronunciation(stats: &mut ::Stats, pronunciation: &::Pronunciation) {
+    stats.elements += 1;
+    for audio in &pronunciation.audio {
+        stats.elements += 1;
+        measure_texts(stats, &audio.accents);
+        stats.text_bytes += audio.file.len() + audio.caption.as_ref().map_or(0, |caption| caption.len());
+    }
+    measure_transcriptions(stats, &pronunciation.enpr);
+    for homophone in &pronunciation.homophones {
+        stats.elements += 1;
+        measure_texts(stats, &homophone.qualifiers);
+        stats.text_bytes += homophone.term.len();
+    }
+    for syllables in &pronunciation.hyphenation {
+        stats.elements += 1;
+        measure_texts(stats, syllables);
+    }
+    measure_transcriptions(stats, &pronunciation.ipa);
+    measure_transcriptions(stats, &pronunciation.x_sampa);
+    for template in &pronunciation.ipa_templates {
+        measure_template(stats, template);
+    }
+    for rhyme in &pronunciation.rhymes {
+        stats.elements += 1;
+        stats.text_bytes += rhyme.value.len();
+    }
+}
+
+fn measure_table(stats: &mut ::Stats, captions: &[Vec<::Flowing>], rows: &[Vec<::TableCell>]) {
+    for caption in captions {
+        measure_flowing(stats, caption);
+    }
+    for row in rows {
+        for cell in row {
+            stats.elements += 1;
+            measure_flowing(stats, &cell.value);
+        }
+    }
+}
+
+fn measure_template(stats: &mut ::Stats, template: &::Template) {
+    stats.elements += 1;
+    stats.text_bytes += template.name.len();
+    for (name, value) in &template.named_parameters {
+        stats.text_bytes += name.len();
+        measure_flowing(stats, value);
+    }
+    for value in &template.unnamed_parameters {
+        measure_flowing(stats, value);
+    }
+}
+
+fn measure_texts(stats: &mut ::Stats, texts: &[::Cow<str>]) {
+    for text in texts {
+        stats.text_bytes += text.len();
+    }
+}
+
+fn measure_transcriptions(stats: &mut ::Stats, transcriptions: &[::Transcription]) {
+    for transcription in transcriptions {
+        stats.elements += 1;
+        measure_texts(stats, &transcription.accents);
+        stats.text_bytes += transcription.value.len();
+    }
+}
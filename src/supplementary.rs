@@ -2,10 +2,10 @@
 // This is free software distributed under the terms specified in
 // the file LICENSE at the top-level directory of this distribution.
 
-pub fn parse_supplementary(
-    context: &mut ::Context,
+pub fn parse_supplementary<'a>(
+    context: &mut ::Context<'a, '_>,
     heading_node: &::Node,
-    nodes: &[::Node],
+    nodes: &[::Node<'a>],
     output: &mut bool,
 ) -> usize {
     if *output {
@@ -14,8 +14,39 @@ pub fn parse_supplementary(
     *output = true;
     let mut node_index = 0;
     while let Some(node) = nodes.get(node_index) {
-        if let ::Node::Heading { .. } = node {
-            break;
+        match node {
+            ::Node::Heading { .. } => break,
+            ::Node::Template {
+                name, parameters, ..
+            } => if let Some(name) = ::parse_text(name) {
+                if ::category::parse_category(context, node, &name, parameters) {
+                    node_index += 1;
+                    continue;
+                }
+            },
+            _ => {}
+        }
+        node_index += 1;
+        ::add_warning(context, node, ::WarningMessage::Supplementary);
+    }
+    node_index
+}
+
+// Skips a section listed in `ParseOptions::skipped_sections` together with its subsections, treating the content as supplementary.
+pub fn skip_section<'a>(context: &mut ::Context<'a, '_>, heading_level: u8, nodes: &[::Node<'a>]) -> usize {
+    let mut node_index = 0;
+    while let Some(node) = nodes.get(node_index) {
+        match node {
+            ::Node::Heading { level, .. } if *level <= heading_level => break,
+            ::Node::Template {
+                name, parameters, ..
+            } => if let Some(name) = ::parse_text(name) {
+                if ::category::parse_category(context, node, &name, parameters) {
+                    node_index += 1;
+                    continue;
+                }
+            },
+            _ => {}
         }
         node_index += 1;
         ::add_warning(context, node, ::WarningMessage::Supplementary);
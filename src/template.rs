@@ -2,44 +2,60 @@
 // This is free software distributed under the terms specified in
 // the file LICENSE at the top-level directory of this distribution.
 
+use parse_wiki_text::Positioned;
+
 pub fn parse_template<'a>(
-    context: &mut ::Context,
+    context: &mut ::Context<'a, '_>,
+    template_node: &::Node,
     name: ::Cow<'a, str>,
     parameters: &[::Parameter<'a>],
 ) -> Option<::Template<'a>> {
-    let mut named_parameters = ::HashMap::new();
+    let mut named_parameter_ranges = ::BTreeMap::new();
+    let mut named_parameters = ::BTreeMap::new();
+    let mut unnamed_parameter_ranges = vec![];
     let mut unnamed_parameters = vec![];
     for parameter in parameters {
-        macro_rules! warn {
-            ($message:tt) => {{
-                ::add_warning(context, parameter, ::WarningMessage::$message);
-                return None;
-            }};
-        }
         match parameter.name {
-            None => match ::parse_text(&parameter.value) {
-                None => warn!(ValueUnrecognized),
-                Some(value) => unnamed_parameters.push(value),
-            },
+            None => {
+                unnamed_parameter_ranges.push(parameter.start..parameter.end);
+                unnamed_parameters.push(::flowing::parse_flowing(context, &parameter.value));
+            }
             Some(_) => match ::parse_parameter_name(parameter) {
-                None => warn!(Unrecognized),
+                None => {
+                    ::add_warning(context, parameter, ::WarningMessage::Unrecognized);
+                    return None;
+                }
                 Some(name) => {
                     if named_parameters.contains_key(name) {
                         ::add_warning(context, parameter, ::WarningMessage::Duplicate);
                     }
-                    match ::parse_text(&parameter.value) {
-                        None => warn!(ValueUnrecognized),
-                        Some(value) => {
-                            named_parameters.insert(::Cow::Borrowed(name), value);
-                        }
-                    }
+                    named_parameter_ranges
+                        .insert(::Cow::Borrowed(name), parameter.start..parameter.end);
+                    named_parameters.insert(
+                        ::Cow::Borrowed(name),
+                        ::flowing::parse_flowing(context, &parameter.value),
+                    );
                 }
             },
         }
     }
     Some(::Template {
+        end: template_node.end(),
         name,
+        named_parameter_ranges,
         named_parameters,
+        start: template_node.start(),
+        unnamed_parameter_ranges,
         unnamed_parameters,
     })
 }
+
+impl<'a> Positioned for ::Template<'a> {
+    fn end(&self) -> usize {
+        self.end
+    }
+
+    fn start(&self) -> usize {
+        self.start
+    }
+}
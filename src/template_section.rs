@@ -0,0 +1,48 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+// Parses a section that consists of a single template specific to the language of the entry, whose name is the language code followed by the given suffix, such as `ja-readings` or `cy-mut`.
+pub fn parse_template_section<'a>(
+    context: &mut ::Context<'a, '_>,
+    heading_node: &::Node,
+    nodes: &[::Node<'a>],
+    output: &mut Option<::Template<'a>>,
+    template_suffix: &str,
+) -> usize {
+    if output.is_some() {
+        ::add_warning(context, heading_node, ::WarningMessage::Duplicate);
+    }
+    let mut template = None;
+    let mut node_index = 0;
+    while let Some(node) = nodes.get(node_index) {
+        match node {
+            ::Node::Heading { .. } => break,
+            ::Node::Template {
+                name, parameters, ..
+            } => if let Some(name) = ::parse_text(name) {
+                if name.strip_suffix(template_suffix) == Some(::language_code(context)) {
+                    node_index += 1;
+                    if template.is_some() {
+                        template = Some(None);
+                        ::add_warning(context, node, ::WarningMessage::Duplicate);
+                    } else {
+                        template =
+                            Some(::template::parse_template(context, node, name, parameters));
+                    }
+                    continue;
+                }
+            },
+            _ => {}
+        }
+        node_index += 1;
+        ::add_warning(context, node, ::WarningMessage::Unrecognized);
+    }
+    match template {
+        None => ::add_warning(context, heading_node, ::WarningMessage::SectionEmpty),
+        Some(template) => if template.is_some() {
+            *output = template;
+        },
+    }
+    node_index
+}
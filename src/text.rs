@@ -0,0 +1,78 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+pub fn gloss_text(definition: &::Definition) -> String {
+    let mut output = String::new();
+    push_text(&mut output, &definition.definition, false);
+    output.trim().to_string()
+}
+
+pub fn plain_text<'a>(elements: &[::Flowing<'a>]) -> Option<::Cow<'a, str>> {
+    match elements {
+        [] => Some(::Cow::Borrowed("")),
+        [::Flowing::Text { value }] => Some(value.clone()),
+        _ => elements
+            .iter()
+            .map(|element| match element {
+                ::Flowing::CharacterEntity { character } => Some(character.to_string()),
+                ::Flowing::Text { value } => Some(value.to_string()),
+                _ => None,
+            })
+            .collect::<Option<String>>()
+            .map(::Cow::Owned),
+    }
+}
+
+/// Converts flowing text into plain text for display.
+///
+/// Formatting is removed, links are replaced by their text, terms by the term itself and labels by the labels within parentheses, such as `(informal, dated)`. Definition dates, references, media, tables, lists and elements that could not be recognized are left out. Whitespace at the start and end is removed.
+pub fn render_plain(elements: &[::Flowing]) -> String {
+    let mut output = String::new();
+    push_text(&mut output, elements, true);
+    output.trim().to_string()
+}
+
+fn push_text(output: &mut String, elements: &[::Flowing], include_labels: bool) {
+    for element in elements {
+        match element {
+            ::Flowing::Bold
+            | ::Flowing::DefinitionDate { .. }
+            | ::Flowing::Italic
+            | ::Flowing::Media { .. }
+            | ::Flowing::Reference
+            | ::Flowing::Table { .. }
+            | ::Flowing::Unknown { .. }
+            | ::Flowing::UnorderedList { .. } => {}
+            ::Flowing::CharacterEntity { character } => output.push(*character),
+            ::Flowing::ExternalLink { text, .. } => push_text(output, text, include_labels),
+            ::Flowing::Labels {
+                disjunctive,
+                labels,
+            } => if include_labels && !labels.is_empty() {
+                let separator = if *disjunctive { " or " } else { ", " };
+                output.push('(');
+                for (index, label) in labels.iter().enumerate() {
+                    if index > 0 {
+                        output.push_str(separator);
+                    }
+                    output.push_str(&label.text);
+                }
+                output.push(')');
+            },
+            ::Flowing::LineBreak => output.push('\n'),
+            ::Flowing::Link { text, .. } | ::Flowing::WikipediaLink { text, .. } => {
+                output.push_str(text)
+            }
+            ::Flowing::NonGlossDefinition { value }
+            | ::Flowing::SmallCapitals { value }
+            | ::Flowing::Subscript { value }
+            | ::Flowing::Superscript { value } => push_text(output, value, include_labels),
+            ::Flowing::ParagraphBreak => output.push_str("\n\n"),
+            ::Flowing::Term {
+                alternative, term, ..
+            } => output.push_str(alternative.as_ref().unwrap_or(term)),
+            ::Flowing::Text { value } => output.push_str(value),
+        }
+    }
+}
@@ -3,7 +3,7 @@
 // the file LICENSE at the top-level directory of this distribution.
 
 pub fn parse_usage_notes<'a>(
-    context: &mut ::Context<'a>,
+    context: &mut ::Context<'a, '_>,
     heading_node: &::Node,
     nodes: &[::Node<'a>],
     output: &mut Option<Option<Vec<::Flowing<'a>>>>,
@@ -26,18 +26,48 @@ pub fn parse_usage_notes<'a>(
         match node {
             ::Node::Bold { .. } => push!(::Flowing::Bold),
             ::Node::Heading { .. } => break,
+            ::Node::CharacterEntity { character, .. } => push!(::Flowing::CharacterEntity {
+                character: *character,
+            }),
+            ::Node::Comment { .. } => {
+                node_index += 1;
+                continue;
+            }
+            ::Node::EndTag { name, .. } | ::Node::StartTag { name, .. } if name == "small" => {
+                node_index += 1;
+                continue;
+            }
+            ::Node::ExternalLink { nodes, .. } => {
+                push!(::flowing::parse_external_link(context, node, nodes))
+            }
+            ::Node::Image { target, text, .. } => {
+                push!(::flowing::parse_image(context, target, text))
+            }
             ::Node::Italic { .. } => push!(::Flowing::Italic),
             ::Node::Link { target, text, .. } => push!(::parse_link(context, node, target, text)),
+            ::Node::StartTag { .. } => if let Some((element, length)) =
+                ::flowing::parse_start_tag(context, &nodes[node_index..])
+            {
+                node_index += length - 1;
+                push!(element);
+            },
+            ::Node::Table { captions, rows, .. } => {
+                push!(::flowing::parse_table_element(context, captions, rows))
+            }
             ::Node::Tag { name, .. } if name == "ref" => {
                 ::add_warning(context, node, ::WarningMessage::Supplementary);
                 push!(::Flowing::Reference);
             }
+            ::Node::Tag { name, nodes, .. } if name == "nowiki" => {
+                node_index += 1;
+                usage_notes.extend(::flowing::parse_nowiki(context, node, nodes));
+                continue;
+            }
             ::Node::Template {
                 name, parameters, ..
             } => if let Some(name) = ::parse_text(name) {
-                match &name as _ {
-                    "l" | "m" => push!(parse_template_term(context, node, parameters)),
-                    _ => {}
+                if let Some(element) = ::flowing::parse_template(context, node, &name, parameters) {
+                    push!(element);
                 }
             },
             ::Node::Text { value, .. } => push!(::Flowing::Text {
@@ -52,52 +82,7 @@ pub fn parse_usage_notes<'a>(
                             ::add_warning(context, item, ::WarningMessage::Empty);
                             None
                         } else {
-                            Some(
-                                item.nodes
-                                    .iter()
-                                    .map(|node| {
-                                        match node {
-                                            ::Node::Bold { .. } => return ::Flowing::Bold,
-                                            ::Node::Italic { .. } => return ::Flowing::Italic,
-                                            ::Node::Link { target, text, .. } => {
-                                                return ::parse_link(context, node, target, text)
-                                            }
-                                            ::Node::Tag { name, .. } if name == "ref" => {
-                                                ::add_warning(
-                                                    context,
-                                                    node,
-                                                    ::WarningMessage::Supplementary,
-                                                );
-                                                return ::Flowing::Reference;
-                                            }
-                                            ::Node::Template {
-                                                name, parameters, ..
-                                            } => if let Some(name) = ::parse_text(name) {
-                                                match &name as _ {
-                                                    "l" | "m" => {
-                                                        return parse_template_term(
-                                                            context, node, parameters,
-                                                        )
-                                                    }
-                                                    _ => {}
-                                                }
-                                            },
-                                            ::Node::Text { value, .. } => {
-                                                return ::Flowing::Text {
-                                                    value: ::Cow::Borrowed(value),
-                                                }
-                                            }
-                                            _ => {}
-                                        }
-                                        ::create_unknown(
-                                            context,
-                                            node,
-                                            node,
-                                            ::WarningMessage::Unrecognized,
-                                        )
-                                    })
-                                    .collect(),
-                            )
+                            Some(parse_list_item(context, &item.nodes))
                         }
                     })
                     .collect();
@@ -125,36 +110,74 @@ pub fn parse_usage_notes<'a>(
     node_index
 }
 
-fn parse_template_term<'a>(
-    context: &mut ::Context<'a>,
-    template_node: &::Node,
-    parameters: &[::Parameter<'a>],
-) -> ::Flowing<'a> {
-    match parameters {
-        [language_parameter @ ::Parameter { name: None, .. }, term_parameter @ ::Parameter { name: None, .. }] => {
-            match ::parse_text_not_empty(&language_parameter.value) {
-                None => ::create_unknown(
-                    context,
-                    template_node,
-                    language_parameter,
-                    ::WarningMessage::ValueUnrecognized,
-                ),
-                Some(language) => match ::parse_text_not_empty(&term_parameter.value) {
-                    None => ::create_unknown(
-                        context,
-                        template_node,
-                        term_parameter,
-                        ::WarningMessage::ValueUnrecognized,
-                    ),
-                    Some(term) => ::Flowing::Term { language, term },
-                },
+fn parse_list_item<'a>(context: &mut ::Context<'a, '_>, nodes: &[::Node<'a>]) -> Vec<::Flowing<'a>> {
+    let mut node_index = 0;
+    let mut output = vec![];
+    macro_rules! push {
+        ($expression:expr) => {{
+            node_index += 1;
+            output.push($expression);
+            continue;
+        }};
+    }
+    while let Some(node) = nodes.get(node_index) {
+        match node {
+            ::Node::Bold { .. } => push!(::Flowing::Bold),
+            ::Node::CharacterEntity { character, .. } => push!(::Flowing::CharacterEntity {
+                character: *character,
+            }),
+            ::Node::Comment { .. } => {
+                node_index += 1;
+                continue;
+            }
+            ::Node::EndTag { name, .. } | ::Node::StartTag { name, .. } if name == "small" => {
+                node_index += 1;
+                continue;
+            }
+            ::Node::ExternalLink { nodes, .. } => {
+                push!(::flowing::parse_external_link(context, node, nodes))
+            }
+            ::Node::Image { target, text, .. } => {
+                push!(::flowing::parse_image(context, target, text))
+            }
+            ::Node::Italic { .. } => push!(::Flowing::Italic),
+            ::Node::Link { target, text, .. } => push!(::parse_link(context, node, target, text)),
+            ::Node::StartTag { .. } => if let Some((element, length)) =
+                ::flowing::parse_start_tag(context, &nodes[node_index..])
+            {
+                node_index += length - 1;
+                push!(element);
+            },
+            ::Node::Table { captions, rows, .. } => {
+                push!(::flowing::parse_table_element(context, captions, rows))
+            }
+            ::Node::Tag { name, .. } if name == "ref" => {
+                ::add_warning(context, node, ::WarningMessage::Supplementary);
+                push!(::Flowing::Reference);
+            }
+            ::Node::Tag { name, nodes, .. } if name == "nowiki" => {
+                node_index += 1;
+                output.extend(::flowing::parse_nowiki(context, node, nodes));
+                continue;
             }
+            ::Node::Template {
+                name, parameters, ..
+            } => if let Some(name) = ::parse_text(name) {
+                if let Some(element) = ::flowing::parse_template(context, node, &name, parameters) {
+                    push!(element);
+                }
+            },
+            ::Node::Text { value, .. } => push!(::Flowing::Text {
+                value: ::Cow::Borrowed(value)
+            }),
+            _ => {}
         }
-        _ => ::create_unknown(
+        push!(::create_unknown(
             context,
-            template_node,
-            template_node,
-            ::WarningMessage::ValueUnrecognized,
-        ),
+            node,
+            node,
+            ::WarningMessage::Unrecognized,
+        ));
     }
+    output
 }
@@ -3,14 +3,35 @@
 // the file LICENSE at the top-level directory of this distribution.
 
 use parse_wiki_text::Positioned;
+use ArticleVisitor;
 
-pub struct Context<'a> {
+pub struct Context<'a, 'v> {
+    pub categories: Vec<::Category<'a>>,
+    // Whether to not collect warnings at all, when neither the warnings nor the coverage computed from them are part of the output.
+    pub discard_warnings: bool,
     pub language: Option<::Language>,
+    pub max_definition_depth: Option<usize>,
+    pub max_elements: Option<usize>,
+    pub max_text_bytes: Option<usize>,
+    pub parse_unknown_parts_of_speech: bool,
+    pub reconstruction: bool,
+    pub registered_language: Option<::RegisteredLanguage>,
+    pub skipped_sections: Vec<String>,
+    // The size of the output so far, which while parsing a language section includes the parts of speech parsed so far in the section.
+    pub stats: ::Stats,
+    pub strict_head_templates: bool,
+    pub title: Option<String>,
+    // Whether the visitor has returned `Stop`, after which nothing more is parsed.
+    pub visit_stopped: bool,
+    pub visitor: Option<&'v mut dyn ArticleVisitor<'a>>,
     pub warnings: Vec<::Warning>,
     pub wiki_text: &'a str,
 }
 
 pub fn add_warning(context: &mut Context, node: &impl Positioned, message: ::WarningMessage) {
+    if context.discard_warnings {
+        return;
+    }
     // This panics when accidentally making an infinite loop that produces warnings. This sometimes happens during development. In release builds, loops are assumed to already be tested and work properly.
     debug_assert!(context.warnings.len() < 10000);
     context.warnings.push(::Warning {
@@ -21,9 +42,49 @@ pub fn add_warning(context: &mut Context, node: &impl Positioned, message: ::War
     });
 }
 
+// The code of the language of the current section, which for a registered language is the code it was registered with.
+#[must_use]
+pub fn language_code<'a>(context: &'a Context) -> &'a str {
+    match context.registered_language {
+        None => context.language.unwrap().language_code(),
+        Some(ref language) => &language.code,
+    }
+}
+
+#[must_use]
+pub fn is_skipped_section(context: &Context, heading_text: &str) -> bool {
+    context
+        .skipped_sections
+        .iter()
+        .any(|section| section == heading_text)
+}
+
+#[must_use]
+pub fn create_context<'a, 'v>(wiki_text: &'a str, options: &::ParseOptions) -> Context<'a, 'v> {
+    Context {
+        categories: vec![],
+        discard_warnings: false,
+        language: None,
+        max_definition_depth: options.max_definition_depth,
+        max_elements: options.max_elements,
+        max_text_bytes: options.max_text_bytes,
+        parse_unknown_parts_of_speech: options.parse_unknown_parts_of_speech,
+        reconstruction: options.reconstruction,
+        registered_language: None,
+        skipped_sections: options.skipped_sections.clone(),
+        stats: ::Stats::default(),
+        strict_head_templates: options.strict_head_templates,
+        title: options.title.clone(),
+        visit_stopped: false,
+        visitor: None,
+        warnings: vec![],
+        wiki_text,
+    }
+}
+
 #[must_use]
 pub fn create_unknown<'a>(
-    context: &mut Context<'a>,
+    context: &mut Context<'a, '_>,
     unknown_node: &::Node,
     warning_node: &impl Positioned,
     warning_message: ::WarningMessage,
@@ -36,16 +97,34 @@ pub fn create_unknown<'a>(
 
 #[must_use]
 pub fn parse_link<'a>(
-    context: &mut Context<'a>,
+    context: &mut Context<'a, '_>,
     node: &::Node,
     target: &'a str,
     text: &[::Node<'a>],
 ) -> ::Flowing<'a> {
     match parse_text(text) {
         None => create_unknown(context, node, node, ::WarningMessage::Unrecognized),
-        Some(text) => ::Flowing::Link {
-            target: ::Cow::Borrowed(target),
-            text,
+        Some(text) => match target.strip_prefix("w:") {
+            None => ::Flowing::Link {
+                target: ::Cow::Borrowed(target),
+                text,
+            },
+            Some(target) => {
+                let (language, target) = match target.find(':') {
+                    Some(index)
+                        if index > 0
+                            && target[..index].bytes().all(|byte| byte.is_ascii_lowercase()) =>
+                    {
+                        (Some(::Cow::Borrowed(&target[..index])), &target[index + 1..])
+                    }
+                    _ => (None, target),
+                };
+                ::Flowing::WikipediaLink {
+                    language,
+                    target: ::Cow::Borrowed(target),
+                    text,
+                }
+            }
         },
     }
 }
@@ -82,3 +161,15 @@ pub fn parse_text<'a>(nodes: &[::Node<'a>]) -> Option<::Cow<'a, str>> {
 pub fn parse_text_not_empty<'a>(nodes: &[::Node<'a>]) -> Option<::Cow<'a, str>> {
     parse_text(nodes).filter(|text| !text.is_empty())
 }
+
+#[must_use]
+pub fn trim_text(text: ::Cow<str>) -> ::Cow<str> {
+    match text {
+        ::Cow::Borrowed(text) => ::Cow::Borrowed(text.trim()),
+        ::Cow::Owned(text) => if text.trim().len() == text.len() {
+            ::Cow::Owned(text)
+        } else {
+            ::Cow::Owned(text.trim().to_string())
+        },
+    }
+}
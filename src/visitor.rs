@@ -0,0 +1,33 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+// Visits the language entry after its section has been parsed, and stops the parsing if the visitor returns `Stop`.
+pub fn visit_language_entry<'a>(context: &mut ::Context<'a, '_>, language_entry: &::LanguageEntry<'a>) {
+    if let Some(visitor) = &mut context.visitor {
+        if visitor.visit_language_entry(language_entry) == ::Visit::Stop {
+            context.visit_stopped = true;
+        }
+    }
+}
+
+// Visits the entry for a part of speech as soon as it has been parsed, followed by its definitions, and stops the parsing if the visitor returns `Stop`.
+pub fn visit_pos_entry<'a>(context: &mut ::Context<'a, '_>, pos_entry: &::PosEntry<'a>) {
+    if let Some(visitor) = &mut context.visitor {
+        let language = context.language.unwrap();
+        match visitor.visit_pos_entry(language, pos_entry) {
+            ::Visit::Continue => {}
+            ::Visit::Skip => return,
+            ::Visit::Stop => {
+                context.visit_stopped = true;
+                return;
+            }
+        }
+        for definition in &pos_entry.definitions {
+            if visitor.visit_definition(language, pos_entry, definition) == ::Visit::Stop {
+                context.visit_stopped = true;
+                return;
+            }
+        }
+    }
+}
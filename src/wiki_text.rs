@@ -0,0 +1,301 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+pub fn output_wiki_text(output: &::Output) -> String {
+    output
+        .language_entries
+        .iter()
+        .map(language_entry_wiki_text)
+        .collect::<Vec<_>>()
+        .join("\n----\n\n")
+}
+
+// With numbered etymologies, the sections of the unnumbered etymology entry come first, followed by a section for each numbered etymology with the sections of its entry one level further down.
+pub fn language_entry_wiki_text(language_entry: &::LanguageEntry) -> String {
+    let language_code = language_entry
+        .language_code
+        .as_ref()
+        .map_or(language_entry.language.language_code(), |code| code);
+    let mut output = String::new();
+    push_heading(
+        &mut output,
+        2,
+        language_entry
+            .language_name
+            .as_ref()
+            .map_or(language_entry.language.name(), |name| name),
+    );
+    if let Some(glyph_origin) = &language_entry.glyph_origin {
+        push_section(&mut output, 3, "Glyph origin", glyph_origin, language_code);
+    }
+    if let Some(etymology) = &language_entry.etymology_entry.etymology {
+        push_section(&mut output, 3, "Etymology", etymology, language_code);
+    }
+    push_pos_entries(&mut output, 3, &language_entry.etymology_entry, language_code);
+    for (index, etymology_entry) in language_entry.etymology_entries.iter().enumerate() {
+        let heading = format!("Etymology {}", index + 1);
+        match &etymology_entry.etymology {
+            None => push_heading(&mut output, 3, &heading),
+            Some(etymology) => push_section(&mut output, 3, &heading, etymology, language_code),
+        }
+        push_pos_entries(&mut output, 4, etymology_entry, language_code);
+    }
+    output
+}
+
+pub fn template_wiki_text(template: &::Template) -> String {
+    let mut output = String::new();
+    push_template(&mut output, template, "");
+    output
+}
+
+fn push_definitions(
+    output: &mut String,
+    definitions: &[::Definition],
+    prefix: &str,
+    language_code: &str,
+) {
+    for definition in definitions {
+        output.push_str(prefix);
+        output.push(' ');
+        push_flowing(output, &definition.definition, language_code);
+        output.push('\n');
+        push_definitions(output, &definition.definitions, &format!("{}#", prefix), language_code);
+    }
+}
+
+fn push_flowing(output: &mut String, elements: &[::Flowing], language_code: &str) {
+    for element in elements {
+        match element {
+            ::Flowing::Bold => output.push_str("'''"),
+            ::Flowing::CharacterEntity { character } => {
+                output.push_str(&format!("&#{};", *character as u32))
+            }
+            ::Flowing::DefinitionDate {
+                obsolescence_value,
+                value,
+                ..
+            } => {
+                output.push_str("{{defdate|");
+                output.push_str(value);
+                if let Some(obsolescence_value) = obsolescence_value {
+                    output.push('|');
+                    output.push_str(obsolescence_value);
+                }
+                output.push_str("}}");
+            }
+            ::Flowing::ExternalLink { text, url } => {
+                output.push('[');
+                output.push_str(url);
+                if !text.is_empty() {
+                    output.push(' ');
+                    push_flowing(output, text, language_code);
+                }
+                output.push(']');
+            }
+            ::Flowing::Italic => output.push_str("''"),
+            ::Flowing::Labels {
+                disjunctive,
+                labels,
+            } => {
+                output.push_str("{{lb|");
+                output.push_str(language_code);
+                for (index, label) in labels.iter().enumerate() {
+                    if index > 0 && *disjunctive {
+                        output.push_str("|or");
+                    }
+                    output.push('|');
+                    output.push_str(&label.text);
+                }
+                output.push_str("}}");
+            }
+            ::Flowing::LineBreak => output.push_str("<br>"),
+            ::Flowing::Link { target, text } => {
+                output.push_str("[[");
+                output.push_str(target);
+                if text != target {
+                    output.push('|');
+                    output.push_str(text);
+                }
+                output.push_str("]]");
+            }
+            ::Flowing::Media { caption, file } => {
+                output.push_str("[[File:");
+                output.push_str(file);
+                if !caption.is_empty() {
+                    output.push('|');
+                    push_flowing(output, caption, language_code);
+                }
+                output.push_str("]]");
+            }
+            ::Flowing::NonGlossDefinition { value } => {
+                push_wrapped(output, "{{n-g|", value, "}}", language_code)
+            }
+            ::Flowing::ParagraphBreak => output.push_str("\n\n"),
+            // The content of the reference is not kept in the output.
+            ::Flowing::Reference => {}
+            ::Flowing::SmallCapitals { value } => {
+                push_wrapped(output, "{{smallcaps|", value, "}}", language_code)
+            }
+            ::Flowing::Subscript { value } => {
+                push_wrapped(output, "<sub>", value, "</sub>", language_code)
+            }
+            ::Flowing::Superscript { value } => {
+                push_wrapped(output, "<sup>", value, "</sup>", language_code)
+            }
+            ::Flowing::Table { captions, rows } => {
+                if !output.is_empty() && !output.ends_with('\n') {
+                    output.push('\n');
+                }
+                output.push_str("{|\n");
+                for caption in captions {
+                    push_wrapped(output, "|+ ", caption, "\n", language_code);
+                }
+                for row in rows {
+                    output.push_str("|-\n");
+                    for cell in row {
+                        let prefix = if cell.heading { "! " } else { "| " };
+                        push_wrapped(output, prefix, &cell.value, "\n", language_code);
+                    }
+                }
+                output.push_str("|}");
+            }
+            ::Flowing::Term {
+                alternative,
+                gloss,
+                id,
+                language,
+                literal,
+                part_of_speech,
+                term,
+                transcription,
+                transliteration,
+            } => {
+                output.push_str("{{m|");
+                output.push_str(language);
+                output.push('|');
+                output.push_str(term);
+                for (name, value) in &[
+                    ("alt", alternative),
+                    ("t", gloss),
+                    ("tr", transliteration),
+                    ("ts", transcription),
+                    ("pos", part_of_speech),
+                    ("lit", literal),
+                    ("id", id),
+                ] {
+                    if let Some(value) = value {
+                        output.push_str(&format!("|{}={}", name, value));
+                    }
+                }
+                output.push_str("}}");
+            }
+            // A horizontal rule separating language sections is parsed as an unrecognized element at the end of the preceding section, and is written separately between language entries.
+            ::Flowing::Unknown { value } if value.trim() == "----" => {}
+            ::Flowing::Text { value } | ::Flowing::Unknown { value } => output.push_str(value),
+            ::Flowing::UnorderedList { items } => {
+                if !output.is_empty() && !output.ends_with('\n') {
+                    output.push('\n');
+                }
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        output.push('\n');
+                    }
+                    push_wrapped(output, "* ", item, "", language_code);
+                }
+            }
+            ::Flowing::WikipediaLink {
+                language,
+                target,
+                text,
+            } => {
+                output.push_str("[[w:");
+                if let Some(language) = language {
+                    output.push_str(language);
+                    output.push(':');
+                }
+                output.push_str(target);
+                output.push('|');
+                output.push_str(text);
+                output.push_str("]]");
+            }
+        }
+    }
+}
+
+// Headings are preceded by an empty line unless they directly follow another heading.
+fn push_heading(output: &mut String, level: usize, heading: &str) {
+    if !output.is_empty() && !output.ends_with("=\n") {
+        output.push('\n');
+    }
+    let equals_signs = "=".repeat(level);
+    output.push_str(&format!("{}{}{}\n", equals_signs, heading, equals_signs));
+}
+
+fn push_pos_entries(
+    output: &mut String,
+    level: usize,
+    etymology_entry: &::EtymologyEntry,
+    language_code: &str,
+) {
+    for pos_entry in &etymology_entry.pos_entries {
+        push_heading(
+            output,
+            level,
+            match (pos_entry.pos, &pos_entry.pos_name) {
+                (::Pos::Other, Some(pos_name)) => pos_name,
+                (pos, _) => pos.name(),
+            },
+        );
+        if let Some(head) = &pos_entry.head {
+            push_template(output, head, language_code);
+            output.push_str("\n\n");
+        }
+        push_definitions(output, &pos_entry.definitions, "#", language_code);
+        if let Some(usage_notes) = &pos_entry.usage_notes {
+            push_section(output, level + 1, "Usage notes", usage_notes, language_code);
+        }
+    }
+}
+
+fn push_section(
+    output: &mut String,
+    level: usize,
+    heading: &str,
+    elements: &[::Flowing],
+    language_code: &str,
+) {
+    push_heading(output, level, heading);
+    push_flowing(output, elements, language_code);
+    output.push('\n');
+}
+
+// The named parameters are written in order of name, since their original order is not kept in the output.
+fn push_template(output: &mut String, template: &::Template, language_code: &str) {
+    output.push_str("{{");
+    output.push_str(&template.name);
+    for parameter in &template.unnamed_parameters {
+        output.push('|');
+        push_flowing(output, parameter, language_code);
+    }
+    for (name, value) in &template.named_parameters {
+        output.push('|');
+        output.push_str(name);
+        output.push('=');
+        push_flowing(output, value, language_code);
+    }
+    output.push_str("}}");
+}
+
+fn push_wrapped(
+    output: &mut String,
+    start: &str,
+    elements: &[::Flowing],
+    end: &str,
+    language_code: &str,
+) {
+    output.push_str(start);
+    push_flowing(output, elements, language_code);
+    output.push_str(end);
+}
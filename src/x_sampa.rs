@@ -0,0 +1,157 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+/// Symbols of X-SAMPA that differ from IPA and the IPA symbols they correspond to.
+///
+/// Symbols that are the same in both, such as most lowercase letters, are not included.
+const SYMBOLS: &[(&str, &str)] = &[
+    ("\"", "ˈ"),
+    ("%", "ˌ"),
+    ("&", "ɶ"),
+    ("-\\", "‿"),
+    ("1", "ɨ"),
+    ("2", "ø"),
+    ("3", "ɜ"),
+    ("3\\", "ɞ"),
+    ("3`", "ɝ"),
+    ("4", "ɾ"),
+    ("5", "ɫ"),
+    ("6", "ɐ"),
+    ("7", "ɤ"),
+    ("8", "ɵ"),
+    ("9", "œ"),
+    (":", "ː"),
+    (":\\", "ˑ"),
+    ("<\\", "ʢ"),
+    ("=", "\u{329}"),
+    ("=\\", "ǂ"),
+    (">\\", "ʡ"),
+    ("?", "ʔ"),
+    ("?\\", "ʕ"),
+    ("@", "ə"),
+    ("@\\", "ɘ"),
+    ("@`", "ɚ"),
+    ("A", "ɑ"),
+    ("B", "β"),
+    ("B\\", "ʙ"),
+    ("C", "ç"),
+    ("D", "ð"),
+    ("E", "ɛ"),
+    ("F", "ɱ"),
+    ("G", "ɣ"),
+    ("G\\", "ɢ"),
+    ("G\\_<", "ʛ"),
+    ("H", "ɥ"),
+    ("H\\", "ʜ"),
+    ("I", "ɪ"),
+    ("I\\", "ᵻ"),
+    ("J", "ɲ"),
+    ("J\\", "ɟ"),
+    ("J\\_<", "ʄ"),
+    ("K", "ɬ"),
+    ("K\\", "ɮ"),
+    ("L", "ʎ"),
+    ("L\\", "ʟ"),
+    ("M", "ɯ"),
+    ("M\\", "ɰ"),
+    ("N", "ŋ"),
+    ("N\\", "ɴ"),
+    ("O", "ɔ"),
+    ("O\\", "ʘ"),
+    ("P", "ʋ"),
+    ("Q", "ɒ"),
+    ("R", "ʁ"),
+    ("R\\", "ʀ"),
+    ("S", "ʃ"),
+    ("T", "θ"),
+    ("U", "ʊ"),
+    ("U\\", "ᵿ"),
+    ("V", "ʌ"),
+    ("W", "ʍ"),
+    ("X", "χ"),
+    ("X\\", "ħ"),
+    ("Y", "ʏ"),
+    ("Z", "ʒ"),
+    ("_\"", "\u{308}"),
+    ("_+", "\u{31f}"),
+    ("_-", "\u{320}"),
+    ("_0", "\u{325}"),
+    ("_<", "\u{2c2}"),
+    ("_=", "\u{329}"),
+    ("_>", "ʼ"),
+    ("_?\\", "ˤ"),
+    ("_A", "\u{318}"),
+    ("_G", "ˠ"),
+    ("_O", "\u{339}"),
+    ("_X", "\u{306}"),
+    ("_^", "\u{32f}"),
+    ("_c", "\u{31c}"),
+    ("_d", "\u{32a}"),
+    ("_h", "ʰ"),
+    ("_j", "ʲ"),
+    ("_k", "\u{330}"),
+    ("_l", "ˡ"),
+    ("_n", "ⁿ"),
+    ("_o", "\u{31e}"),
+    ("_q", "\u{319}"),
+    ("_r", "\u{31d}"),
+    ("_t", "\u{324}"),
+    ("_w", "ʷ"),
+    ("_x", "\u{33d}"),
+    ("_}", "\u{31a}"),
+    ("_~", "\u{303}"),
+    ("`", "˞"),
+    ("b_<", "ɓ"),
+    ("d`", "ɖ"),
+    ("d_<", "ɗ"),
+    ("g", "ɡ"),
+    ("g_<", "ɠ"),
+    ("h\\", "ɦ"),
+    ("j\\", "ʝ"),
+    ("l\\", "ɺ"),
+    ("l`", "ɭ"),
+    ("n`", "ɳ"),
+    ("p\\", "ɸ"),
+    ("r\\", "ɹ"),
+    ("r\\`", "ɻ"),
+    ("r`", "ɽ"),
+    ("s\\", "ɕ"),
+    ("s`", "ʂ"),
+    ("t`", "ʈ"),
+    ("v\\", "ʋ"),
+    ("x\\", "ɧ"),
+    ("z\\", "ʑ"),
+    ("z`", "ʐ"),
+    ("{", "æ"),
+    ("|\\", "ǀ"),
+    ("|\\|\\", "ǁ"),
+    ("||", "‖"),
+    ("}", "ʉ"),
+    ("~", "\u{303}"),
+];
+
+/// Converts a transcription written in [X-SAMPA](https://en.wikipedia.org/wiki/X-SAMPA) to IPA.
+///
+/// Each symbol is replaced by the longest symbol in X-SAMPA it starts with. Characters that are not part of any symbol that differs from IPA, such as slashes, brackets and most lowercase letters, are kept as they are.
+pub fn x_sampa_to_ipa(x_sampa: &str) -> String {
+    let mut output = String::with_capacity(x_sampa.len());
+    let mut remaining = x_sampa;
+    while let Some(character) = remaining.chars().next() {
+        match SYMBOLS
+            .iter()
+            .filter(|(symbol, _)| remaining.starts_with(symbol))
+            .max_by_key(|(symbol, _)| symbol.len())
+        {
+            None => {
+                output.push(character);
+                remaining = &remaining[character.len_utf8()..];
+            }
+            Some((symbol, ipa)) => {
+                output.push_str(ipa);
+                remaining = &remaining[symbol.len()..];
+            }
+        }
+    }
+    output
+}
@@ -0,0 +1,298 @@
+// Copyright 2018 Fredrik Portström <https://portstrom.com>
+// This is free software distributed under the terms specified in
+// the file LICENSE at the top-level directory of this distribution.
+
+//! Runs every fixture in `tests/fixtures`.
+//!
+//! Each fixture is a file `<name>.wiki` containing the wiki text of a minimal article and a file `<name>.expected` containing the output of parsing it, serialized as JSON. Fields that are empty, absent or `false` are left out, so adding a field to the output doesn't change the expected output of fixtures that don't cover it. Set the environment variable `UPDATE_FIXTURES` to write the expected output files from the current output instead of comparing.
+
+extern crate parse_wiki_text;
+extern crate parse_wiktionary_en;
+extern crate serde_json;
+
+use parse_wiktionary_en::{Definition, Flowing, Output, Resource, TableCell, Template};
+use std::{collections::HashSet, fs, path::Path};
+
+/// Headings recognized by any of the section parsers.
+const HEADINGS: &[&str] = &[
+    "Abbreviation",
+    "Acronym",
+    "Adjective",
+    "Adverb",
+    "Alternative forms",
+    "Anagrams",
+    "Antonyms",
+    "Article",
+    "Circumfix",
+    "Conjugation",
+    "Conjunction",
+    "Declension",
+    "Definitions",
+    "Derived terms",
+    "Descendants",
+    "Diacritical mark",
+    "Etymology",
+    "Etymology 1",
+    "Further reading",
+    "Glyph origin",
+    "Hanja",
+    "Hypernyms",
+    "Hyponyms",
+    "Infix",
+    "Inflection",
+    "Initialism",
+    "Interfix",
+    "Interjection",
+    "Kanji",
+    "Letter",
+    "Mutation",
+    "Noun",
+    "Noun 1",
+    "Numeral",
+    "Participle",
+    "Particle",
+    "Phrase",
+    "Prefix",
+    "Preposition",
+    "Prepositional phrase",
+    "Pronoun",
+    "Pronunciation",
+    "Pronunciation 1",
+    "Proper noun",
+    "Proverb",
+    "Punctuation mark",
+    "Readings",
+    "References",
+    "Related terms",
+    "Romanization",
+    "Root",
+    "Suffix",
+    "Symbol",
+    "Synonyms",
+    "Translations",
+    "Usage notes",
+    "Verb",
+];
+
+fn flowing_variant(element: &Flowing) -> &'static str {
+    match element {
+        Flowing::Bold => "Bold",
+        Flowing::CharacterEntity { .. } => "CharacterEntity",
+        Flowing::DefinitionDate { .. } => "DefinitionDate",
+        Flowing::ExternalLink { .. } => "ExternalLink",
+        Flowing::Italic => "Italic",
+        Flowing::Labels { .. } => "Labels",
+        Flowing::LineBreak => "LineBreak",
+        Flowing::Link { .. } => "Link",
+        Flowing::Media { .. } => "Media",
+        Flowing::NonGlossDefinition { .. } => "NonGlossDefinition",
+        Flowing::ParagraphBreak => "ParagraphBreak",
+        Flowing::Reference => "Reference",
+        Flowing::SmallCapitals { .. } => "SmallCapitals",
+        Flowing::Subscript { .. } => "Subscript",
+        Flowing::Superscript { .. } => "Superscript",
+        Flowing::Table { .. } => "Table",
+        Flowing::Term { .. } => "Term",
+        Flowing::Text { .. } => "Text",
+        Flowing::Unknown { .. } => "Unknown",
+        Flowing::UnorderedList { .. } => "UnorderedList",
+        Flowing::WikipediaLink { .. } => "WikipediaLink",
+    }
+}
+
+/// Every variant of `Flowing`. Keep in sync with `flowing_variant`.
+const FLOWING_VARIANTS: &[&str] = &[
+    "Bold",
+    "CharacterEntity",
+    "DefinitionDate",
+    "ExternalLink",
+    "Italic",
+    "Labels",
+    "LineBreak",
+    "Link",
+    "Media",
+    "NonGlossDefinition",
+    "ParagraphBreak",
+    "Reference",
+    "SmallCapitals",
+    "Subscript",
+    "Superscript",
+    "Table",
+    "Term",
+    "Text",
+    "Unknown",
+    "UnorderedList",
+    "WikipediaLink",
+];
+
+fn visit_definition(definition: &Definition, variants: &mut HashSet<&'static str>) {
+    visit_flowing(&definition.definition, variants);
+    for definition in &definition.definitions {
+        visit_definition(definition, variants);
+    }
+}
+
+fn visit_flowing(elements: &[Flowing], variants: &mut HashSet<&'static str>) {
+    for element in elements {
+        variants.insert(flowing_variant(element));
+        match element {
+            Flowing::ExternalLink { text: value, .. }
+            | Flowing::Media { caption: value, .. }
+            | Flowing::NonGlossDefinition { value }
+            | Flowing::SmallCapitals { value }
+            | Flowing::Subscript { value }
+            | Flowing::Superscript { value } => visit_flowing(value, variants),
+            Flowing::Table { captions, rows } => visit_table(captions, rows, variants),
+            Flowing::UnorderedList { items } => for item in items {
+                visit_flowing(item, variants);
+            },
+            _ => {}
+        }
+    }
+}
+
+fn visit_table(
+    captions: &[Vec<Flowing>],
+    rows: &[Vec<TableCell>],
+    variants: &mut HashSet<&'static str>,
+) {
+    for caption in captions {
+        visit_flowing(caption, variants);
+    }
+    for row in rows {
+        for cell in row {
+            visit_flowing(&cell.value, variants);
+        }
+    }
+}
+
+fn visit_template(template: &Template, variants: &mut HashSet<&'static str>) {
+    for value in template.named_parameters.values() {
+        visit_flowing(value, variants);
+    }
+    for value in &template.unnamed_parameters {
+        visit_flowing(value, variants);
+    }
+}
+
+fn visit_output(output: &Output, variants: &mut HashSet<&'static str>) {
+    for language_entry in &output.language_entries {
+        if let Some(forms) = &language_entry.forms {
+            visit_template(forms, variants);
+        }
+        if let Some(glyph_origin) = &language_entry.glyph_origin {
+            visit_flowing(glyph_origin, variants);
+        }
+        for resource in &language_entry.resources {
+            match resource {
+                Resource::ExternalLink { text, .. } => visit_flowing(text, variants),
+                Resource::Template { template } => visit_template(template, variants),
+            }
+        }
+        for etymology_entry in
+            std::iter::once(&language_entry.etymology_entry).chain(&language_entry.etymology_entries)
+        {
+            if let Some(etymology) = &etymology_entry.etymology {
+                visit_flowing(etymology, variants);
+            }
+            for pos_entry in &etymology_entry.pos_entries {
+                for definition in &pos_entry.definitions {
+                    visit_definition(definition, variants);
+                }
+                if let Some(head) = &pos_entry.head {
+                    visit_template(head, variants);
+                }
+                for template in &pos_entry.inflection {
+                    visit_template(template, variants);
+                }
+                for table in &pos_entry.inflection_tables {
+                    visit_table(&table.captions, &table.rows, variants);
+                }
+                if let Some(mutation) = &pos_entry.mutation {
+                    visit_template(mutation, variants);
+                }
+                if let Some(readings) = &pos_entry.readings {
+                    visit_template(readings, variants);
+                }
+                if let Some(usage_notes) = &pos_entry.usage_notes {
+                    visit_flowing(usage_notes, variants);
+                }
+            }
+        }
+    }
+}
+
+/// Removes the fields that are `false`, which serialization keeps unlike empty and absent fields.
+fn remove_false_fields(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Array(values) => for value in values {
+            remove_false_fields(value);
+        },
+        serde_json::Value::Object(fields) => {
+            fields.retain(|_, value| *value != serde_json::Value::Bool(false));
+            for value in fields.values_mut() {
+                remove_false_fields(value);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[test]
+fn fixtures() {
+    let configuration = parse_wiktionary_en::create_configuration();
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let update = std::env::var_os("UPDATE_FIXTURES").is_some();
+    let mut failures = vec![];
+    let mut headings = HashSet::new();
+    let mut paths: Vec<_> = fs::read_dir(&directory)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "wiki"))
+        .collect();
+    paths.sort();
+    assert!(!paths.is_empty());
+    let mut variants = HashSet::new();
+    for path in &paths {
+        let wiki_text = fs::read_to_string(path).unwrap();
+        for heading in HEADINGS {
+            if wiki_text.contains(&format!("={}=", heading)) {
+                headings.insert(*heading);
+            }
+        }
+        let output = parse_wiktionary_en::parse(&wiki_text, &configuration.parse(&wiki_text).nodes);
+        visit_output(&output, &mut variants);
+        let mut value = serde_json::to_value(&output).unwrap();
+        remove_false_fields(&mut value);
+        let actual = serde_json::to_string_pretty(&value).unwrap() + "\n";
+        let expected_path = path.with_extension("expected");
+        if update {
+            fs::write(&expected_path, &actual).unwrap();
+        } else if fs::read_to_string(&expected_path).ok().as_ref() != Some(&actual) {
+            failures.push(path.file_name().unwrap().to_string_lossy().into_owned());
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "fixtures with unexpected output: {:?}",
+        failures
+    );
+    let missing_headings: Vec<_> = HEADINGS
+        .iter()
+        .filter(|heading| !headings.contains(*heading))
+        .collect();
+    assert!(
+        missing_headings.is_empty(),
+        "headings without fixtures: {:?}",
+        missing_headings
+    );
+    let missing_variants: Vec<_> = FLOWING_VARIANTS
+        .iter()
+        .filter(|variant| !variants.contains(*variant))
+        .collect();
+    assert!(
+        missing_variants.is_empty(),
+        "flowing variants without fixtures: {:?}",
+        missing_variants
+    );
+}
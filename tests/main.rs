@@ -4,6 +4,10 @@
 
 extern crate parse_wiki_text;
 extern crate parse_wiktionary_en;
+extern crate serde;
+
+use serde::{de::IntoDeserializer, Deserialize};
+use std::fs;
 
 #[test]
 fn main() {
@@ -12,3 +16,1070 @@ fn main() {
         &parse_wiktionary_en::create_configuration().parse("").nodes,
     );
 }
+
+#[test]
+fn into_owned() {
+    let (debug, stats, output) = {
+        let wiki_text = fs::read_to_string("tests/fixtures/inflection.wiki").unwrap();
+        let nodes = parse_wiktionary_en::create_configuration()
+            .parse(&wiki_text)
+            .nodes;
+        let output = parse_wiktionary_en::parse(&wiki_text, &nodes);
+        (format!("{:?}", output), output.stats(), output.into_owned())
+    };
+    assert_eq!(format!("{:?}", output), debug);
+    assert_eq!(output.stats(), stats);
+}
+
+#[test]
+fn reexported_parse_wiki_text() {
+    let wiki_text = "==English==\n===Noun===\n# A word.";
+    let configuration: parse_wiktionary_en::parse_wiki_text::Configuration =
+        parse_wiktionary_en::create_configuration();
+    let nodes: Vec<parse_wiktionary_en::parse_wiki_text::Node> =
+        configuration.parse(wiki_text).nodes;
+    let output = parse_wiktionary_en::parse(wiki_text, &nodes);
+    assert_eq!(output.language_entries.len(), 1);
+}
+
+#[test]
+fn visitor() {
+    struct Visitor(Vec<String>);
+
+    impl<'a> parse_wiktionary_en::ArticleVisitor<'a> for Visitor {
+        fn visit_language_entry(
+            &mut self,
+            language_entry: &parse_wiktionary_en::LanguageEntry<'a>,
+        ) -> parse_wiktionary_en::Visit {
+            self.0.push(language_entry.language.name().to_string());
+            parse_wiktionary_en::Visit::Continue
+        }
+
+        fn visit_pos_entry(
+            &mut self,
+            language: parse_wiktionary_en::Language,
+            pos_entry: &parse_wiktionary_en::PosEntry<'a>,
+        ) -> parse_wiktionary_en::Visit {
+            self.0.push(pos_entry.pos.name().to_string());
+            match language {
+                parse_wiktionary_en::Language::En => parse_wiktionary_en::Visit::Skip,
+                _ => parse_wiktionary_en::Visit::Continue,
+            }
+        }
+
+        fn visit_definition(
+            &mut self,
+            _language: parse_wiktionary_en::Language,
+            _pos_entry: &parse_wiktionary_en::PosEntry<'a>,
+            definition: &parse_wiktionary_en::Definition<'a>,
+        ) -> parse_wiktionary_en::Visit {
+            self.0.push(definition.gloss_text());
+            parse_wiktionary_en::Visit::Stop
+        }
+    }
+
+    let wiki_text = "==English==\n===Noun===\n# A word.\n==French==\n===Verb===\n# A verb.\n# Another verb.\n===Noun===\n# A noun.\n==German==\n===Noun===\n# A noun.";
+    let nodes = parse_wiktionary_en::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let mut visitor = Visitor(vec![]);
+    parse_wiktionary_en::parse_with_visitor(wiki_text, &nodes, &Default::default(), &mut visitor);
+    assert_eq!(visitor.0, ["Noun", "English", "Verb", "A verb."]);
+}
+
+#[test]
+fn visitor_borrowing_wiki_text() {
+    struct Visitor<'a>(Vec<std::borrow::Cow<'a, str>>);
+
+    impl<'a> parse_wiktionary_en::ArticleVisitor<'a> for Visitor<'a> {
+        fn visit_definition(
+            &mut self,
+            _language: parse_wiktionary_en::Language,
+            _pos_entry: &parse_wiktionary_en::PosEntry<'a>,
+            definition: &parse_wiktionary_en::Definition<'a>,
+        ) -> parse_wiktionary_en::Visit {
+            for element in &definition.definition {
+                if let parse_wiktionary_en::Flowing::Text { value } = element {
+                    self.0.push(value.clone());
+                }
+            }
+            parse_wiktionary_en::Visit::Continue
+        }
+    }
+
+    let wiki_text = "==English==\n===Noun===\n# A word.\n# Another word.";
+    let nodes = parse_wiktionary_en::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let mut visitor = Visitor(vec![]);
+    parse_wiktionary_en::parse_with_visitor(wiki_text, &nodes, &Default::default(), &mut visitor);
+    assert_eq!(visitor.0, ["A word.", "Another word."]);
+    assert!(visitor
+        .0
+        .iter()
+        .all(|text| matches!(text, std::borrow::Cow::Borrowed(_))));
+}
+
+#[test]
+fn definitions() {
+    let wiki_text = "==English==\n===Noun===\n# A word.\n==French==\n===Etymology 1===\n====Verb====\n# A verb.\n# Another verb.\n===Etymology 2===\n====Noun====\n# A noun.";
+    let nodes = parse_wiktionary_en::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let output = parse_wiktionary_en::parse(wiki_text, &nodes);
+    let pos: Vec<_> = output.language_entries[1]
+        .pos_entries()
+        .map(|pos_entry| pos_entry.pos)
+        .collect();
+    assert_eq!(pos, [parse_wiktionary_en::Pos::Verb, parse_wiktionary_en::Pos::Noun]);
+    let definitions: Vec<_> = output
+        .definitions()
+        .map(|(language_entry, pos_entry, definition)| {
+            (language_entry.language, pos_entry.pos, definition.gloss_text())
+        })
+        .collect();
+    assert_eq!(
+        definitions,
+        [
+            (parse_wiktionary_en::Language::En, parse_wiktionary_en::Pos::Noun, "A word.".to_string()),
+            (parse_wiktionary_en::Language::Fr, parse_wiktionary_en::Pos::Verb, "A verb.".to_string()),
+            (parse_wiktionary_en::Language::Fr, parse_wiktionary_en::Pos::Verb, "Another verb.".to_string()),
+            (parse_wiktionary_en::Language::Fr, parse_wiktionary_en::Pos::Noun, "A noun.".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn lookup() {
+    let wiki_text = "==English==\n===Etymology 1===\n====Noun====\n# A word.\n===Etymology 2===\n====Verb====\n# To word.\n====Noun====\n# Another word.\n==German==\n===Noun===\n# Ein Wort.";
+    let nodes = parse_wiktionary_en::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let output = parse_wiktionary_en::parse(wiki_text, &nodes);
+    let language_entry = output.language(parse_wiktionary_en::Language::De).unwrap();
+    assert_eq!(language_entry.language, parse_wiktionary_en::Language::De);
+    assert!(output.language(parse_wiktionary_en::Language::Fr).is_none());
+    let glosses: Vec<_> = output
+        .entry(parse_wiktionary_en::Language::En, parse_wiktionary_en::Pos::Noun)
+        .map(|pos_entry| pos_entry.definitions[0].gloss_text())
+        .collect();
+    assert_eq!(glosses, ["A word.", "Another word."]);
+    assert_eq!(
+        output
+            .entry(parse_wiktionary_en::Language::De, parse_wiktionary_en::Pos::Verb)
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn parse_language_only() {
+    let wiki_text = "==English==\n===Noun===\n# A word.\n==German==\n===Noun===\n# Ein Wort.\n===Verb===\n# Ein Verb.\n==German==\n===Adjective===\n# Ein Adjektiv.";
+    let nodes = parse_wiktionary_en::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let language_entry =
+        parse_wiktionary_en::parse_language_only(wiki_text, &nodes, parse_wiktionary_en::Language::De)
+            .unwrap();
+    assert_eq!(language_entry.language, parse_wiktionary_en::Language::De);
+    let pos: Vec<_> = language_entry
+        .pos_entries()
+        .map(|pos_entry| pos_entry.pos)
+        .collect();
+    assert_eq!(pos, [parse_wiktionary_en::Pos::Noun, parse_wiktionary_en::Pos::Verb]);
+    assert!(parse_wiktionary_en::parse_language_only(
+        wiki_text,
+        &nodes,
+        parse_wiktionary_en::Language::Fr
+    )
+    .is_none());
+    let wiki_text = "==Serbian==\n===Noun===\n# A word.";
+    let nodes = parse_wiktionary_en::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    assert!(parse_wiktionary_en::parse_language_only(
+        wiki_text,
+        &nodes,
+        parse_wiktionary_en::Language::Sh
+    )
+    .is_some());
+}
+
+#[test]
+fn section_entry_points() {
+    let wiki_text = "===Noun===\n{{en-noun}}\n\n# {{lb|en|informal}} A word.\n## A sense.\n\n====Usage notes====\n* A note.";
+    let nodes = parse_wiktionary_en::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let pos_entry =
+        parse_wiktionary_en::parse_pos_section(wiki_text, &nodes, parse_wiktionary_en::Language::En)
+            .unwrap();
+    assert_eq!(pos_entry.pos, parse_wiktionary_en::Pos::Noun);
+    assert_eq!(pos_entry.head.as_ref().unwrap().name, "en-noun");
+    assert_eq!(pos_entry.definitions[0].definitions.len(), 1);
+    assert!(pos_entry.usage_notes.is_some());
+    let definitions =
+        parse_wiktionary_en::parse_definition_list(wiki_text, &nodes, parse_wiktionary_en::Language::En);
+    assert_eq!(definitions.len(), 1);
+    assert_eq!(
+        parse_wiktionary_en::render_plain(&definitions[0].definition),
+        "(informal) A word."
+    );
+    let wiki_text = "===Etymology===\nFrom Latin.";
+    let nodes = parse_wiktionary_en::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    assert!(
+        parse_wiktionary_en::parse_pos_section(wiki_text, &nodes, parse_wiktionary_en::Language::En)
+            .is_none()
+    );
+    assert!(
+        parse_wiktionary_en::parse_definition_list(wiki_text, &nodes, parse_wiktionary_en::Language::En)
+            .is_empty()
+    );
+}
+
+#[test]
+fn output_size_limit() {
+    let wiki_text = "==English==\n===Noun===\n# A word.\n==German==\n===Noun===\n# Ein Wort.";
+    let nodes = parse_wiktionary_en::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let output = parse_wiktionary_en::parse(wiki_text, &nodes);
+    assert_eq!(output.language_entries.len(), 2);
+    let limit = output.stats().elements - 1;
+    let output = parse_wiktionary_en::parse_with_options(
+        wiki_text,
+        &nodes,
+        &parse_wiktionary_en::ParseOptions {
+            max_elements: Some(limit),
+            ..Default::default()
+        },
+    );
+    assert_eq!(output.language_entries.len(), 1);
+    assert_eq!(
+        output.warnings.last().unwrap().message,
+        parse_wiktionary_en::WarningMessage::OutputTooLarge
+    );
+    let wiki_text = "==English==\n===Noun===\n# A word.\n===Verb===\n# To word.\n===Adjective===\n# Wordy.\n\nStray text.";
+    let nodes = parse_wiktionary_en::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let stats = parse_wiktionary_en::parse(wiki_text, &nodes).stats();
+    let output = parse_wiktionary_en::parse_with_options(
+        wiki_text,
+        &nodes,
+        &parse_wiktionary_en::ParseOptions::default().max_elements(1),
+    );
+    assert!(output.language_entries.is_empty());
+    assert!(output.stats().elements < stats.elements);
+    assert_eq!(output.warnings.len(), 1);
+    assert_eq!(
+        output.warnings[0].message,
+        parse_wiktionary_en::WarningMessage::OutputTooLarge
+    );
+}
+
+#[test]
+fn merge_duplicate_languages() {
+    let wiki_text = "==English==\n===Noun===\n# A word.\n==English==\n===Verb===\n# To word.";
+    let nodes = parse_wiktionary_en::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let output = parse_wiktionary_en::parse(wiki_text, &nodes);
+    assert_eq!(output.language_entries.len(), 2);
+    let output = parse_wiktionary_en::parse_with_options(
+        wiki_text,
+        &nodes,
+        &parse_wiktionary_en::ParseOptions {
+            merge_duplicate_languages: true,
+            ..Default::default()
+        },
+    );
+    assert_eq!(output.language_entries.len(), 1);
+    let pos_entries = &output.language_entries[0].etymology_entry.pos_entries;
+    assert_eq!(pos_entries.len(), 2);
+    assert_eq!(pos_entries[0].pos, parse_wiktionary_en::Pos::Noun);
+    assert_eq!(pos_entries[1].pos, parse_wiktionary_en::Pos::Verb);
+    assert_eq!(
+        output.warnings[0].message,
+        parse_wiktionary_en::WarningMessage::Duplicate
+    );
+}
+
+#[test]
+fn parse_unknown_languages() {
+    let wiki_text = "==Quenya==\n===Noun===\n# A word.\n==Klingon==\n===Noun===\n# A word.";
+    let nodes = parse_wiktionary_en::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let output = parse_wiktionary_en::parse(wiki_text, &nodes);
+    assert_eq!(output.language_entries.len(), 0);
+    assert_eq!(output.skipped_languages.len(), 2);
+    let output = parse_wiktionary_en::parse_with_options(
+        wiki_text,
+        &nodes,
+        &parse_wiktionary_en::ParseOptions {
+            merge_duplicate_languages: true,
+            parse_unknown_languages: true,
+            ..Default::default()
+        },
+    );
+    assert_eq!(output.language_entries.len(), 2);
+    assert!(output.skipped_languages.is_empty());
+    assert!(output.warnings.is_empty());
+    let language_entry = &output.language_entries[1];
+    assert_eq!(language_entry.language, parse_wiktionary_en::Language::Other);
+    assert_eq!(language_entry.language_name.as_ref().unwrap(), "Klingon");
+    assert_eq!(
+        language_entry.etymology_entry.pos_entries[0].pos,
+        parse_wiktionary_en::Pos::Noun
+    );
+}
+
+#[test]
+fn parse_unknown_parts_of_speech() {
+    let wiki_text = "==Chinese==\n===Classifier===\n{{head|zh|classifier}}\n\n# For flat objects.\n\n===Quotations===\n* A quotation.";
+    let nodes = parse_wiktionary_en::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let output = parse_wiktionary_en::parse(wiki_text, &nodes);
+    assert!(output.language_entries[0].etymology_entry.pos_entries.is_empty());
+    let output = parse_wiktionary_en::parse_with_options(
+        wiki_text,
+        &nodes,
+        &parse_wiktionary_en::ParseOptions {
+            parse_unknown_parts_of_speech: true,
+            ..Default::default()
+        },
+    );
+    let pos_entries = &output.language_entries[0].etymology_entry.pos_entries;
+    assert_eq!(pos_entries.len(), 1);
+    assert_eq!(pos_entries[0].pos, parse_wiktionary_en::Pos::Other);
+    assert_eq!(pos_entries[0].pos_name.as_ref().unwrap(), "Classifier");
+    assert_eq!(pos_entries[0].definitions.len(), 1);
+    assert!(pos_entries[0].head.is_some());
+    // The section without definitions is still unrecognized.
+    assert_eq!(output.warnings.len(), 2);
+}
+
+#[test]
+fn registered_languages() {
+    let wiki_text = "==Klingon==\n===Pronunciation===\n* {{IPA|tlh|/tʰaχ/}}\n\n===Noun===\n{{tlh-noun}}\n\n# {{lb|tlh|slang}} A word.";
+    let nodes = parse_wiktionary_en::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let output = parse_wiktionary_en::parse_with_options(
+        wiki_text,
+        &nodes,
+        &parse_wiktionary_en::ParseOptions {
+            registered_languages: vec![parse_wiktionary_en::RegisteredLanguage {
+                code: "tlh".to_string(),
+                head_template_prefixes: vec!["tlh-".to_string()],
+                name: "Klingon".to_string(),
+            }],
+            ..Default::default()
+        },
+    );
+    assert_eq!(output.language_entries.len(), 1);
+    assert!(output.skipped_languages.is_empty());
+    assert!(output.warnings.is_empty());
+    let language_entry = &output.language_entries[0];
+    assert_eq!(language_entry.language, parse_wiktionary_en::Language::Other);
+    assert_eq!(language_entry.language_code.as_ref().unwrap(), "tlh");
+    assert_eq!(language_entry.language_name.as_ref().unwrap(), "Klingon");
+    assert!(language_entry.etymology_entry.pronunciation.is_some());
+    assert_eq!(
+        language_entry.etymology_entry.pos_entries[0].head.as_ref().unwrap().name,
+        "tlh-noun"
+    );
+    assert!(language_entry.to_wiki_text().contains("# {{lb|tlh|slang}} A word.\n"));
+}
+
+#[test]
+fn language_names() {
+    assert_eq!(parse_wiktionary_en::Language::En.name(), "English");
+    assert_eq!(parse_wiktionary_en::Language::InePro.name(), "Proto-Indo-European");
+    assert_eq!(
+        parse_wiktionary_en::Language::ALL.len(),
+        parse_wiktionary_en::Language::iter().count()
+    );
+    for language in parse_wiktionary_en::Language::iter() {
+        assert_ne!(language, parse_wiktionary_en::Language::Other);
+        assert_eq!(parse_wiktionary_en::Language::from_name(language.name()), Some(language));
+    }
+}
+
+#[test]
+fn pos_names() {
+    assert_eq!(parse_wiktionary_en::Pos::ProperNoun.name(), "Proper noun");
+    assert_eq!(
+        parse_wiktionary_en::Pos::from_heading("Proper noun"),
+        Some(parse_wiktionary_en::Pos::ProperNoun)
+    );
+    assert_eq!(parse_wiktionary_en::Pos::from_heading("Noun 2"), None);
+    assert_eq!(parse_wiktionary_en::Pos::from_heading("Other"), None);
+    for pos in &[
+        parse_wiktionary_en::Pos::DiacriticalMark,
+        parse_wiktionary_en::Pos::Noun,
+        parse_wiktionary_en::Pos::Root,
+    ] {
+        assert_eq!(parse_wiktionary_en::Pos::from_heading(pos.name()), Some(*pos));
+    }
+}
+
+#[test]
+fn reconstruction() {
+    let wiki_text = "==Proto-Indo-European==\n===Root===\n{{head|ine-pro|root}}\n\n# to [[turn]]";
+    let nodes = parse_wiktionary_en::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let output = parse_wiktionary_en::parse_with_options(
+        wiki_text,
+        &nodes,
+        &parse_wiktionary_en::ParseOptions {
+            reconstruction: true,
+            ..Default::default()
+        },
+    );
+    assert!(output.warnings.is_empty());
+    let pos_entries = &output.language_entries[0].etymology_entry.pos_entries;
+    assert_eq!(pos_entries[0].pos, parse_wiktionary_en::Pos::Root);
+    let language = parse_wiktionary_en::Language::from_code("gem-pro").unwrap();
+    assert_eq!(language, parse_wiktionary_en::Language::GemPro);
+    assert_eq!(language.page_title("*wulfaz"), "Reconstruction:Proto-Germanic/wulfaz");
+    assert_eq!(parse_wiktionary_en::Language::En.page_title("wolf"), "wolf");
+}
+
+#[test]
+fn language_filter() {
+    let wiki_text = "==English==\n===Noun===\n# A word.\n==German==\n===Noun===\n# A word.\n==Quenya==\n===Noun===\n# A word.";
+    let nodes = parse_wiktionary_en::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let output = parse_wiktionary_en::parse_with_options(
+        wiki_text,
+        &nodes,
+        &parse_wiktionary_en::ParseOptions {
+            languages: Some(vec![parse_wiktionary_en::Language::De]),
+            ..Default::default()
+        },
+    );
+    assert_eq!(output.language_entries.len(), 1);
+    assert_eq!(output.language_entries[0].language, parse_wiktionary_en::Language::De);
+    assert_eq!(output.skipped_languages.len(), 1);
+    assert!(output.warnings.is_empty());
+}
+
+#[test]
+fn parse_options_builder() {
+    let wiki_text = "==Turkish==\n===Noun===\n{{tr-noun}}\n\n# A word.\n## A sense.\n### A subsense.\n\n====Usage notes====\n* A note.\n\n====Synonyms====\n* [[söz]]";
+    let nodes = parse_wiktionary_en::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let output = parse_wiktionary_en::parse(wiki_text, &nodes);
+    let pos_entry = &output.language_entries[0].etymology_entry.pos_entries[0];
+    assert!(pos_entry.head.is_some());
+    assert!(pos_entry.usage_notes.is_some());
+    assert_eq!(pos_entry.definitions[0].definitions[0].definitions.len(), 1);
+    let options = parse_wiktionary_en::ParseOptions::default()
+        .max_definition_depth(1)
+        .skip_section("Usage notes")
+        .skip_section("Synonyms");
+    let output = parse_wiktionary_en::parse_with_options(wiki_text, &nodes, &options);
+    let pos_entry = &output.language_entries[0].etymology_entry.pos_entries[0];
+    assert!(pos_entry.usage_notes.is_none());
+    assert!(!pos_entry.synonyms);
+    assert_eq!(pos_entry.definitions[0].definitions.len(), 1);
+    assert!(pos_entry.definitions[0].definitions[0].definitions.is_empty());
+    assert!(output
+        .warnings
+        .iter()
+        .all(|warning| warning.message == parse_wiktionary_en::WarningMessage::Supplementary));
+    let options = parse_wiktionary_en::ParseOptions::default().strict_head_templates(true);
+    let output = parse_wiktionary_en::parse_with_options(wiki_text, &nodes, &options);
+    assert!(output.language_entries[0].etymology_entry.pos_entries[0].head.is_none());
+    assert_eq!(
+        output.warnings[0].message,
+        parse_wiktionary_en::WarningMessage::Unrecognized
+    );
+    let options = options.discard_warnings(true);
+    let output = parse_wiktionary_en::parse_with_options(wiki_text, &nodes, &options);
+    assert!(output.warnings.is_empty());
+    assert!(output.coverage()[0].unrecognized > 0.0);
+}
+
+#[test]
+fn deserialize_unknown_variants() {
+    fn deserialize<'a, T: Deserialize<'a>>(value: &'a str) -> T {
+        T::deserialize(IntoDeserializer::<serde::de::value::Error>::into_deserializer(value))
+            .unwrap()
+    }
+    assert_eq!(
+        deserialize::<parse_wiktionary_en::Language>("gem-pro"),
+        parse_wiktionary_en::Language::GemPro
+    );
+    assert_eq!(
+        deserialize::<parse_wiktionary_en::Language>("unknown_language"),
+        parse_wiktionary_en::Language::Other
+    );
+    assert_eq!(
+        deserialize::<parse_wiktionary_en::Pos>("unknown_part_of_speech"),
+        parse_wiktionary_en::Pos::Other
+    );
+    assert_eq!(
+        deserialize::<parse_wiktionary_en::WarningMessage>("unknown_warning"),
+        parse_wiktionary_en::WarningMessage::Other
+    );
+}
+
+#[test]
+fn english_comparison() {
+    for (title, head, comparatives, superlatives) in &[
+        ("happy", "{{en-adj}}", &["more happy"][..], &["most happy"][..]),
+        ("happy", "{{en-adj|er|more}}", &["happier", "more happy"], &["happiest", "most happy"]),
+        ("nice", "{{en-adv|er}}", &["nicer"], &["nicest"]),
+        ("good", "{{en-adj|better|sup=best}}", &["better"], &["best"]),
+        ("quick", "{{en-adj|quicker}}", &["quicker"], &["quickest"]),
+        ("wooden", "{{en-adj|-}}", &[], &[]),
+        ("good", "{{en-adj|-|better|sup=best}}", &["better"], &["best"]),
+        ("far", "{{en-adj|-|farther|further|sup=farthest|sup2=furthest}}", &["farther", "further"], &["farthest", "furthest"]),
+        ("far", "{{en-adv|-|farther|further|sup2=furthest}}", &["farther", "further"], &["farthest", "furthest"]),
+    ] {
+        let wiki_text = format!("==English==\n===Adjective===\n{}\n\n# A word.", head);
+        let nodes = parse_wiktionary_en::create_configuration()
+            .parse(&wiki_text)
+            .nodes;
+        let output = parse_wiktionary_en::parse_with_options(
+            &wiki_text,
+            &nodes,
+            &parse_wiktionary_en::ParseOptions {
+                title: Some(title.to_string()),
+                ..Default::default()
+            },
+        );
+        let pos_entry = &output.language_entries[0].etymology_entry.pos_entries[0];
+        assert_eq!(&pos_entry.comparatives, comparatives);
+        assert_eq!(&pos_entry.superlatives, superlatives);
+    }
+}
+
+#[test]
+fn conjugation() {
+    use parse_wiktionary_en::{Mood::*, Number::*, Person::*, Tense::*};
+    for (language, title, template, form, mood, tense, person) in &[
+        ("Czech", "dělat", "{{cs-conj-dělat|děl}}", "dělají", Indicative, Some(Present), Some((Third, Plural))),
+        ("Czech", "prosit", "{{cs-conj-prosit|pros}}", "prosil", Participle, Some(Past), None),
+        ("German", "arbeiten", "{{de-conj}}", "arbeitetest", Indicative, Some(Preterite), Some((Second, Singular))),
+        ("German", "bezahlen", "{{de-conj-weak|bezahl}}", "bezahlt", Participle, Some(Past), None),
+        ("Spanish", "hablar", "{{es-conj-ar}}", "hablaríamos", Conditional, None, Some((First, Plural))),
+        ("Spanish", "comer", "{{es-conj-er|com}}", "comiera", Subjunctive, Some(Imperfect), Some((First, Singular))),
+        ("Spanish", "vivir", "{{es-conj-ir}}", "vivís", Indicative, Some(Present), Some((Second, Plural))),
+        ("Swedish", "kasta", "{{sv-conj-wk|kast}}", "kastat", Supine, None, None),
+    ] {
+        let wiki_text = format!(
+            "=={}==\n===Verb===\n{{{{head|xx|verb}}}}\n\n# A verb.\n\n====Conjugation====\n{}",
+            language, template
+        );
+        let nodes = parse_wiktionary_en::create_configuration()
+            .parse(&wiki_text)
+            .nodes;
+        let output = parse_wiktionary_en::parse_with_options(
+            &wiki_text,
+            &nodes,
+            &parse_wiktionary_en::ParseOptions {
+                title: Some(title.to_string()),
+                ..Default::default()
+            },
+        );
+        let pos_entry = &output.language_entries[0].etymology_entry.pos_entries[0];
+        let conjugated_form = pos_entry
+            .conjugation
+            .iter()
+            .find(|conjugated_form| conjugated_form.form == *form && conjugated_form.mood == *mood)
+            .unwrap();
+        assert_eq!(conjugated_form.tense, *tense);
+        assert_eq!(conjugated_form.person, person.map(|(person, _)| person));
+        assert_eq!(conjugated_form.number, person.map(|(_, number)| number));
+    }
+    let wiki_text = "==German==\n===Verb===\n{{head|de|verb}}\n\n# A verb.\n\n====Conjugation====\n{{de-conj|<ie,o,o>}}";
+    let nodes = parse_wiktionary_en::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let output = parse_wiktionary_en::parse_with_options(
+        wiki_text,
+        &nodes,
+        &parse_wiktionary_en::ParseOptions {
+            title: Some("fliegen".to_string()),
+            ..Default::default()
+        },
+    );
+    assert!(output.language_entries[0].etymology_entry.pos_entries[0]
+        .conjugation
+        .is_empty());
+}
+
+#[test]
+fn declension() {
+    use parse_wiktionary_en::{Case::*, Number::*};
+    for (language, title, template, form, case, definite, number) in &[
+        (
+            "Czech",
+            "hrad",
+            "{{cs-decl-noun|hrad|hrady|hradu|hradů|hradu|hradům|hrad|hrady|hrade|hrady|hradě|hradech|hradem|hrady}}",
+            "hradem",
+            Instrumental,
+            None,
+            Singular,
+        ),
+        ("German", "Hund", "{{de-decl-noun-m|Hundes|Hunde}}", "Hundes", Genitive, None, Singular),
+        ("German", "Hund", "{{de-decl-noun-m|Hundes|Hunde}}", "Hunden", Dative, None, Plural),
+        ("German", "Frau", "{{de-decl-noun-f|Frauen}}", "Frauen", Dative, None, Plural),
+        ("Swedish", "bil", "{{sv-infl-noun|bil|bilen|bilar|bilarna}}", "bilens", Genitive, Some(true), Singular),
+        ("Swedish", "bil", "{{sv-infl-noun|bil|bilen|bilar|bilarna}}", "bilar", Nominative, Some(false), Plural),
+    ] {
+        let wiki_text = format!(
+            "=={}==\n===Noun===\n{{{{head|xx|noun}}}}\n\n# A noun.\n\n====Declension====\n{}",
+            language, template
+        );
+        let nodes = parse_wiktionary_en::create_configuration()
+            .parse(&wiki_text)
+            .nodes;
+        let output = parse_wiktionary_en::parse_with_options(
+            &wiki_text,
+            &nodes,
+            &parse_wiktionary_en::ParseOptions {
+                title: Some(title.to_string()),
+                ..Default::default()
+            },
+        );
+        assert!(output.warnings.is_empty());
+        let pos_entry = &output.language_entries[0].etymology_entry.pos_entries[0];
+        assert!(pos_entry.declension.iter().any(|declined_form| declined_form.form == *form
+            && declined_form.case == *case
+            && declined_form.definite == *definite
+            && declined_form.number == *number));
+    }
+}
+
+#[test]
+fn english_comparability() {
+    for (pos, head, comparable) in &[
+        ("Adjective", "{{en-adj}}", Some(true)),
+        ("Adjective", "{{en-adj|er}}", Some(true)),
+        ("Adjective", "{{en-adj|-}}", Some(false)),
+        ("Adjective", "{{en-adj|-|more}}", Some(true)),
+        ("Adjective", "{{en-adj|?}}", None),
+        ("Adverb", "{{en-adv|-}}", Some(false)),
+        ("Adjective", "{{head|en|adjective}}", None),
+    ] {
+        let wiki_text = format!("==English==\n==={}===\n{}\n\n# A word.", pos, head);
+        let nodes = parse_wiktionary_en::create_configuration()
+            .parse(&wiki_text)
+            .nodes;
+        let output = parse_wiktionary_en::parse(&wiki_text, &nodes);
+        let pos_entry = &output.language_entries[0].etymology_entry.pos_entries[0];
+        assert_eq!(&pos_entry.comparable, comparable);
+    }
+    let wiki_text = "==English==\n===Adjective===\n{{head|en|adjective}}\n\n# {{lb|en|not comparable}} A word.\n# {{lb|en|informal|not comparable}} A word.";
+    let nodes = parse_wiktionary_en::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let output = parse_wiktionary_en::parse(wiki_text, &nodes);
+    let pos_entry = &output.language_entries[0].etymology_entry.pos_entries[0];
+    assert_eq!(pos_entry.comparable, Some(false));
+}
+
+#[test]
+fn english_noun_plurals() {
+    let wiki_text = "==English==\n===Noun===\n{{en-noun}}\n\n# A noun.\n\n===Noun 2===\n{{en-noun|~|es|boxen}}\n\n# A noun.\n\n===Noun 3===\n{{en-noun|-}}\n\n# A noun.\n\n===Noun 4===\n{{en-noun|[[boxen]]|-}}\n\n# A noun.";
+    let nodes = parse_wiktionary_en::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let output = parse_wiktionary_en::parse(wiki_text, &nodes);
+    let pos_entries = &output.language_entries[0].etymology_entry.pos_entries;
+    assert!(pos_entries[0].plurals.is_empty());
+    assert_eq!(pos_entries[1].plurals, ["boxen"]);
+    let output = parse_wiktionary_en::parse_with_options(
+        wiki_text,
+        &nodes,
+        &parse_wiktionary_en::ParseOptions {
+            title: Some("box".to_string()),
+            ..Default::default()
+        },
+    );
+    assert!(output.warnings.is_empty());
+    let pos_entries = &output.language_entries[0].etymology_entry.pos_entries;
+    assert_eq!(pos_entries[0].plurals, ["boxes"]);
+    assert_eq!(pos_entries[1].plurals, ["boxes", "boxen"]);
+    assert!(pos_entries[2].plurals.is_empty());
+    assert!(pos_entries[3].plurals.is_empty());
+    assert_eq!(
+        pos_entries[3].countability,
+        Some(parse_wiktionary_en::Countability::CountableAndUncountable)
+    );
+}
+
+#[test]
+fn title() {
+    let wiki_text = "==English==\n===Noun===\n{{en-noun}}\n\n# A noun.\n\n===Noun 2===\n{{en-noun|head=[[cat]]s [[eye]]}}\n\n# A noun.\n==Swedish==\n===Noun===\n{{head|sv|noun}}\n\n# A noun.\n\n====Declension====\n{{sv-infl-noun|katt|katten|katter|katterna}}\n==Spanish==\n===Verb===\n{{head|es|verb}}\n\n# A verb.\n\n====Conjugation====\n{{es-conj-ar|habl}}";
+    let nodes = parse_wiktionary_en::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let output = parse_wiktionary_en::parse(wiki_text, &nodes);
+    assert_eq!(output.word, None);
+    assert!(output.warnings.is_empty());
+    assert_eq!(output.language_entries[0].etymology_entry.pos_entries[0].headword, None);
+    let options = parse_wiktionary_en::ParseOptions::default().title("cat");
+    let output = parse_wiktionary_en::parse_with_options(wiki_text, &nodes, &options);
+    assert_eq!(output.word.as_ref().unwrap(), "cat");
+    let headwords: Vec<_> = output
+        .language_entries
+        .iter()
+        .flat_map(parse_wiktionary_en::LanguageEntry::pos_entries)
+        .map(|pos_entry| pos_entry.headword.as_ref().unwrap().as_ref())
+        .collect();
+    assert_eq!(headwords, ["cat", "cats eye", "cat", "cat"]);
+    let languages: Vec<_> = output.warnings.iter().map(|warning| warning.language).collect();
+    assert_eq!(
+        languages,
+        [Some(parse_wiktionary_en::Language::Sv), Some(parse_wiktionary_en::Language::Es)]
+    );
+    assert!(output
+        .warnings
+        .iter()
+        .all(|warning| warning.message == parse_wiktionary_en::WarningMessage::ValueConflicting));
+    let options = parse_wiktionary_en::ParseOptions::default().title("katt");
+    let output = parse_wiktionary_en::parse_with_options(wiki_text, &nodes, &options);
+    assert_eq!(output.warnings.len(), 1);
+    let options = parse_wiktionary_en::ParseOptions::default().title("hablarse");
+    let output = parse_wiktionary_en::parse_with_options(wiki_text, &nodes, &options);
+    assert_eq!(output.warnings.len(), 1);
+    let output = parse_wiktionary_en::parse_with_options("", &[], &options);
+    assert_eq!(output.stats().text_bytes, "hablarse".len());
+}
+
+#[test]
+fn english_noun_countability() {
+    use parse_wiktionary_en::Countability::*;
+    for (head, countability) in &[
+        ("{{en-noun}}", Some(Countable)),
+        ("{{en-noun|es}}", Some(Countable)),
+        ("{{en-noun|-}}", Some(Uncountable)),
+        ("{{en-noun|-|s}}", Some(UsuallyUncountable)),
+        ("{{en-noun|~}}", Some(CountableAndUncountable)),
+        ("{{en-noun|s|-}}", Some(CountableAndUncountable)),
+        ("{{en-noun|[[mice]]|-}}", Some(CountableAndUncountable)),
+        ("{{en-noun|?}}", None),
+        ("{{head|en|noun}}", None),
+    ] {
+        let wiki_text = format!("==English==\n===Noun===\n{}\n\n# A noun.", head);
+        let nodes = parse_wiktionary_en::create_configuration()
+            .parse(&wiki_text)
+            .nodes;
+        let output = parse_wiktionary_en::parse(&wiki_text, &nodes);
+        let pos_entry = &output.language_entries[0].etymology_entry.pos_entries[0];
+        assert_eq!(&pos_entry.countability, countability);
+    }
+}
+
+#[test]
+fn english_verb_forms() {
+    for (title, head, forms) in &[
+        ("cry", "{{en-verb}}", ["cries", "crying", "cried", "cried"]),
+        ("stop", "{{en-verb|++}}", ["stops", "stopping", "stopped", "stopped"]),
+        ("bake", "{{en-verb|+|+}}", ["bakes", "baking", "baked", "baked"]),
+        ("run", "{{en-verb|runs|running|ran|run}}", ["runs", "running", "ran", "run"]),
+    ] {
+        let wiki_text = format!("==English==\n===Verb===\n{}\n\n# A verb.", head);
+        let nodes = parse_wiktionary_en::create_configuration()
+            .parse(&wiki_text)
+            .nodes;
+        let output = parse_wiktionary_en::parse_with_options(
+            &wiki_text,
+            &nodes,
+            &parse_wiktionary_en::ParseOptions {
+                title: Some(title.to_string()),
+                ..Default::default()
+            },
+        );
+        let verb_forms = output.language_entries[0].etymology_entry.pos_entries[0]
+            .verb_forms
+            .as_ref()
+            .unwrap();
+        assert_eq!(verb_forms.third_person_singular.as_ref().unwrap(), forms[0]);
+        assert_eq!(verb_forms.present_participle.as_ref().unwrap(), forms[1]);
+        assert_eq!(verb_forms.past_tense.as_ref().unwrap(), forms[2]);
+        assert_eq!(verb_forms.past_participle.as_ref().unwrap(), forms[3]);
+    }
+}
+
+#[test]
+fn genders() {
+    use parse_wiktionary_en::Gender::*;
+    for (language, head, genders) in &[
+        ("Danish", "{{da-noun|g=c}}", &[Common][..]),
+        ("Dutch", "{{head|nl|noun|g=m|g2=f}}", &[Masculine, Feminine]),
+        ("Polish", "{{pl-noun|g=m-in}}", &[Masculine]),
+        ("Russian", "{{ru-noun|ко́шка|f-an}}", &[Feminine]),
+        ("Spanish", "{{es-noun|mf}}", &[Masculine, Feminine]),
+        ("Spanish", "{{head|es|noun}}", &[]),
+    ] {
+        let wiki_text = format!("=={}==\n===Noun===\n{}\n\n# A noun.", language, head);
+        let nodes = parse_wiktionary_en::create_configuration()
+            .parse(&wiki_text)
+            .nodes;
+        let output = parse_wiktionary_en::parse(&wiki_text, &nodes);
+        let pos_entry = &output.language_entries[0].etymology_entry.pos_entries[0];
+        assert_eq!(&pos_entry.genders, genders);
+    }
+}
+
+#[test]
+fn german_nouns() {
+    for (title, head, genitives, plurals) in &[
+        ("Haus", "{{de-noun|n,es,^er}}", &["Hauses"][..], &["Häuser"][..]),
+        ("Vater", "{{de-noun|m,s,^}}", &["Vaters"], &["Väter"]),
+        ("Tag", "{{de-noun|m,es:s,e}}", &["Tages", "Tags"], &["Tage"]),
+        ("Milch", "{{de-noun|f,,-}}", &[], &[]),
+    ] {
+        let wiki_text = format!("==German==\n===Noun===\n{}\n\n# A noun.", head);
+        let nodes = parse_wiktionary_en::create_configuration()
+            .parse(&wiki_text)
+            .nodes;
+        let output = parse_wiktionary_en::parse_with_options(
+            &wiki_text,
+            &nodes,
+            &parse_wiktionary_en::ParseOptions {
+                title: Some(title.to_string()),
+                ..Default::default()
+            },
+        );
+        let pos_entry = &output.language_entries[0].etymology_entry.pos_entries[0];
+        assert_eq!(pos_entry.genders.len(), 1);
+        assert_eq!(&pos_entry.genitives, genitives);
+        assert_eq!(&pos_entry.plurals, plurals);
+    }
+}
+
+#[test]
+fn romance_nouns() {
+    for (language, title, head, plurals) in &[
+        ("French", "cheval", "{{fr-noun|m|chevaux}}", &["chevaux"][..]),
+        ("French", "chat", "{{fr-noun|m}}", &["chats"]),
+        ("French", "bateau", "{{fr-noun|m}}", &["bateaux"]),
+        ("French", "jeu", "{{fr-noun|m}}", &[]),
+        ("French", "jeu", "{{fr-noun|m|x}}", &["jeux"]),
+        ("French", "prix", "{{fr-noun|m|#}}", &["prix"]),
+        ("Italian", "libro", "{{it-noun|m}}", &["libri"]),
+        ("Italian", "casa", "{{it-noun|f}}", &["case"]),
+        ("Italian", "amica", "{{it-noun|f}}", &[]),
+        ("Portuguese", "animal", "{{pt-noun|m}}", &["animais"]),
+        ("Portuguese", "canção", "{{pt-noun|f}}", &["canções"]),
+        ("Portuguese", "homem", "{{pt-noun|m}}", &["homens"]),
+        ("Portuguese", "papel", "{{pt-noun|m}}", &[]),
+        ("Portuguese", "papel", "{{pt-noun|m|papéis}}", &["papéis"]),
+        ("Spanish", "canción", "{{es-noun|f}}", &["canciones"]),
+        ("Spanish", "luz", "{{es-noun|f}}", &["luces"]),
+        ("Spanish", "lunes", "{{es-noun|m}}", &["lunes"]),
+        ("Spanish", "joven", "{{es-noun|m}}", &[]),
+        ("Spanish", "país", "{{es-noun|m}}", &["países"]),
+        ("Spanish", "papel", "{{es-noun|m}}", &["papeles"]),
+        ("Spanish", "casa", "{{es-noun|f|+|pl2=casitas}}", &["casas", "casitas"]),
+    ] {
+        let wiki_text = format!("=={}==\n===Noun===\n{}\n\n# A noun.", language, head);
+        let nodes = parse_wiktionary_en::create_configuration()
+            .parse(&wiki_text)
+            .nodes;
+        let output = parse_wiktionary_en::parse_with_options(
+            &wiki_text,
+            &nodes,
+            &parse_wiktionary_en::ParseOptions {
+                title: Some(title.to_string()),
+                ..Default::default()
+            },
+        );
+        assert!(output.warnings.is_empty());
+        let pos_entry = &output.language_entries[0].etymology_entry.pos_entries[0];
+        assert_eq!(pos_entry.genders.len(), 1);
+        assert_eq!(&pos_entry.plurals, plurals);
+    }
+}
+
+#[test]
+fn gloss_text() {
+    let wiki_text = "==English==\n===Noun===\n# {{lb|en|informal}} {{defdate|1900}} A [[word|wordy]] of ''the'' sort<sup>2</sup>.";
+    let output = parse_wiktionary_en::parse(
+        wiki_text,
+        &parse_wiktionary_en::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert_eq!(
+        output.language_entries[0].etymology_entry.pos_entries[0].definitions[0].gloss_text(),
+        "A wordy of the sort2."
+    );
+}
+
+#[test]
+fn render_plain() {
+    let wiki_text = "==English==\n===Noun===\n# {{lb|en|informal|dated}} A '''[[word|wordy]]''' {{m|en|thing}}.\n# {{lb|en|US|or|UK}} A word.";
+    let output = parse_wiktionary_en::parse(
+        wiki_text,
+        &parse_wiktionary_en::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    let definitions = &output.language_entries[0].etymology_entry.pos_entries[0].definitions;
+    assert_eq!(
+        parse_wiktionary_en::render_plain(&definitions[0].definition),
+        "(informal, dated) A wordy thing."
+    );
+    assert_eq!(parse_wiktionary_en::render_plain(&definitions[1].definition), "(US or UK) A word.");
+}
+
+#[test]
+fn markdown() {
+    let wiki_text = "==English==\n===Noun===\n# {{lb|en|informal}} A [[word|wordy]] {{m|en|thing}}.<br>See [[w:Word|*words*]].\n## [[Old English]] use.\n# Another_word.\n\n====Usage notes====\n* '''Rarely''' used.";
+    let output = parse_wiktionary_en::parse(
+        wiki_text,
+        &parse_wiktionary_en::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    let pos_entry = &output.language_entries[0].etymology_entry.pos_entries[0];
+    assert_eq!(
+        pos_entry.definitions[0].to_markdown(),
+        "*(informal)* A [wordy](https://en.wiktionary.org/wiki/word) *thing*.\\\nSee [\\*words\\*](https://en.wikipedia.org/wiki/Word).\n1. [Old English](https://en.wiktionary.org/wiki/Old_English) use.\n"
+    );
+    assert_eq!(
+        pos_entry.to_markdown(),
+        "### Noun\n\n1. *(informal)* A [wordy](https://en.wiktionary.org/wiki/word) *thing*.\\\n   See [\\*words\\*](https://en.wikipedia.org/wiki/Word).\n   1. [Old English](https://en.wiktionary.org/wiki/Old_English) use.\n2. Another\\_word.\n\n#### Usage notes\n\n- **Rarely** used.\n"
+    );
+}
+
+#[test]
+fn wiki_text() {
+    let wiki_text = "==English==\n===Etymology===\nFrom {{m|enm|water|t=water}}.\n\n===Noun===\n{{en-noun|-|head=[[water]]}}\n\n# {{lb|en|uncountable}} A [[liquid]] of ''clear'' colour.\n## Drinking water.\n# {{lb|en|US|or|UK}} A body of water.\n\n====Usage notes====\n* Often in the plural.\n\n----\n\n==German==\n===Etymology 1===\n====Noun====\n# A word.\n\n===Etymology 2===\nUnknown.\n\n====Verb====\n# To word.\n";
+    let nodes = parse_wiktionary_en::create_configuration()
+        .parse(wiki_text)
+        .nodes;
+    let mut output = parse_wiktionary_en::parse(wiki_text, &nodes);
+    assert_eq!(output.to_wiki_text(), wiki_text);
+    let pos_entry = &mut output.language_entries[1].etymology_entries[1].pos_entries[0];
+    pos_entry.definitions[0].definition = vec![parse_wiktionary_en::Flowing::Text {
+        value: "To use words.".into(),
+    }];
+    assert_eq!(
+        output.language_entries[1].to_wiki_text(),
+        "==German==\n===Etymology 1===\n====Noun====\n# A word.\n\n===Etymology 2===\nUnknown.\n\n====Verb====\n# To use words.\n"
+    );
+}
+
+#[test]
+fn coverage() {
+    let wiki_text = "==English==\n===Noun===\n# A word.\n===Etymology===\nFrom Latin.<ref>A dictionary.</ref>\n==German==\n{{unknown}}";
+    let output = parse_wiktionary_en::parse(
+        wiki_text,
+        &parse_wiktionary_en::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    let coverage = output.coverage();
+    assert_eq!(coverage.len(), 2);
+    assert_eq!(coverage[0].language, parse_wiktionary_en::Language::En);
+    assert_eq!(coverage[0].unrecognized, 0.0);
+    assert!(coverage[0].supplementary > 0.0);
+    assert!(coverage[0].parsed > coverage[0].supplementary);
+    assert_eq!(coverage[1].language, parse_wiktionary_en::Language::De);
+    assert!(coverage[1].unrecognized > 0.0);
+    for coverage in coverage {
+        assert!(
+            (coverage.parsed + coverage.supplementary + coverage.unrecognized - 1.0).abs() < 1e-9
+        );
+    }
+}
+
+#[test]
+fn template_text() {
+    let wiki_text = "==English==\n===Noun===\n{{en-noun|mice&amp;men|[[mouse]]|head=test}}\n# A word.";
+    let output = parse_wiktionary_en::parse(
+        wiki_text,
+        &parse_wiktionary_en::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    let head = output.language_entries[0].etymology_entry.pos_entries[0]
+        .head
+        .as_ref()
+        .unwrap();
+    assert_eq!(head.named_text("head").as_ref().map(|text| text as &str), Some("test"));
+    assert_eq!(head.named_text("plural"), None);
+    assert_eq!(head.unnamed_text(0).as_ref().map(|text| text as &str), Some("mice&men"));
+    assert_eq!(head.unnamed_text(1), None);
+    assert_eq!(head.unnamed_text(2), None);
+    assert_eq!(
+        &wiki_text[head.start..head.end],
+        "{{en-noun|mice&amp;men|[[mouse]]|head=test}}"
+    );
+    assert_eq!(&wiki_text[head.named_parameter_ranges["head"].clone()], "head=test");
+    assert_eq!(&wiki_text[head.unnamed_parameter_ranges[1].clone()], "[[mouse]]");
+}
+
+#[test]
+fn audio_commons_url() {
+    let audio = parse_wiktionary_en::Audio {
+        accents: vec![],
+        caption: None,
+        file: "En-us-tomato (1).ogg".into(),
+    };
+    assert_eq!(
+        audio.commons_url(),
+        "https://commons.wikimedia.org/wiki/Special:FilePath/En-us-tomato_%281%29.ogg"
+    );
+}
+
+#[test]
+fn x_sampa_to_ipa() {
+    assert_eq!(parse_wiktionary_en::x_sampa_to_ipa("/w3:d/"), "/wɜːd/");
+    assert_eq!(parse_wiktionary_en::x_sampa_to_ipa("/t@\"mA:t@U/"), "/təˈmɑːtəʊ/");
+    assert_eq!(parse_wiktionary_en::x_sampa_to_ipa("[r\\`I4_0]"), "[ɻɪɾ\u{325}]");
+}
+
+#[test]
+fn ancestry() {
+    let wiki_text = "==English==\n===Etymology===\nFrom {{inh|en|enm|water}}, from {{inh|en|ang|wæter}}, from {{inh|en|gem-pro|*watōr}}. Compare {{der|en|enm|wateren}}.\n===Noun===\n# A liquid.";
+    let output = parse_wiktionary_en::parse(
+        wiki_text,
+        &parse_wiktionary_en::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    let etymology_entry = &output.language_entries[0].etymology_entry;
+    assert_eq!(etymology_entry.derivations.len(), 4);
+    let languages: Vec<_> = etymology_entry
+        .ancestry()
+        .iter()
+        .map(|derivation| derivation.language.as_ref())
+        .collect();
+    assert_eq!(languages, ["enm", "ang", "gem-pro"]);
+}
+
+#[test]
+fn numbered_parameter_out_of_range() {
+    let wiki_text = "==English==\n===Etymology===\n{{af|en|a|b|t400000000=x}}\n===Noun===\n# A word.";
+    let output = parse_wiktionary_en::parse(
+        wiki_text,
+        &parse_wiktionary_en::create_configuration()
+            .parse(wiki_text)
+            .nodes,
+    );
+    assert!(output.language_entries[0]
+        .etymology_entry
+        .word_formations
+        .is_empty());
+    assert!(output
+        .warnings
+        .iter()
+        .any(|warning| warning.message == parse_wiktionary_en::WarningMessage::Unrecognized));
+}